@@ -0,0 +1,10 @@
+use std::env;
+
+fn main() {
+
+	// vkraw says whether there is a Vulkan loader to link against, the tests that need one are skipped without it
+	println!("cargo:rustc-check-cfg=cfg(vulkan_loader)");
+	if env::var("DEP_VULKAN_LOADER").map(|l| l == "1").unwrap_or(false) {
+		println!("cargo:rustc-cfg=vulkan_loader");
+	}
+}
@@ -18,6 +18,7 @@ pub struct Instance {
 	pub instance: vkraw::VkInstance,
 	pub vk: vkraw::VulkanFunctionPointers,
 	pub callback: vkraw::VkDebugReportCallbackEXT,
+	pub extensions: Vec<String>,
 }
 
 pub struct Device<'a> {
@@ -160,6 +161,43 @@ pub fn rust_debug_message_callback(flags: vkraw::VkDebugReportFlagsEXT, obj_type
 	true
 }
 
+// Extension names are ASCII, anything else is replaced rather than trusted
+pub fn extension_name(name: &std::ffi::CStr) -> String {
+	name.to_string_lossy().into_owned()
+}
+
+// Add an extension and the extensions it requires (requirements first). Extensions that are not in the registry
+// or are of the wrong type can not be enabled
+fn add_extension(name: &str, extension_type: vkraw::ExtensionType, enabled: &mut Vec<String>) -> Result<(), vkraw::VkResult> {
+
+	if enabled.iter().any(|e| e == name) {
+		return Ok(());
+	}
+
+	match vkraw::extension_info(name) {
+		Some(info) if info.extension_type == extension_type => {
+			for r in info.requires {
+				match vkraw::extension_info(r) {
+					Some(required) if required.extension_type == extension_type => add_extension(r, extension_type, enabled)?,
+					_ => ()
+				}
+			}
+			enabled.push(name.to_string());
+			Ok(())
+		},
+		_ => Err(vkraw::VkResult::VK_ERROR_EXTENSION_NOT_PRESENT)
+	}
+}
+
+fn resolve_extensions(extensions: &[String], extension_type: vkraw::ExtensionType) -> Result<Vec<String>, vkraw::VkResult> {
+
+	let mut enabled = Vec::<String>::with_capacity(extensions.len());
+	for e in extensions {
+		add_extension(e, extension_type, &mut enabled)?;
+	}
+	Ok(enabled)
+}
+
 pub struct InstanceBuilder {
 	pub layers: Vec<String>,
 	pub extensions: Vec<String>,
@@ -182,22 +220,22 @@ impl Default for InstanceBuilder {
 			],
 			extensions: vec![
 				#[cfg(debug_assertions)]
-				"VK_EXT_debug_report".to_string(),
-				"VK_KHR_surface".to_string(),
+				extension_name(vkraw::VK_EXT_DEBUG_REPORT_EXTENSION_NAME),
+				extension_name(vkraw::VK_KHR_SURFACE_EXTENSION_NAME),
 				#[cfg(windows)]
-				"VK_KHR_win32_surface".to_string(),
+				extension_name(vkraw::VK_KHR_WIN32_SURFACE_EXTENSION_NAME),
 				#[cfg(unix)]
-				"VK_KHR_xcb_surface".to_string(),
-				"VK_KHR_get_surface_capabilities2".to_string(),
+				extension_name(vkraw::VK_KHR_XCB_SURFACE_EXTENSION_NAME),
+				extension_name(vkraw::VK_KHR_GET_SURFACE_CAPABILITIES_2_EXTENSION_NAME),
 
-				//"VK_EXT_swapchain_colorspace".to_string(),
+				//extension_name(vkraw::VK_EXT_SWAPCHAIN_COLOR_SPACE_EXTENSION_NAME),
 
-				"VK_KHR_get_physical_device_properties2".to_string(),
-				//"VK_KHR_swapchain".to_string(),
-				//"VK_EXT_full_screen_exclusive".to_string(),
+				extension_name(vkraw::VK_KHR_GET_PHYSICAL_DEVICE_PROPERTIES_2_EXTENSION_NAME),
+				//extension_name(vkraw::VK_KHR_SWAPCHAIN_EXTENSION_NAME),
+				//extension_name(vkraw::VK_EXT_FULL_SCREEN_EXCLUSIVE_EXTENSION_NAME),
 				
-				//"VK_KHR_display".to_string(),
-				//"VK_KHR_display_swapchain".to_string(),
+				//extension_name(vkraw::VK_KHR_DISPLAY_EXTENSION_NAME),
+				//extension_name(vkraw::VK_KHR_DISPLAY_SWAPCHAIN_EXTENSION_NAME),
 			],
 			application_name: "rust vulkan application".to_string(),
 			debug_message_callback: rust_debug_message_callback,
@@ -215,32 +253,10 @@ impl InstanceBuilder {
 		// Check available layers/extensions
 		let available_layers: Vec<String>;
 		let available_extensions: Vec<String>;
-
-		let mut num_available_extensions: u32 = 0;
-		let mut num_available_layers: u32 = 0;
-		let ext_res;
-		let layer_res;
-		unsafe {
-			ext_res = vkraw::vkEnumerateInstanceExtensionProperties(ptr::null_mut(), &mut num_available_extensions, ptr::null_mut());
-			layer_res = vkraw::vkEnumerateInstanceLayerProperties(&mut num_available_layers, ptr::null_mut());
-		};
-
-		let mut available_layers_struct = Vec::<vkraw::VkLayerProperties>::with_capacity(num_available_layers as usize);
-		let mut available_extensions_struct = Vec::<vkraw::VkExtensionProperties>::with_capacity(num_available_extensions as usize);
 		unsafe {
-			if ext_res == vkraw::VkResult::VK_SUCCESS {
-				let res = vkraw::vkEnumerateInstanceExtensionProperties(ptr::null_mut(), &mut num_available_extensions, available_extensions_struct.as_mut_ptr());
-				if res == vkraw::VkResult::VK_SUCCESS {
-					available_extensions_struct.set_len(num_available_extensions as usize);
-				}
-			}
+			let available_extensions_struct = vkraw::enumerate::vkEnumerateInstanceExtensionProperties(ptr::null()).unwrap_or(vec![]);
 			available_extensions = available_extensions_struct.iter().map(|x| std::ffi::CStr::from_ptr(&x.extensionName[0] as *const u8 as *const i8).to_owned().into_string().unwrap()).collect();
-			if layer_res == vkraw::VkResult::VK_SUCCESS {
-				let res = vkraw::vkEnumerateInstanceLayerProperties(&mut num_available_layers, available_layers_struct.as_mut_ptr());
-				if res == vkraw::VkResult::VK_SUCCESS {
-					available_layers_struct.set_len(num_available_layers as usize);
-				}
-			}
+			let available_layers_struct = vkraw::enumerate::vkEnumerateInstanceLayerProperties().unwrap_or(vec![]);
 			available_layers = available_layers_struct.iter().map(|x| std::ffi::CStr::from_ptr(&x.layerName[0] as *const u8 as *const i8).to_owned().into_string().unwrap()).collect();
 		};
 
@@ -257,8 +273,9 @@ impl InstanceBuilder {
 			}
 		}
 
-		let mut enabled_extensions_rust = Vec::<CString>::with_capacity(self.extensions.len());
-		for e in &self.extensions {
+		let extensions = resolve_extensions(&self.extensions, vkraw::ExtensionType::Instance)?;
+		let mut enabled_extensions_rust = Vec::<CString>::with_capacity(extensions.len());
+		for e in &extensions {
 			enabled_extensions_rust.push(CString::new(e.clone()).unwrap());
 			if !available_extensions.iter().any(|x| x == e) {
 				println!("{}", format!("Extension {} not available", e).red());
@@ -322,7 +339,7 @@ impl InstanceBuilder {
 				assert!(res2 == vkraw::VkResult::VK_SUCCESS);
 			};
 
-			Ok(Instance { instance: instance, vk: vk, callback: callback })
+			Ok(Instance { instance: instance, vk: vk, callback: callback, extensions: extensions })
 		} else {
 			Err(res)
 		}
@@ -348,22 +365,9 @@ impl Instance {
 
 	pub fn physical_devices(&self) -> Vec<PhysicalDevice> {
 
-		let mut num_physical_devices = 0;
-		let mut res: vkraw::VkResult;
-		unsafe {
-			res = vkraw::vkEnumeratePhysicalDevices(self.instance, &mut num_physical_devices, 0 as *mut u64);
-		}
-		assert!(res == vkraw::VkResult::VK_SUCCESS);
-
-		let mut physical_devices = Vec::<PhysicalDevice>::with_capacity(num_physical_devices as usize);
-
-		let mut vk_physical_devices = Vec::<vkraw::VkPhysicalDevice>::with_capacity(num_physical_devices as usize);
-		unsafe {
-			vk_physical_devices.set_len(num_physical_devices as usize);
-			res = vkraw::vkEnumeratePhysicalDevices(self.instance, &mut num_physical_devices, vk_physical_devices.as_mut_ptr());
-		}
-		assert!(res == vkraw::VkResult::VK_SUCCESS);
+		let vk_physical_devices = unsafe { vkraw::enumerate::vkEnumeratePhysicalDevices(self.instance) }.expect("Failed to enumerate physical devices");
 
+		let mut physical_devices = Vec::<PhysicalDevice>::with_capacity(vk_physical_devices.len());
 		for d in vk_physical_devices {
 			assert!(d != vkraw::VK_NULL_HANDLE);
 
@@ -537,16 +541,7 @@ impl Instance {
 
 impl<'a> PhysicalDevice<'a> {
 	pub fn queue_families(&self) -> Vec<vkraw::VkQueueFamilyProperties> {
-		let mut num_physical_devices = 0;
-		unsafe {
-			vkraw::vkGetPhysicalDeviceQueueFamilyProperties(self.physical_device, &mut num_physical_devices, ptr::null_mut());
-		}
-		let mut physical_devices = Vec::<vkraw::VkQueueFamilyProperties>::with_capacity(num_physical_devices as usize);
-		unsafe {
-			physical_devices.set_len(num_physical_devices as usize);
-			vkraw::vkGetPhysicalDeviceQueueFamilyProperties(self.physical_device, &mut num_physical_devices, physical_devices.as_mut_ptr());
-		}
-		return physical_devices;
+		unsafe { vkraw::enumerate::vkGetPhysicalDeviceQueueFamilyProperties(self.physical_device) }
 	}
 
 	pub fn physical_properties(&self) -> vkraw::VkPhysicalDeviceProperties {
@@ -583,22 +578,7 @@ impl<'a> PhysicalDevice<'a> {
 	pub fn supported_surface_formats(&self, surface: &Surface) -> Result<Vec<vkraw::VkSurfaceFormatKHR>, vkraw::VkResult> {
 
 		// Get a supported colour format and colour space
-		let mut format_count = 0;
-		assert!(self.instance.vk.GetPhysicalDeviceSurfaceFormatsKHR.is_some());
-		self.instance.vk.GetPhysicalDeviceSurfaceFormatsKHR.unwrap()(self.physical_device, surface.surface, &mut format_count, ptr::null_mut());
-
-		let mut surface_formats = Vec::<vkraw::VkSurfaceFormatKHR>::with_capacity(format_count as usize);
-		unsafe {
-			surface_formats.set_len(format_count as usize);
-		}
-		assert!(self.instance.vk.GetPhysicalDeviceSurfaceFormatsKHR.is_some());
-		let res = self.instance.vk.GetPhysicalDeviceSurfaceFormatsKHR.unwrap()(self.physical_device, surface.surface, &mut format_count, surface_formats.as_mut_ptr());
-
-		if res == vkraw::VkResult::VK_SUCCESS {
-			Ok(surface_formats)
-		} else {
-			Err(res)
-		}
+		unsafe { self.instance.vk.vkGetPhysicalDeviceSurfaceFormatsKHR(self.physical_device, surface.surface) }
 	}
 
 	#[cfg(unix)]
@@ -690,24 +670,9 @@ impl<'a> PhysicalDevice<'a> {
 
 	pub fn present_modes(&self, surface: &Surface) -> Result<Vec<vkraw::VkPresentModeKHR>, vkraw::VkResult> {
 
-		let mut present_mode_count = 0;
-		assert!(self.instance.vk.GetPhysicalDeviceSurfacePresentModesKHR.is_some());
-		let mut res = self.instance.vk.GetPhysicalDeviceSurfacePresentModesKHR.unwrap()(self.physical_device, surface.surface, &mut present_mode_count, ptr::null_mut());
-		if res != vkraw::VkResult::VK_SUCCESS {
-			return Err(res)
-		}
-		assert!(present_mode_count > 0);
-		let mut present_modes = Vec::<vkraw::VkPresentModeKHR>::with_capacity(present_mode_count as usize);
-		unsafe {
-			present_modes.set_len(present_mode_count as usize);
-		}
-		assert!(self.instance.vk.GetPhysicalDeviceSurfacePresentModesKHR.is_some());
-		res = self.instance.vk.GetPhysicalDeviceSurfacePresentModesKHR.unwrap()(self.physical_device, surface.surface, &mut present_mode_count, present_modes.as_mut_ptr());
-		if res == vkraw::VkResult::VK_SUCCESS {
-			Ok(present_modes)
-		} else {
-			Err(res)
-		}
+		let present_modes = unsafe { self.instance.vk.vkGetPhysicalDeviceSurfacePresentModesKHR(self.physical_device, surface.surface) }?;
+		assert!(!present_modes.is_empty());
+		Ok(present_modes)
 	}
 }
 
@@ -729,10 +694,10 @@ impl<'a> DeviceBuilder<'a> {
 				"VK_LAYER_LUNARG_standard_validation".to_string(),
 			],
 			extensions: vec![
-				"VK_KHR_swapchain".to_string(),
-				//"VK_EXT_full_screen_exclusive".to_string(),
-				//"VK_AMD_display_native_hdr".to_string(),
-				"VK_KHR_shader_float16_int8".to_string(),
+				extension_name(vkraw::VK_KHR_SWAPCHAIN_EXTENSION_NAME),
+				//extension_name(vkraw::VK_EXT_FULL_SCREEN_EXCLUSIVE_EXTENSION_NAME),
+				//extension_name(vkraw::VK_AMD_DISPLAY_NATIVE_HDR_EXTENSION_NAME),
+				extension_name(vkraw::VK_KHR_SHADER_FLOAT16_INT8_EXTENSION_NAME),
 			],
 			queue_create_infos: vec![(0, vec![1.0])],
 			want_device_name: String::new(),
@@ -829,8 +794,23 @@ impl<'a> DeviceBuilder<'a> {
 		for l in &self.layers {
 			enabled_layers_rust.push(CString::new(l.clone()).unwrap());
 		}
-		let mut enabled_extensions_rust = Vec::<CString>::with_capacity(self.extensions.len());
-		for e in &self.extensions {
+		let extensions = resolve_extensions(&self.extensions, vkraw::ExtensionType::Device)?;
+
+		// Instance extensions that the device extensions need must already be enabled on the instance
+		for e in &extensions {
+			if let Some(info) = vkraw::extension_info(e) {
+				for r in info.requires {
+					if let Some(required) = vkraw::extension_info(r) {
+						if required.extension_type == vkraw::ExtensionType::Instance && !self.instance.extensions.iter().any(|x| x == r) {
+							return Err(vkraw::VkResult::VK_ERROR_EXTENSION_NOT_PRESENT);
+						}
+					}
+				}
+			}
+		}
+
+		let mut enabled_extensions_rust = Vec::<CString>::with_capacity(extensions.len());
+		for e in &extensions {
 			enabled_extensions_rust.push(CString::new(e.clone()).unwrap());
 		}
 
@@ -1695,17 +1675,9 @@ impl<'a> SwapchainBuilder<'a> {
 impl<'a> Swapchain<'a> {
 	pub fn get_swapchain_images(&self) -> Vec<Image<'a>> {
 
-		let mut swapchain_image_count = 0;
-		assert!(self.device.instance.vk.GetSwapchainImagesKHR.is_some());
-		self.device.instance.vk.GetSwapchainImagesKHR.unwrap()(self.device.device, self.swapchain, &mut swapchain_image_count, ptr::null_mut());
-		assert!(swapchain_image_count > 0);
-		println!("Creating {} swapchain images", swapchain_image_count);
-		let mut swapchain_images = Vec::<vkraw::VkImage>::with_capacity(swapchain_image_count as usize);
-		unsafe {
-			swapchain_images.set_len(swapchain_image_count as usize);
-		}
-		assert!(self.device.instance.vk.GetSwapchainImagesKHR.is_some());
-		self.device.instance.vk.GetSwapchainImagesKHR.unwrap()(self.device.device, self.swapchain, &mut swapchain_image_count, swapchain_images.as_mut_ptr());
+		let swapchain_images = unsafe { self.device.instance.vk.vkGetSwapchainImagesKHR(self.device.device, self.swapchain) }.expect("Failed to get swapchain images");
+		assert!(swapchain_images.len() > 0);
+		println!("Creating {} swapchain images", swapchain_images.len());
 
 		swapchain_images.iter().map(|x| Image { device: &self.device, image: *x, swapchain_image: true }).collect()
 	}
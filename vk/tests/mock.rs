@@ -0,0 +1,107 @@
+// Runs the wrapper on the mock driver (vkmock), whose scripted results take it down its error paths
+
+extern crate vk;
+extern crate vkraw;
+
+#[cfg(all(test, not(vulkan_loader)))]
+mod tests {
+
+	#[test]
+	fn error_paths_test() {
+		eprintln!("Skipping error_paths_test, there is no Vulkan loader (libvulkan) to load the mock driver");
+	}
+}
+
+#[cfg(all(test, vulkan_loader))]
+mod tests {
+
+	use std::path::Path;
+	use std::process::Command;
+
+	// Each result line is used up by the next calls of the command, after that it succeeds again
+	const CONFIG: &str = "
+instance_extension VK_EXT_debug_report 9
+
+device mock
+type integrated
+queue_family 0x7 1
+memory_heap 1048576 0x1
+memory_type 0x7 0
+
+result vkCreateInstance VK_ERROR_INCOMPATIBLE_DRIVER
+result vkCreateDevice VK_ERROR_TOO_MANY_OBJECTS
+result vkCreateBuffer VK_ERROR_OUT_OF_DEVICE_MEMORY
+result vkCreateSemaphore VK_ERROR_OUT_OF_HOST_MEMORY
+result vkCreateFence VK_SUCCESS VK_ERROR_OUT_OF_DEVICE_MEMORY
+result vkQueueSubmit VK_ERROR_DEVICE_LOST
+";
+
+	// Builds the driver library in a target directory of its own, vkmock is only a cdylib so it can not be a dependency.
+	// Then points the loader at a copy of the manifest with the path of that library
+	fn use_built_driver() {
+
+		let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("vkmock");
+		let status = Command::new(env!("CARGO"))
+			.args(["build", "--manifest-path", concat!(env!("CARGO_MANIFEST_DIR"), "/../vkmock/Cargo.toml"), "--target-dir"])
+			.arg(&target_dir)
+			.status()
+			.expect("Failed to run cargo");
+		assert!(status.success(), "Could not build the mock driver");
+		let library = target_dir.join("debug").join(format!("{}vkmock{}", std::env::consts::DLL_PREFIX, std::env::consts::DLL_SUFFIX));
+		assert!(library.is_file(), "Driver library {} has not been built", library.display());
+
+		let manifest = include_str!("../../vkmock/VkICD_rust_mock.json").replace("target/debug/libvkmock.so", &library.to_str().unwrap().replace('\\', "\\\\"));
+		let manifest_path = format!("{}/VkICD_rust_mock.json", env!("CARGO_TARGET_TMPDIR"));
+		std::fs::write(&manifest_path, manifest).expect("Could not write driver manifest");
+		let config_path = format!("{}/vkmock.cfg", env!("CARGO_TARGET_TMPDIR"));
+		std::fs::write(&config_path, CONFIG).expect("Could not write driver configuration");
+		std::env::set_var("VK_ICD_FILENAMES", manifest_path);
+		std::env::set_var("VK_MOCK_ICD_CONFIG", config_path);
+	}
+
+	fn instance_builder() -> vk::InstanceBuilder {
+		vk::InstanceBuilder {
+			layers: vec![],
+			extensions: vec![vk::extension_name(vkraw::VK_EXT_DEBUG_REPORT_EXTENSION_NAME)],
+			..vk::InstanceBuilder::default()
+		}
+	}
+
+	#[test]
+	fn error_paths_test() {
+
+		use_built_driver();
+
+		// Extensions that are not in the registry, or are device extensions, are refused before the driver is called
+		let mut builder = instance_builder();
+		builder.extensions.push("VK_VENDOR_not_there".to_string());
+		assert_eq!(builder.create_instance().err(), Some(vkraw::VkResult::VK_ERROR_EXTENSION_NOT_PRESENT));
+		let mut builder = instance_builder();
+		builder.extensions.push(vk::extension_name(vkraw::VK_KHR_SWAPCHAIN_EXTENSION_NAME));
+		assert_eq!(builder.create_instance().err(), Some(vkraw::VkResult::VK_ERROR_EXTENSION_NOT_PRESENT));
+
+		// The driver refuses the first instance
+		assert_eq!(instance_builder().create_instance().err(), Some(vkraw::VkResult::VK_ERROR_INCOMPATIBLE_DRIVER));
+		let instance = instance_builder().create_instance().expect("Failed to create instance with the mock driver");
+
+		let mut device_builder = vk::DeviceBuilder::new(&instance);
+		device_builder.layers = vec![];
+		device_builder.extensions = vec![];
+		device_builder.physical_device = Some((instance.physical_devices().remove(0), 0));
+		assert_eq!(device_builder.create_device().err(), Some(vkraw::VkResult::VK_ERROR_TOO_MANY_OBJECTS));
+		let device = device_builder.create_device().expect("Failed to create device with the mock driver");
+
+		assert_eq!(device.create_buffer(256, vkraw::VkBufferUsageFlags::VK_BUFFER_USAGE_VERTEX_BUFFER_BIT).err(), Some(vkraw::VkResult::VK_ERROR_OUT_OF_DEVICE_MEMORY));
+		assert!(device.create_buffer(256, vkraw::VkBufferUsageFlags::VK_BUFFER_USAGE_VERTEX_BUFFER_BIT).is_ok());
+
+		assert_eq!(device.create_semaphore().err(), Some(vkraw::VkResult::VK_ERROR_OUT_OF_HOST_MEMORY));
+		let semaphore = device.create_semaphore().expect("Failed to create semaphore");
+
+		let fence = device.create_fence().expect("Failed to create fence");
+		assert_eq!(device.create_fence().err(), Some(vkraw::VkResult::VK_ERROR_OUT_OF_DEVICE_MEMORY));
+
+		let queue = device.get_queue(0, 0).expect("No queue");
+		assert_eq!(queue.submit(vec![], Some(&fence), vec![], vec![&semaphore]), vkraw::VkResult::VK_ERROR_DEVICE_LOST);
+		assert_eq!(queue.submit(vec![], Some(&fence), vec![], vec![&semaphore]), vkraw::VkResult::VK_SUCCESS);
+	}
+}
@@ -0,0 +1,361 @@
+
+// Validation of a vk.xml (e.g. one with local patches for vendor extensions)
+// Problems are reported with their position instead of panicking half way through generating
+
+use quick_xml::reader::Reader;
+use quick_xml::events::Event;
+use std::str;
+
+use std::collections::HashMap;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::depends;
+
+// See https://github.com/KhronosGroup/Vulkan-LoaderAndValidationLayers/blob/master/scripts/generator.py
+const EXTENSION_BASE_NUMBER: i64 = 1000000000;
+const EXTENSION_BLOCK_SIZE: i64 = 1000;
+
+// Something named somewhere in the registry that has to exist
+struct Reference {
+	name: String,
+	position: usize,
+	context: String
+}
+
+// 1 based line and column of a byte offset
+pub fn line_column(contents: &str, position: usize) -> (usize, usize) {
+	let before = &contents[..position];
+	let line = before.matches('\n').count() + 1;
+	let column = match before.rfind('\n') {
+		Some(line_start) => position - line_start,
+		None => position + 1
+	};
+	(line, column)
+}
+
+// Decimal or hex enum value, as written in vk.xml
+pub fn parse_enum_value(value: &str) -> Option<i64> {
+	let (negative, digits) = match value.strip_prefix('-') { Some(digits) => (true, digits), None => (false, value) };
+	let parsed = if let Some(hex) = digits.strip_prefix("0x") {
+		i64::from_str_radix(hex, 16).ok()
+	} else {
+		digits.parse::<i64>().ok()
+	};
+	parsed.map(|v| if negative { -v } else { v })
+}
+
+// Returns the problems found as "line:column: message", in file order
+pub fn check(contents: &str) -> Vec<String> {
+
+	let mut reader = Reader::from_str(contents);
+	reader.trim_text(true);
+
+	let mut buf = Vec::new();
+
+	// Open elements, innermost last, with the position of their start tags
+	let mut elements = Vec::<(String, usize)>::new();
+
+	let mut problems = Vec::<(usize, String)>::new();
+
+	// Definitions
+	let mut types = BTreeSet::<String>::new();
+	let mut structs = BTreeSet::<String>::new();
+	let mut enum_groups = BTreeSet::<String>::new();
+	let mut enum_names = BTreeSet::<String>::new();
+	let mut commands = BTreeSet::<String>::new();
+
+	// References to check once everything is defined
+	let mut type_references = Vec::<Reference>::new();
+	let mut type_aliases = Vec::<Reference>::new();
+	let mut struct_extends = Vec::<Reference>::new();
+	let mut enum_group_references = Vec::<Reference>::new();
+	let mut enum_references = Vec::<Reference>::new();
+	let mut enum_aliases = Vec::<Reference>::new();
+	let mut command_references = Vec::<Reference>::new();
+	let mut command_aliases = Vec::<Reference>::new();
+	let mut depends_references = Vec::<Reference>::new();
+
+	// Enum/bitmask name to its values (value, name)
+	let mut enum_values = BTreeMap::<String, BTreeMap<i64, String>>::new();
+	let mut enum_group = String::new();
+	let mut enum_group_type = String::new();
+
+	// Extension and feature (version) names
+	let mut extension_names = BTreeSet::<String>::new();
+
+	// Extension number to name
+	let mut extension_numbers = BTreeMap::<u32, String>::new();
+	let mut extension_name = String::new();
+	let mut extension_number: Option<u32> = None;
+
+	let mut type_name_position = 0;
+
+	let mut attributes: HashMap<String, String> = HashMap::new();
+
+	// Adds a value to an enum, reporting a collision with a different name
+	let mut add_enum_value = |problems: &mut Vec<(usize, String)>, group: &str, value: i64, name: &str, position: usize| {
+		let values = enum_values.entry(group.to_string()).or_default();
+		if let Some(existing) = values.get(&value) {
+			if existing != name {
+				problems.push((position, format!("value {} of {} in {} collides with {}", value, name, group, existing)));
+			}
+		} else {
+			values.insert(value, name.to_string());
+		}
+	};
+
+	loop {
+		let before = reader.buffer_position();
+		let event = reader.read_event(&mut buf);
+		let empty = matches!(event, Ok(Event::Empty(_)));
+
+		match event {
+			Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e)) => {
+
+				// The event starts at the first tag after the previous event
+				let position = before + contents[before..].find('<').unwrap_or(0);
+
+				attributes.clear();
+				for tmp in e.attributes().flatten() {
+					attributes.insert(str::from_utf8(tmp.key).unwrap_or("").to_string(), str::from_utf8(tmp.value).unwrap_or("").to_string());
+				}
+				let name = attributes.get("name").cloned().unwrap_or_default();
+				let parent = elements.last().map(|p| p.0.clone()).unwrap_or_default();
+
+				match e.name() {
+					b"enums" => {
+						enum_group = name.clone();
+						enum_group_type = attributes.get("type").cloned().unwrap_or_default();
+						if enum_group_type == "enum" || enum_group_type == "bitmask" {
+							enum_groups.insert(name.clone());
+						}
+					},
+					b"enum" => {
+						let is_vulkan = match attributes.get("api") {
+							Some(api) => api.split(',').any(|a| a == "vulkan"),
+							None => true
+						};
+
+						if parent == "enums" && is_vulkan {
+							enum_names.insert(name.clone());
+							if let Some(alias) = attributes.get("alias") {
+								enum_aliases.push(Reference { name: alias.clone(), position, context: format!("alias of {}", name) });
+							} else if enum_group_type == "enum" || enum_group_type == "bitmask" {
+								let value = if let Some(bitpos) = attributes.get("bitpos") {
+									bitpos.parse::<u32>().ok().filter(|b| *b < 64).map(|b| 1i64 << b)
+								} else {
+									attributes.get("value").and_then(|v| parse_enum_value(v))
+								};
+								match value {
+									Some(value) => add_enum_value(&mut problems, &enum_group, value, &name, position),
+									None => problems.push((position, format!("{} in {} has no valid value or bitpos", name, enum_group)))
+								}
+							}
+						} else if parent == "require" && is_vulkan {
+							if let Some(extends) = attributes.get("extends") {
+								enum_names.insert(name.clone());
+								enum_group_references.push(Reference { name: extends.clone(), position, context: format!("extended by {}", name) });
+
+								if let Some(alias) = attributes.get("alias") {
+									enum_aliases.push(Reference { name: alias.clone(), position, context: format!("alias of {}", name) });
+								} else {
+									let value = if let Some(offset) = attributes.get("offset") {
+										let number = match attributes.get("extnumber") {
+											Some(extnumber) => extnumber.parse::<u32>().ok(),
+											None => extension_number
+										};
+										match (number, offset.parse::<i64>()) {
+											(Some(number), Ok(offset)) => {
+												let value = EXTENSION_BASE_NUMBER + (number as i64 - 1) * EXTENSION_BLOCK_SIZE + offset;
+												Some(if attributes.get("dir").map(|d| d == "-").unwrap_or(false) { -value } else { value })
+											},
+											_ => None
+										}
+									} else if let Some(bitpos) = attributes.get("bitpos") {
+										bitpos.parse::<u32>().ok().filter(|b| *b < 64).map(|b| 1i64 << b)
+									} else {
+										attributes.get("value").and_then(|v| parse_enum_value(v))
+									};
+									match value {
+										Some(value) => add_enum_value(&mut problems, extends, value, &name, position),
+										None => problems.push((position, format!("{} extending {} has no valid offset, value or bitpos", name, extends)))
+									}
+								}
+							} else if attributes.contains_key("value") {
+								enum_names.insert(name.clone());
+							} else if let Some(alias) = attributes.get("alias") {
+								enum_names.insert(name.clone());
+								enum_aliases.push(Reference { name: alias.clone(), position, context: format!("alias of {}", name) });
+							} else {
+								enum_references.push(Reference { name: name.clone(), position, context: format!("required by {}", extension_name) });
+							}
+						}
+					},
+					b"type" => {
+						if parent == "types" {
+							if !name.is_empty() {
+								types.insert(name.clone());
+							}
+							if let Some(alias) = attributes.get("alias") {
+								type_aliases.push(Reference { name: alias.clone(), position, context: format!("alias of {}", name) });
+							}
+							let category = attributes.get("category").cloned().unwrap_or_default();
+							if category == "struct" || category == "union" {
+								structs.insert(name.clone());
+							}
+							if let Some(extends) = attributes.get("structextends") {
+								for target in extends.split(',') {
+									struct_extends.push(Reference { name: target.to_string(), position, context: format!("structextends of {}", name) });
+								}
+							}
+						} else if parent == "require" {
+							type_references.push(Reference { name: name.clone(), position, context: format!("required by {}", extension_name) });
+						}
+						type_name_position = position;
+					},
+					b"command" => {
+						if parent == "commands" {
+							if let Some(alias) = attributes.get("alias") {
+								commands.insert(name.clone());
+								command_aliases.push(Reference { name: alias.clone(), position, context: format!("alias of {}", name) });
+							}
+						} else if parent == "require" {
+							command_references.push(Reference { name: name.clone(), position, context: format!("required by {}", extension_name) });
+						}
+					},
+					b"feature" => {
+						extension_name = name.clone();
+						extension_number = None;
+						extension_names.insert(name.clone());
+					},
+					b"extension" => {
+						extension_name = name.clone();
+						extension_number = None;
+						extension_names.insert(name.clone());
+						match attributes.get("number").map(|n| n.parse::<u32>()) {
+							Some(Ok(number)) => {
+								if let Some(existing) = extension_numbers.get(&number) {
+									problems.push((position, format!("extension number {} of {} is already used by {}", number, name, existing)));
+								} else {
+									extension_numbers.insert(number, name.clone());
+								}
+								extension_number = Some(number);
+							},
+							_ => problems.push((position, format!("extension {} has no valid number", name)))
+						}
+					},
+					b"format" if parent == "formats" => {
+						enum_references.push(Reference { name: name.clone(), position, context: "described in formats".to_string() });
+					},
+					_ => ()
+				}
+
+				// depends="VK_KHR_a+(VK_KHR_b,VK_VERSION_1_1)" on extensions and their <require> blocks
+				if let Some(expression) = attributes.get("depends") {
+					let owner = if e.name() == b"extension" { name.clone() } else { extension_name.clone() };
+					match depends::parse(expression) {
+						Ok(parsed) => {
+							for dependency in parsed.names() {
+								depends_references.push(Reference { name: dependency, position, context: format!("dependency of {}", owner) });
+							}
+						},
+						Err(message) => problems.push((position, format!("invalid depends of {}: {}", owner, message)))
+					}
+				}
+
+				if !empty {
+					elements.push((str::from_utf8(e.name()).unwrap_or("").to_string(), position));
+				}
+			},
+			Ok(Event::Text(ref e)) => {
+				let text = e.unescape_and_decode(&reader).unwrap_or_default();
+				let element = elements.last().map(|p| p.0.clone()).unwrap_or_default();
+				let parent = if elements.len() > 1 { elements[elements.len() - 2].0.clone() } else { String::new() };
+				let grandparent = if elements.len() > 2 { elements[elements.len() - 3].0.clone() } else { String::new() };
+
+				// <type><name>blah</name></type> and <command><proto><name>blah</name></proto></command>
+				if element == "name" {
+					if parent == "type" && grandparent == "types" {
+						types.insert(text);
+					} else if parent == "proto" {
+						commands.insert(text);
+					}
+
+				// <member><type>blah</type></member>
+				} else if element == "type" && parent != "types" {
+					let context = if parent == "type" { "used in a type definition" } else { "used in a member, parameter or return type" };
+					type_references.push(Reference { name: text, position: type_name_position, context: context.to_string() });
+
+				// <member>...[<enum>blah</enum>]</member>
+				} else if element == "enum" && parent == "member" {
+					enum_references.push(Reference { name: text, position: elements.last().unwrap().1, context: "used as an array size".to_string() });
+				}
+			},
+			Ok(Event::End(_)) => {
+				elements.pop();
+			},
+			Ok(Event::Eof) => break,
+			Err(e) => {
+				problems.push((reader.buffer_position(), format!("xml error {:?}", e)));
+				break;
+			},
+			_ => ()
+		}
+
+		buf.clear();
+	}
+
+	for r in type_references {
+		if !types.contains(&r.name) {
+			problems.push((r.position, format!("unknown type {} ({})", r.name, r.context)));
+		}
+	}
+	for r in type_aliases {
+		if !types.contains(&r.name) {
+			problems.push((r.position, format!("dangling type alias {} ({})", r.name, r.context)));
+		}
+	}
+	for r in struct_extends {
+		if !structs.contains(&r.name) {
+			problems.push((r.position, format!("unknown struct {} ({})", r.name, r.context)));
+		}
+	}
+	for r in enum_group_references {
+		if !enum_groups.contains(&r.name) {
+			problems.push((r.position, format!("unknown enum {} ({})", r.name, r.context)));
+		}
+	}
+	for r in enum_references {
+		if !enum_names.contains(&r.name) {
+			problems.push((r.position, format!("unknown enum value {} ({})", r.name, r.context)));
+		}
+	}
+	for r in enum_aliases {
+		if !enum_names.contains(&r.name) {
+			problems.push((r.position, format!("dangling enum alias {} ({})", r.name, r.context)));
+		}
+	}
+	for r in command_references {
+		if !commands.contains(&r.name) {
+			problems.push((r.position, format!("unknown command {} ({})", r.name, r.context)));
+		}
+	}
+	for r in command_aliases {
+		if !commands.contains(&r.name) {
+			problems.push((r.position, format!("dangling command alias {} ({})", r.name, r.context)));
+		}
+	}
+
+	for r in depends_references {
+		if !extension_names.contains(&r.name) {
+			problems.push((r.position, format!("unknown extension or version {} ({})", r.name, r.context)));
+		}
+	}
+
+	problems.sort_by_key(|p| p.0);
+	problems.iter().map(|p| {
+		let (line, column) = line_column(contents, p.0);
+		format!("{}:{}: {}", line, column, p.1)
+	}).collect()
+}
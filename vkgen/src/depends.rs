@@ -0,0 +1,223 @@
+// Boolean dependency expressions of the registry's depends attributes, i.e.
+// depends="VK_KHR_get_physical_device_properties2+(VK_KHR_x,VK_VERSION_1_1)"
+// '+' is and, ',' is or (binding looser than '+') and parentheses group
+
+use std::fmt;
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Depends {
+	// An extension or a core version
+	Name(String),
+	All(Vec<Depends>),
+	Any(Vec<Depends>)
+}
+
+// Core versions (VK_VERSION_1_1, XR_VERSION_1_0) are always generated, so they are always met
+pub fn is_version(name: &str) -> bool {
+	name.split_once('_').map(|(_, rest)| rest.starts_with("VERSION_")).unwrap_or(false)
+}
+
+pub fn parse(expression: &str) -> Result<Depends, String> {
+	let tokens = tokenize(expression)?;
+	let mut position = 0;
+	let depends = parse_any(&tokens, &mut position)?;
+	if position < tokens.len() {
+		return Err(format!("unexpected \"{}\" in \"{}\"", tokens[position], expression));
+	}
+	Ok(depends)
+}
+
+fn tokenize(expression: &str) -> Result<Vec<String>, String> {
+	let mut tokens = Vec::<String>::new();
+	let mut name = String::new();
+	for c in expression.chars() {
+		match c {
+			'+' | ',' | '(' | ')' => {
+				if !name.is_empty() {
+					tokens.push(name.clone());
+					name.clear();
+				}
+				tokens.push(c.to_string());
+			},
+			c if c.is_alphanumeric() || c == '_' => name.push(c),
+			c if c.is_whitespace() => (),
+			c => return Err(format!("unexpected '{}' in \"{}\"", c, expression))
+		}
+	}
+	if !name.is_empty() {
+		tokens.push(name);
+	}
+	Ok(tokens)
+}
+
+fn parse_any(tokens: &[String], position: &mut usize) -> Result<Depends, String> {
+	let mut terms = Vec::<Depends>::new();
+	loop {
+		// (A,B),C is A,B,C
+		match parse_all(tokens, position)? {
+			Depends::Any(inner) => terms.extend(inner),
+			term => terms.push(term)
+		}
+		if *position < tokens.len() && tokens[*position] == "," {
+			*position += 1;
+		} else {
+			break;
+		}
+	}
+	Ok(if terms.len() == 1 { terms.remove(0) } else { Depends::Any(terms) })
+}
+
+fn parse_all(tokens: &[String], position: &mut usize) -> Result<Depends, String> {
+	let mut terms = Vec::<Depends>::new();
+	loop {
+		match parse_term(tokens, position)? {
+			Depends::All(inner) => terms.extend(inner),
+			term => terms.push(term)
+		}
+		if *position < tokens.len() && tokens[*position] == "+" {
+			*position += 1;
+		} else {
+			break;
+		}
+	}
+	Ok(if terms.len() == 1 { terms.remove(0) } else { Depends::All(terms) })
+}
+
+fn parse_term(tokens: &[String], position: &mut usize) -> Result<Depends, String> {
+	match tokens.get(*position).map(|t| t.as_str()) {
+		Some("(") => {
+			*position += 1;
+			let depends = parse_any(tokens, position)?;
+			if tokens.get(*position).map(|t| t.as_str()) != Some(")") {
+				return Err("missing ')'".to_string());
+			}
+			*position += 1;
+			Ok(depends)
+		},
+		Some("+") | Some(",") | Some(")") => Err(format!("unexpected \"{}\"", tokens[*position])),
+		Some(name) => {
+			*position += 1;
+			Ok(Depends::Name(name.to_string()))
+		},
+		None => Err("expression ends early".to_string())
+	}
+}
+
+impl Depends {
+
+	// Every name in the expression, in order
+	pub fn names(&self) -> Vec<String> {
+		match *self {
+			Depends::Name(ref name) => vec![name.clone()],
+			Depends::All(ref terms) | Depends::Any(ref terms) => terms.iter().flat_map(|t| t.names()).collect()
+		}
+	}
+
+	// Extensions that are needed whichever way the expression is met
+	pub fn required(&self) -> Vec<String> {
+		match *self {
+			Depends::Name(ref name) => if is_version(name) { vec![] } else { vec![name.clone()] },
+			Depends::All(ref terms) => terms.iter().flat_map(|t| t.required()).collect(),
+			Depends::Any(_) => vec![]
+		}
+	}
+
+	// Extensions a cargo feature turns on so the expression is met, available says which extensions have a feature.
+	// An or that a core version meets needs nothing, otherwise its first alternative that can be met is taken,
+	// and notes says so. Extensions that are needed but have no feature are added to missing
+	pub fn enabling(&self, available: &dyn Fn(&str) -> bool, notes: &mut Vec<String>, missing: &mut Vec<String>) -> Vec<String> {
+		match *self {
+			Depends::Name(ref name) => {
+				if is_version(name) {
+					vec![]
+				} else if available(name) {
+					vec![name.clone()]
+				} else {
+					missing.push(name.clone());
+					vec![]
+				}
+			},
+			Depends::All(ref terms) => terms.iter().flat_map(|t| t.enabling(available, notes, missing)).collect(),
+			Depends::Any(ref terms) => {
+				let mut met = Vec::<Vec<String>>::new();
+				let mut first_missing = None;
+				for term in terms {
+					let mut term_missing = vec![];
+					let enabled = term.enabling(available, notes, &mut term_missing);
+					if !term_missing.is_empty() {
+						first_missing.get_or_insert(term_missing);
+					} else if enabled.is_empty() {
+						return vec![];
+					} else {
+						met.push(enabled);
+					}
+				}
+				if met.len() > 1 {
+					notes.push(format!("needs {}, the feature turns on {}", self, met[0].join(" and ")));
+				}
+				match met.into_iter().next() {
+					Some(enabled) => enabled,
+					None => {
+						missing.extend(first_missing.unwrap_or_default());
+						vec![]
+					}
+				}
+			}
+		}
+	}
+
+	// cfg predicate on the cargo features, None when it is always met
+	pub fn cfg(&self) -> Option<String> {
+		match *self {
+			Depends::Name(ref name) => if is_version(name) { None } else { Some(format!("feature = \"{}\"", name)) },
+			Depends::All(ref terms) => {
+				let predicates: Vec<String> = terms.iter().filter_map(|t| t.cfg()).collect();
+				match predicates.len() {
+					0 => None,
+					1 => Some(predicates[0].clone()),
+					_ => Some(format!("all({})", predicates.join(", ")))
+				}
+			},
+			Depends::Any(ref terms) => {
+				let predicates: Vec<Option<String>> = terms.iter().map(|t| t.cfg()).collect();
+				if predicates.iter().any(|p| p.is_none()) {
+					return None;
+				}
+				let predicates: Vec<String> = predicates.into_iter().map(|p| p.unwrap()).collect();
+				match predicates.len() {
+					1 => Some(predicates[0].clone()),
+					_ => Some(format!("any({})", predicates.join(", ")))
+				}
+			}
+		}
+	}
+}
+
+// Expression for something an extension provides from a <require> block with its own condition
+pub fn provider(extension: &str, condition: &str) -> String {
+	if condition.is_empty() {
+		extension.to_string()
+	} else {
+		format!("{}+({})", extension, condition)
+	}
+}
+
+// The expression as the registry writes it
+impl fmt::Display for Depends {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match *self {
+			Depends::Name(ref name) => write!(f, "{}", name),
+			Depends::All(ref terms) => {
+				let terms: Vec<String> = terms.iter().map(|t| match *t {
+					Depends::Any(_) => format!("({})", t),
+					_ => t.to_string()
+				}).collect();
+				write!(f, "{}", terms.join("+"))
+			},
+			Depends::Any(ref terms) => {
+				let terms: Vec<String> = terms.iter().map(|t| t.to_string()).collect();
+				write!(f, "{}", terms.join(","))
+			}
+		}
+	}
+}
@@ -0,0 +1,167 @@
+// The parsed registry as JSON (vkgen --emit json), for tools that need registry data without reading vk.xml themselves
+// Objects keep their members in the order they are added and arrays are in registry (or name) order, so the output
+// is the same for the same registry
+
+// Version of the layout of the output, bumped when something is removed or changes meaning (not when something is added)
+pub const FORMAT_VERSION: i128 = 1;
+
+pub enum Value {
+	Null,
+	Bool(bool),
+	Number(i128),
+	Float(f64),
+	String(String),
+	Array(Vec<Value>),
+	Object(Vec<(&'static str, Value)>)
+}
+
+impl Value {
+
+	pub fn string(value: &str) -> Value {
+		Value::String(value.to_string())
+	}
+
+	// Null for the empty strings the parser uses for missing attributes
+	pub fn optional(value: &str) -> Value {
+		if value.is_empty() { Value::Null } else { Value::string(value) }
+	}
+
+	pub fn strings<'a, I: IntoIterator<Item = &'a String>>(values: I) -> Value {
+		Value::Array(values.into_iter().map(|v| Value::string(v)).collect())
+	}
+
+	// The member called key of an object, Null if there is none
+	pub fn get(&self, key: &str) -> &Value {
+		match *self {
+			Value::Object(ref members) => members.iter().find(|m| m.0 == key).map(|m| &m.1).unwrap_or(&Value::Null),
+			_ => &Value::Null
+		}
+	}
+
+	pub fn as_str(&self) -> Option<&str> {
+		match *self {
+			Value::String(ref value) => Some(value),
+			_ => None
+		}
+	}
+
+	pub fn as_number(&self) -> Option<i128> {
+		match *self {
+			Value::Number(value) => Some(value),
+			_ => None
+		}
+	}
+
+	// The elements of an array, none for anything else
+	pub fn as_array(&self) -> &[Value] {
+		match *self {
+			Value::Array(ref values) => values,
+			_ => &[]
+		}
+	}
+
+	fn is_scalar(&self) -> bool {
+		!matches!(*self, Value::Array(_) | Value::Object(_))
+	}
+
+	// Arrays and objects of scalars (and of arrays of scalars) are written on one line
+	fn is_flat(&self) -> bool {
+		match *self {
+			Value::Array(ref values) => values.iter().all(|v| v.is_scalar()),
+			Value::Object(ref members) => members.iter().all(|m| m.1.is_scalar() || (m.1.is_flat() && matches!(m.1, Value::Array(_)))),
+			_ => true
+		}
+	}
+
+	fn write(&self, out: &mut String, indent: usize) {
+		let flat = self.is_flat();
+		match *self {
+			Value::Null => out.push_str("null"),
+			Value::Bool(value) => out.push_str(if value { "true" } else { "false" }),
+			Value::Number(value) => out.push_str(&value.to_string()),
+			Value::Float(value) => out.push_str(&format!("{:?}", value)),
+			Value::String(ref value) => write_string(out, value),
+			Value::Array(ref values) => {
+				out.push('[');
+				for (i, value) in values.iter().enumerate() {
+					separate(out, i, flat, indent + 1);
+					value.write(out, indent + 1);
+				}
+				separate(out, 0, flat || values.is_empty(), indent);
+				out.push(']');
+			},
+			Value::Object(ref members) => {
+				out.push('{');
+				for (i, member) in members.iter().enumerate() {
+					separate(out, i, flat, indent + 1);
+					write_string(out, member.0);
+					out.push_str(": ");
+					member.1.write(out, indent + 1);
+				}
+				separate(out, 0, flat || members.is_empty(), indent);
+				out.push('}');
+			}
+		}
+	}
+}
+
+// Before the i-th element of an array or object (or before its closing bracket, i = 0)
+fn separate(out: &mut String, i: usize, flat: bool, indent: usize) {
+	if i > 0 {
+		out.push(',');
+		if flat {
+			out.push(' ');
+		}
+	}
+	if !flat {
+		out.push('\n');
+		out.push_str(&"\t".repeat(indent));
+	}
+}
+
+fn write_string(out: &mut String, value: &str) {
+	out.push('"');
+	for c in value.chars() {
+		match c {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\n' => out.push_str("\\n"),
+			'\r' => out.push_str("\\r"),
+			'\t' => out.push_str("\\t"),
+			c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+			c => out.push(c)
+		}
+	}
+	out.push('"');
+}
+
+// The number a constant's C expression stands for, i.e. "(~0U-1)" or "1000.0f", None for an expression that is not one
+pub fn c_number(expression: &str) -> Option<Value> {
+
+	let expression = expression.trim_start_matches('(').trim_end_matches(')');
+	if expression.ends_with('f') && expression.contains('.') {
+		return expression.trim_end_matches('f').parse::<f64>().ok().map(Value::Float);
+	}
+
+	let (expression, subtracted) = match expression.split_once('-') {
+		Some((expression, subtracted)) => (expression, subtracted.parse::<i128>().ok()?),
+		None => (expression, 0)
+	};
+	let max = if expression.ends_with("LL") { u64::MAX as i128 } else { u32::MAX as i128 };
+	let expression = expression.trim_end_matches(&['U', 'L'][..]);
+	let value = match expression.strip_prefix('~') {
+		Some(complemented) => max ^ complemented.parse::<i128>().ok()?,
+		None => match expression.strip_prefix("0x") {
+			Some(hex) => i128::from_str_radix(hex, 16).ok()?,
+			None => expression.parse::<i128>().ok()?
+		}
+	};
+	Some(Value::Number(value - subtracted))
+}
+
+pub fn to_string(value: &Value) -> String {
+	let mut out = String::new();
+	value.write(&mut out, 0);
+	out.push('\n');
+	out
+}
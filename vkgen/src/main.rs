@@ -1,40 +1,42 @@
 
 use quick_xml::reader::Reader;
-use quick_xml::events::Event;
+use quick_xml::events::{BytesStart, Event};
 use std::fmt::Write;
 use std::str;
 
 use std::io::prelude::*;
 use std::collections::HashMap;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 
 use inflector::cases::screamingsnakecase::to_screaming_snake_case;
+use inflector::cases::snakecase::to_snake_case;
 
 use regex::Regex;
 
-// c type mappings to rust style
-fn guess_type_from_name(name: &String) -> String {
-	match name.as_ref() {
-		"VK_WHOLE_SIZE" => "u64".to_string(),
-		"VK_LOD_CLAMP_NONE" => "f32".to_string(),
-		"VK_TRUE" => "u32".to_string(),
-		"VK_FALSE" => "u32".to_string(),
-		_ => "usize".to_string()
-	}
-}
+mod check;
+mod depends;
+mod json;
+mod overlay;
+mod profile;
+mod query;
+
+use profile::Profile;
 
+// c type mappings to rust style
 fn c_wsi_types_to_rust_types(type_name: &String) -> String {
 	match type_name.as_ref() {
 		"xcb_connection_t" => "pub type xcb_connection_t = xcb::ffi::xcb_connection_t;".to_string(),
-//		"xcb_visualid_t " => "pub xcb_visualid_t = xcb::ffi::xcb_connection_t;".to_string(),
+		"xcb_visualid_t" => "pub type xcb_visualid_t = u32;".to_string(),
 		"xcb_window_t" => "pub type xcb_window_t = u32;".to_string(),
-		_ => format!("pub type {} = u64;", type_name.to_string())
+		_ => format!("pub type {} = u64;", type_name)
 	}
 }
 
 fn c_wsi_types_to_cfg_var(type_name: &String) -> String {
 	match type_name.as_ref() {
 		"xcb_connection_t" => "xcb".to_string(),
-		"xcb_visualid_t " => "xcb".to_string(),
+		"xcb_visualid_t" => "xcb".to_string(),
 		"xcb_window_t" => "xcb".to_string(),
 		_ => "".to_string()
 	}
@@ -44,6 +46,9 @@ fn translate_types(original_type: String) -> String {
 	match original_type.as_ref() {
 		"void" => "c_void".to_string(),
 		"uint32_t" => "u32".to_string(),
+		"int8_t" => "i8".to_string(),
+		"uint16_t" => "u16".to_string(),
+		"int16_t" => "i16".to_string(),
 		"int32_t" => "i32".to_string(),
 		"int" => "i32".to_string(),
 		"uint64_t" => "u64".to_string(),
@@ -51,19 +56,35 @@ fn translate_types(original_type: String) -> String {
 		"char" => "u8".to_string(),
 		"uint8_t" => "u8".to_string(),
 		"float" => "f32".to_string(),
+		"double" => "f64".to_string(),
+		"size_t" => "usize".to_string(),
 		"VkBool32" => "VkBool32".to_string(),
 		_ => original_type.to_string()
 	}
 }
 
-fn translate_define(define: (String, String)) -> String {
+// The return type of a command for its signature, commands that return void return ()
+fn returns(return_type: &str) -> String {
+	if return_type == "()" {
+		String::new()
+	} else {
+		format!(" -> {}", return_type)
+	}
+}
+
+fn translate_define(define: (String, String), profile: &Profile) -> String {
 
 	// TODO: this is the wrong way to use XML
 	let re = Regex::new("[0-9]+$").unwrap();
+	let version_re = Regex::new(r"\(([0-9]+), *([0-9]+), *([0-9]+)\)").unwrap();
 
 	match define.0.as_ref() {
-		"VK_HEADER_VERSION" => format!("\npub const VK_HEADER_VERSION: i32 = {};", re.captures(&define.1).unwrap().get(0).unwrap().as_str()).to_string(),
-		"VK_NULL_HANDLE" => "\npub const VK_NULL_HANDLE: u64 = 0;".to_string(),
+		name if name == format!("{}HEADER_VERSION", profile.constant_prefix) => format!("\npub const {}: i32 = {};", name, re.captures(&define.1).unwrap().get(0).unwrap().as_str()).to_string(),
+		name if name == format!("{}CURRENT_API_VERSION", profile.constant_prefix) => match version_re.captures(&define.1) {
+			Some(version) => format!("\npub const {}: u64 = {}MAKE_VERSION({}, {}, {});", name, profile.constant_prefix, &version[1], &version[2], &version[3]),
+			None => String::new()
+		},
+		name if name == format!("{}NULL_HANDLE", profile.constant_prefix) => format!("\npub const {}: u64 = 0;", name),
 		"ANativeWindow" => "\npub type ANativeWindow = u64;".to_string(),
 		"AHardwareBuffer" => "\npub type AHardwareBuffer = u64;".to_string(),
 		_ => String::new()
@@ -87,17 +108,662 @@ fn translate_parameter_name(original_name: String) -> String {
 fn translate_values(original_value: String) -> String {
 	match original_value.as_ref() {
 		"1000.0f" => "1000.0".to_string(),
-		"(~0U)" => "::std::usize::MAX".to_string(),
-		"(~0ULL)" => "::std::u64::MAX".to_string(),
-		"(~0U-1)" => "::std::usize::MAX - 1".to_string(),
-		"(~0U-2)" => "::std::usize::MAX - 2".to_string(),
+		"(~0U)" => "::core::usize::MAX".to_string(),
+		"(~0ULL)" => "::core::u64::MAX".to_string(),
+		"(~0U-1)" => "::core::usize::MAX - 1".to_string(),
+		"(~0U-2)" => "::core::usize::MAX - 2".to_string(),
+		"0x7fffffffffffffffLL" => "0x7fffffffffffffff".to_string(),
 		_ => original_value.to_string()
 	}
 }
 
+// Members of a struct or union, used to work out its C layout, to trace it and for --emit json
+struct LayoutMember {
+	name: String,
+	type_name: String,
+	ptr: bool,
+	ptr_ptr: bool,
+	is_const: bool,
+	array_sizes: Vec<String>,
+	bitfield: bool,
+	len: String,
+	optional: String,
+	noautovalidity: bool,
+
+	// A char array, a fixed size string
+	char_array: bool
+}
+
+struct LayoutType {
+	union: bool,
+	members: Vec<LayoutMember>,
+
+	// VkStructureType value of the sType member, if it has one
+	structure_type: String,
+
+	// The structs it can be in the pNext chain of (structextends)
+	extends: Vec<String>
+}
+
+// Parameters of a command, used to find the two-call enumerations and to trace calls
+struct CommandParam {
+	name: String,
+	declaration: String,
+	type_name: String,
+	ptr: bool,
+	ptr_ptr: bool,
+	is_const: bool,
+	array_size: String,
+	len: String,
+	optional: String,
+	noautovalidity: bool
+}
+
+// Element type of a command that is called once for the count and again for the values
+// i.e. the last two parameters are (uint32_t* pCount, T* pValues) with len="pCount"
+// T with an sType needs initialising before the second call, so its VkStructureType value is returned too
+fn enumeration_element_type(params: &[CommandParam], layout_types: &BTreeMap<String, LayoutType>) -> Option<(String, Option<String>)> {
+
+	if params.len() < 2 {
+		return None;
+	}
+	let count = &params[params.len() - 2];
+	let values = &params[params.len() - 1];
+
+	if !count.ptr || count.is_const || count.type_name != "u32" || !count.optional.starts_with("false,true") {
+		return None;
+	}
+	if !values.ptr || values.is_const || values.len != count.name || values.type_name == "c_void" {
+		return None;
+	}
+	match layout_types.get(&values.type_name) {
+		Some(layout_type) if layout_type.members.iter().any(|m| m.name == "sType") => {
+			// Without a value for sType there is nothing to initialise it to
+			if layout_type.structure_type.is_empty() {
+				return None;
+			}
+			Some((values.type_name.clone(), Some(layout_type.structure_type.clone())))
+		},
+		_ => Some((values.type_name.clone(), None))
+	}
+}
+
+// Sets sType and pNext of each element the second call fills in, the rest of it is left to the command
+fn enumeration_initialise(structure_type: &Option<String>, indent: &str) -> String {
+
+	let mut initialise = String::new();
+	if let Some(structure_type) = structure_type {
+		writeln!(initialise, "{}for i in 0..count as usize {{", indent).expect("Could not format string");
+		writeln!(initialise, "{}\tlet value = values.as_mut_ptr().add(i);", indent).expect("Could not format string");
+		writeln!(initialise, "{}\t::core::ptr::addr_of_mut!((*value).sType).write(VkStructureType::{});", indent, structure_type).expect("Could not format string");
+		writeln!(initialise, "{}\t::core::ptr::addr_of_mut!((*value).pNext).write(::core::ptr::null_mut());", indent).expect("Could not format string");
+		writeln!(initialise, "{}}}", indent).expect("Could not format string");
+	}
+	initialise
+}
+
+// Wrapper for a two-call enumeration that returns the values in a Vec
+// Commands returning VkResult are retried while they return VK_INCOMPLETE
+fn enumeration_wrapper(name: &String, call: &String, receiver: &str, return_type: &String, params: &[CommandParam], element: &(String, Option<String>)) -> String {
+
+	let (element_type, structure_type) = element;
+
+	let inputs = &params[..params.len() - 2];
+	let declarations: Vec<&str> = inputs.iter().map(|p| p.declaration.as_ref()).collect();
+	let mut arguments: Vec<&str> = inputs.iter().map(|p| p.name.as_ref()).collect();
+	arguments.push("&mut count");
+
+	let mut wrapper = String::new();
+	if return_type == "VkResult" {
+		wrapper.write_fmt(format_args!("\tpub unsafe fn {}({}{}) -> Result<Vec<{}>, VkResult> {{\n", name, receiver, declarations.join(", "), element_type)).expect("Could not format string");
+		wrapper.write_fmt(format_args!("\t\tloop {{\n")).expect("Could not format string");
+		wrapper.write_fmt(format_args!("\t\t\tlet mut count = 0;\n")).expect("Could not format string");
+		wrapper.write_fmt(format_args!("\t\t\tlet res = {}({}, ::core::ptr::null_mut());\n", call, arguments.join(", "))).expect("Could not format string");
+		wrapper.write_fmt(format_args!("\t\t\tif res != VkResult::VK_SUCCESS {{\n\t\t\t\treturn Err(res);\n\t\t\t}}\n")).expect("Could not format string");
+		wrapper.write_fmt(format_args!("\t\t\tlet mut values = Vec::<{}>::with_capacity(count as usize);\n", element_type)).expect("Could not format string");
+		wrapper.push_str(&enumeration_initialise(structure_type, "\t\t\t"));
+		wrapper.write_fmt(format_args!("\t\t\tlet res = {}({}, values.as_mut_ptr());\n", call, arguments.join(", "))).expect("Could not format string");
+		wrapper.write_fmt(format_args!("\t\t\tif res == VkResult::VK_SUCCESS {{\n\t\t\t\tvalues.set_len(count as usize);\n\t\t\t\treturn Ok(values);\n")).expect("Could not format string");
+		wrapper.write_fmt(format_args!("\t\t\t}} else if res != VkResult::VK_INCOMPLETE {{\n\t\t\t\treturn Err(res);\n\t\t\t}}\n")).expect("Could not format string");
+		wrapper.write_fmt(format_args!("\t\t}}\n\t}}\n")).expect("Could not format string");
+	} else {
+		wrapper.write_fmt(format_args!("\tpub unsafe fn {}({}{}) -> Vec<{}> {{\n", name, receiver, declarations.join(", "), element_type)).expect("Could not format string");
+		wrapper.write_fmt(format_args!("\t\tlet mut count = 0;\n")).expect("Could not format string");
+		wrapper.write_fmt(format_args!("\t\t{}({}, ::core::ptr::null_mut());\n", call, arguments.join(", "))).expect("Could not format string");
+		wrapper.write_fmt(format_args!("\t\tlet mut values = Vec::<{}>::with_capacity(count as usize);\n", element_type)).expect("Could not format string");
+		wrapper.push_str(&enumeration_initialise(structure_type, "\t\t"));
+		wrapper.write_fmt(format_args!("\t\t{}({}, values.as_mut_ptr());\n", call, arguments.join(", "))).expect("Could not format string");
+		wrapper.write_fmt(format_args!("\t\tvalues.set_len(count as usize);\n\t\tvalues\n\t}}\n")).expect("Could not format string");
+	}
+	wrapper
+}
+
+// The targets the struct layouts are checked on, as the cfg predicate, the size of a pointer
+// and the alignment of 64-bit scalars in a struct (4 on 32-bit x86)
+struct LayoutTarget {
+	cfg: &'static str,
+	pointer_size: usize,
+	align64: usize
+}
+
+const LAYOUT_TARGETS: &[LayoutTarget] = &[
+	LayoutTarget { cfg: "target_pointer_width = \"64\"", pointer_size: 8, align64: 8 },
+	LayoutTarget { cfg: "all(target_pointer_width = \"32\", not(target_arch = \"x86\"))", pointer_size: 4, align64: 8 },
+	LayoutTarget { cfg: "target_arch = \"x86\"", pointer_size: 4, align64: 4 }
+];
+
+// Which of a layer's dispatch tables a command goes through, from the dispatchable handle it is called on
+// Commands that are not called on a handle (other than vkCreateInstance) never reach a layer's vkGetInstanceProcAddr
+#[derive(Copy, Clone, PartialEq)]
+enum DispatchLevel {
+	Instance,
+	Device
+}
+
+fn dispatch_level(name: &String, params: &[CommandParam]) -> Option<DispatchLevel> {
+
+	if name == "vkCreateInstance" {
+		return Some(DispatchLevel::Instance);
+	}
+	match params.first().map(|p| p.type_name.as_ref()) {
+		Some("VkInstance") | Some("VkPhysicalDevice") => Some(DispatchLevel::Instance),
+		Some("VkDevice") | Some("VkQueue") | Some("VkCommandBuffer") => Some(DispatchLevel::Device),
+		_ => None
+	}
+}
+
+// Handles that point at an object the loader writes its dispatch table into
+const DISPATCHABLE_HANDLES: [&str; 5] = ["VkInstance", "VkPhysicalDevice", "VkDevice", "VkQueue", "VkCommandBuffer"];
+
+// Commands the icd module has its own implementations of, named in snake case without the vk prefix
+const ICD_COMMANDS: [&str; 16] = [
+	"vkCreateInstance",
+	"vkEnumerateInstanceExtensionProperties",
+	"vkEnumerateInstanceVersion",
+	"vkEnumeratePhysicalDevices",
+	"vkGetPhysicalDeviceProperties",
+	"vkGetPhysicalDeviceFeatures",
+	"vkGetPhysicalDeviceMemoryProperties",
+	"vkGetPhysicalDeviceQueueFamilyProperties",
+	"vkEnumerateDeviceExtensionProperties",
+	"vkCreateDevice",
+	"vkGetDeviceQueue",
+	"vkAllocateMemory",
+	"vkFreeMemory",
+	"vkMapMemory",
+	"vkGetInstanceProcAddr",
+	"vkGetDeviceProcAddr"
+];
+
+// cfg attribute for something provided by any of the given extensions, nothing for core
+// Each provider is an extension name or a depends expression, see depends::provider
+fn extensions_cfg(providers: &[String]) -> String {
+	let mut predicates = Vec::<String>::new();
+	for provider in providers {
+		match depends::parse(provider).unwrap_or_else(|e| panic!("Invalid depends expression: {}", e)).cfg() {
+			Some(predicate) => predicates.push(predicate),
+			None => return String::new()
+		}
+	}
+	match predicates.len() {
+		0 => String::new(),
+		1 => format!("#[cfg({})]\n", predicates[0]),
+		_ => format!("#[cfg(any({}))]\n", predicates.join(", "))
+	}
+}
+
+// The command an alias stands for, following aliases of aliases (vkCmdDrawIndirectCountAMD to vkCmdDrawIndirectCountKHR to
+// vkCmdDrawIndirectCount), the name itself if it is not an alias
+fn command_alias_target(command_aliases: &[(String, String)], name: &str) -> String {
+	let mut target = name.to_string();
+	for _ in 0..command_aliases.len() {
+		match command_aliases.iter().find(|a| a.0 == target) {
+			Some(alias) => target = alias.1.clone(),
+			None => break
+		}
+	}
+	target
+}
+
+// Loader struct for an extension's commands, i.e. KhrSwapchainFn for VK_KHR_swapchain
+fn extension_struct_name(extension: &str, constant_prefix: &str) -> String {
+	let mut name = String::new();
+	for word in extension.trim_start_matches(constant_prefix).split('_') {
+		let mut chars = word.chars();
+		if let Some(first) = chars.next() {
+			name.push(first.to_ascii_uppercase());
+			name.push_str(&chars.as_str().to_ascii_lowercase());
+		}
+	}
+	name + "Fn"
+}
+
+// Number of elements for a len attribute, from one of the other members or parameters (siblings, with their pointer depth)
+// i.e. "count", "pCount" or "pInfo->count", anything else (latexmath etc.) is not followed
+fn len_count(len: &str, prefix: &str, siblings: &[(String, usize)]) -> Option<String> {
+	let len = len.split(',').next().unwrap_or("");
+	let (sibling, member) = match len.find("->") {
+		Some(i) => (&len[..i], Some(&len[i + 2..])),
+		None => (len, None)
+	};
+	let depth = siblings.iter().find(|s| s.0 == sibling)?.1;
+	match (depth, member) {
+		(0, None) => Some(format!("{}{} as usize", prefix, sibling)),
+		(1, None) => Some(format!("if {0}{1}.is_null() {{ 0 }} else {{ *{0}{1} as usize }}", prefix, sibling)),
+		(1, Some(member)) => Some(format!("if {0}{1}.is_null() {{ 0 }} else {{ (*{0}{1}).{2} as usize }}", prefix, sibling, member)),
+		_ => None
+	}
+}
+
+// How the traced module formats a value of a type
+#[derive(Copy, Clone, PartialEq)]
+enum TraceKind {
+	Handle,
+	Struct,
+	Debug,
+	Opaque
+}
+
+// What the traced module needs to know about the registry's types
+struct TraceTypes<'a> {
+	layout_types: &'a BTreeMap<String, LayoutType>,
+	type_layouts: &'a BTreeMap<String, (usize, usize)>,
+	handle_types: &'a Vec<String>,
+	structs: BTreeSet<String>
+}
+
+impl<'a> TraceTypes<'a> {
+
+	fn kind(&self, type_name: &String) -> TraceKind {
+		if self.handle_types.contains(type_name) {
+			TraceKind::Handle
+		} else if self.structs.contains(type_name) {
+			TraceKind::Struct
+		} else if c_scalar_type_layout(type_name).is_some() || self.type_layouts.contains_key(type_name) || type_name.starts_with("PFN_") ||
+			self.layout_types.get(type_name).map(|t| t.union).unwrap_or(false) {
+			TraceKind::Debug
+		} else {
+			TraceKind::Opaque
+		}
+	}
+
+	// Statement formatting place, an expression of type type_name, into out
+	fn element(&self, place: &str, type_name: &String) -> String {
+		match self.kind(type_name) {
+			TraceKind::Handle => format!("trace_handle(out, {})", place),
+			TraceKind::Struct => format!("trace_{}(out, &{})", type_name, place),
+			TraceKind::Debug => format!("trace_debug(out, &{})", place),
+			TraceKind::Opaque => format!("out.push_str(\"<{}>\")", type_name)
+		}
+	}
+
+	// Statement formatting a member or parameter into out, char arrays (fixed size strings) are only ever members
+	// Pointers are followed to structs, strings and len= arrays, unless the registry says they may not be valid
+	fn statement(&self, expr: &str, field: &TraceField, prefix: &str, siblings: &[(String, usize)]) -> String {
+		let TraceField { name, type_name, depth, is_const, array_sizes, char_array, len, valid } = *field;
+
+		let kind = self.kind(type_name);
+		let element = self.element("*element", type_name);
+
+		if depth == 0 {
+			if char_array {
+				format!("trace_char_array(out, &{})", expr)
+			} else if array_sizes.len() == 1 && (kind == TraceKind::Handle || kind == TraceKind::Struct) {
+				format!("trace_array(out, {0}.as_ptr(), {0}.len(), |out, element| {1})", expr, element)
+			} else if !array_sizes.is_empty() {
+				if kind == TraceKind::Debug { format!("trace_debug(out, &{})", expr) } else { "out.push_str(\"[..]\")".to_string() }
+			} else {
+				self.element(expr, type_name)
+			}
+		} else if depth == 1 {
+			let count = len_count(len, prefix, siblings);
+			if type_name == "c_void" && name == "pNext" {
+				format!("trace_chain(out, {} as *const c_void)", expr)
+			} else if type_name == "c_void" || !valid || kind == TraceKind::Opaque {
+				format!("trace_debug(out, &{})", expr)
+			} else if type_name == "u8" && (len.starts_with("null-terminated") || (len.is_empty() && is_const)) {
+				format!("trace_string(out, {} as *const u8)", expr)
+			} else if let Some(count) = count {
+				format!("trace_array(out, {} as *const {}, {}, |out, element| {})", expr, type_name, count, element)
+			} else if len.is_empty() {
+				format!("trace_pointer(out, {} as *const {}, |out, element| {})", expr, type_name, element)
+			} else {
+				format!("trace_debug(out, &{})", expr)
+			}
+		} else {
+			match len_count(len, prefix, siblings) {
+				Some(ref count) if type_name == "u8" && len.ends_with(",null-terminated") && valid => {
+					format!("trace_array(out, {} as *const *const u8, {}, |out, element| trace_string(out, *element))", expr, count)
+				},
+				_ => format!("trace_debug(out, &{})", expr)
+			}
+		}
+	}
+}
+
+// A member or parameter, as the trace module formats it
+struct TraceField<'a> {
+	name: &'a String,
+	type_name: &'a String,
+	depth: usize,
+	is_const: bool,
+	array_sizes: &'a [String],
+	char_array: bool,
+	len: &'a String,
+	valid: bool
+}
+
+// A member or parameter, as the validate module checks it
+struct ValidateField<'a> {
+	name: &'a String,
+	type_name: &'a String,
+	depth: usize,
+	is_const: bool,
+	array: bool,
+	len: &'a String,
+	optional: &'a String,
+	noautovalidity: bool
+}
+
+// What the validate module needs to know about the registry's types
+struct ValidateTypes<'a> {
+	layout_types: &'a BTreeMap<String, LayoutType>,
+	handle_types: &'a Vec<String>,
+	enums: BTreeSet<String>,
+	structs: BTreeSet<String>,
+	type_prefix: &'a str,
+	constant_prefix: &'a str,
+	newtype_enums: bool,
+
+	// The structs chain finds in pNext chains with their cfg, empty without chain
+	chain_structs: Vec<(String, String)>
+}
+
+impl<'a> ValidateTypes<'a> {
+
+	// Statement returning a Problem for the member (or parameter) name, path is the expression the path to its struct is in
+	fn fail(&self, path: &str, name: &str, problem: &str) -> String {
+		format!("return fail(command, {}, \"{}\", {});", path, name, problem)
+	}
+
+	// sType check of the struct expr points at, for structs the command writes to
+	fn structure_type(&self, expr: &str, type_name: &String, path: &str, name: &String) -> Option<String> {
+		let structure_type = &self.layout_types.get(type_name)?.structure_type;
+		if structure_type.is_empty() {
+			return None;
+		}
+		let expected = format!("{}StructureType::{}", self.type_prefix, structure_type);
+		Some(format!("if !{0}.is_null() && (*{0}).sType != {1} {{ {2} }}", expr, expected, self.fail(path, &format!("{}->sType", name), &format!("Problem::StructureType {{ expected: {}, found: (*{}).sType }}", expected, expr))))
+	}
+
+	// Statements checking the implicit valid usage of a struct's members or a command's parameters
+	// prefix is "value." for members, structure_type is the sType a struct must have
+	fn checks(&self, fields: &Vec<ValidateField>, prefix: &str, path: &str, structure_type: &String) -> Vec<String> {
+
+		let siblings: Vec<(String, usize)> = fields.iter().map(|f| (f.name.clone(), f.depth)).collect();
+		let mut checks = Vec::<String>::new();
+
+		for f in fields.iter().filter(|f| !f.noautovalidity && !f.array) {
+
+			let expr = format!("{}{}", prefix, f.name);
+			let required = f.optional.split(',').next() != Some("true");
+
+			if f.depth == 0 {
+
+				// Counts of arrays are not 0, unless they are optional
+				let counts_array = fields.iter().any(|a| a.depth > 0 && !a.noautovalidity && a.len.split(',').next() == Some(f.name.as_ref()));
+
+				// Rust enums can not hold values vkraw does not know, the value is read as an i32
+				if self.enums.contains(f.type_name) && !self.newtype_enums {
+					checks.push(format!("{{\n\t\t\tlet raw = *(::core::ptr::addr_of!({}) as *const i32);\n\t\t\tif !known_{}(raw) {{ {} }}\n\t\t}}", expr, f.type_name, self.fail(path, f.name, "Problem::EnumValue(raw)")));
+				}
+
+				if f.name == "sType" && !structure_type.is_empty() {
+					let expected = format!("{}StructureType::{}", self.type_prefix, structure_type);
+					checks.push(format!("if {} != {} {{ {} }}", expr, expected, self.fail(path, f.name, &format!("Problem::StructureType {{ expected: {}, found: {} }}", expected, expr))));
+				} else if self.enums.contains(f.type_name) && self.newtype_enums {
+					checks.push(format!("if {0}.known().is_none() {{ {1} }}", expr, self.fail(path, f.name, &format!("Problem::EnumValue({}.0)", expr))));
+				} else if self.handle_types.contains(f.type_name) && required {
+					checks.push(format!("if {} == {}NULL_HANDLE {{ {} }}", expr, self.constant_prefix, self.fail(path, f.name, "Problem::Null")));
+				} else if self.structs.contains(f.type_name) {
+					checks.push(format!("check_{}(&{}, command, &format!(\"{{}}{}.\", {}))?;", f.type_name, expr, f.name, path));
+				} else if counts_array && required && c_scalar_type_layout(f.type_name).is_some() {
+					checks.push(format!("if {} == 0 {{ {} }}", expr, self.fail(path, f.name, "Problem::ZeroCount")));
+				}
+
+			} else if f.name == "pNext" {
+
+				// The next struct in the chain checks the rest of it, structs are only checked where the command reads them
+				if !self.chain_structs.is_empty() {
+					let mut check = format!("if let Some(next) = chain({} as *const c_void).next() {{\n\t\t\tmatch next {{\n", expr);
+					for (name, cfg) in &self.chain_structs {
+						check.push_str(&format!("{}\t\t\t\tChainStruct::{}(next) => check_{}(next, command, &format!(\"{{}}pNext->\", {}))?,\n", cfg, name, name, path));
+					}
+					check.push_str("\t\t\t\t_ => ()\n\t\t\t}\n\t\t}");
+					checks.push(check);
+				}
+
+			} else if f.len.starts_with("null-terminated") || f.len.is_empty() {
+
+				if required {
+					checks.push(format!("if {}.is_null() {{ {} }}", expr, self.fail(path, f.name, "Problem::Null")));
+				}
+				if f.depth == 1 && f.len.is_empty() && self.structs.contains(f.type_name) {
+					if f.is_const {
+						checks.push(format!("if !{0}.is_null() {{ check_{1}(&*{0}, command, &format!(\"{{}}{2}->\", {3}))?; }}", expr, f.type_name, f.name, path));
+					} else if let Some(check) = self.structure_type(&expr, f.type_name, path, f.name) {
+						checks.push(check);
+					}
+				}
+
+			} else if let Some(count) = len_count(f.len, prefix, &siblings) {
+
+				// Arrays may be null when they are empty
+				if required {
+					checks.push(format!("if {} != 0 && {}.is_null() {{ {} }}", count, expr, self.fail(path, f.name, "Problem::Null")));
+				}
+				if f.depth == 1 && f.is_const && self.structs.contains(f.type_name) {
+					checks.push(format!("if !{0}.is_null() {{\n\t\t\tfor i in 0..{1} {{\n\t\t\t\tcheck_{2}(&*{0}.add(i), command, &format!(\"{{}}{3}[{{}}].\", {4}, i))?;\n\t\t\t}}\n\t\t}}", expr, count, f.type_name, f.name, path));
+				} else if f.depth == 1 && f.is_const && self.enums.contains(f.type_name) && self.newtype_enums {
+					checks.push(format!("if !{0}.is_null() {{\n\t\t\tfor i in 0..{1} {{\n\t\t\t\tlet element = *{0}.add(i);\n\t\t\t\tif element.known().is_none() {{ return fail(command, {2}, &format!(\"{3}[{{}}]\", i), Problem::EnumValue(element.0)); }}\n\t\t\t}}\n\t\t}}", expr, count, path, f.name));
+				} else if f.depth == 1 && f.is_const && self.enums.contains(f.type_name) {
+					checks.push(format!("if !{0}.is_null() {{\n\t\t\tfor i in 0..{1} {{\n\t\t\t\tlet element = *({0}.add(i) as *const i32);\n\t\t\t\tif !known_{4}(element) {{ return fail(command, {2}, &format!(\"{3}[{{}}]\", i), Problem::EnumValue(element)); }}\n\t\t\t}}\n\t\t}}", expr, count, path, f.name, f.type_name));
+				}
+			}
+		}
+		checks
+	}
+}
+
+// What the serde impls need to know about the registry's types
+struct SerdeTypes<'a> {
+	type_layouts: &'a BTreeMap<String, (usize, usize)>,
+	structs: BTreeSet<String>
+}
+
+impl<'a> SerdeTypes<'a> {
+
+	// Types that have Serialize and Deserialize: scalars, the types in type_layouts (enums, flags, handles, ...) and structs
+	fn serializable(&self, type_name: &String) -> bool {
+		c_scalar_type_layout(type_name).is_some() || self.type_layouts.contains_key(type_name) || self.structs.contains(type_name)
+	}
+
+	// Expression serializing a member, None for members that are skipped
+	// Pointers are followed to strings, structs and len= arrays, unless the registry says they may not be valid
+	fn serialize(&self, member: &LayoutMember, siblings: &[(String, usize)]) -> Option<String> {
+
+		let expr = format!("self.{}", member.name);
+		let depth = if member.ptr_ptr { 2 } else if member.ptr { 1 } else { 0 };
+
+		// Only counts that are members themselves, so serializing needs no unsafe
+		let count = match siblings.iter().find(|s| Some(s.0.as_ref()) == member.len.split(',').next()) {
+			Some(&(_, 0)) => len_count(&member.len, "self.", siblings),
+			_ => None
+		};
+
+		if member.bitfield || (depth > 0 && (member.name == "pNext" || member.noautovalidity)) {
+			None
+		} else if depth == 0 && member.char_array {
+			Some(format!("&CharArray(&{})", expr))
+		} else if depth == 1 && member.type_name == "u8" && (member.len.starts_with("null-terminated") || (member.len.is_empty() && member.is_const)) {
+			Some(format!("&NullTerminated({} as *const u8)", expr))
+		} else if depth == 2 && member.type_name == "u8" && member.len.ends_with(",null-terminated") && count.is_some() {
+			Some(format!("&Strings({} as *const *const u8, {})", expr, count.unwrap()))
+		} else if !self.serializable(&member.type_name) {
+			None
+		} else if depth == 0 && member.array_sizes.len() == 1 {
+			Some(format!("&{}[..]", expr))
+		} else if depth == 0 {
+			Some(format!("&{}", expr))
+		} else if depth == 1 && count.is_some() {
+			Some(format!("&Array({} as *const {}, {})", expr, member.type_name, count.unwrap()))
+		} else if depth == 1 && member.len.is_empty() {
+			Some(format!("&Pointer({} as *const {})", expr, member.type_name))
+		} else {
+			None
+		}
+	}
+
+	// Expression reading a member from a map, None for members that are left null (pointers) or zeroed (unions, function pointers, ...)
+	fn deserialize(&self, member: &LayoutMember) -> Option<String> {
+		if member.ptr || member.bitfield {
+			None
+		} else if member.char_array {
+			Some("char_array(&mut map)?".to_string())
+		} else if !self.serializable(&member.type_name) {
+			None
+		} else if member.array_sizes.len() == 1 {
+			Some("fixed_array(&mut map)?".to_string())
+		} else {
+			Some("map.next_value()?".to_string())
+		}
+	}
+}
+
+// Size and alignment of the (translated) scalar types that structs are built from, on a 64-bit target
+// (c_struct_layout makes usize pointer sized and aligns 64-bit scalars for the target). The registry's own base types (VkBool32 etc.) are in its profile
+fn c_scalar_type_layout(type_name: &String) -> Option<(usize, usize)> {
+	match type_name.as_ref() {
+		"u8" | "i8" => Some((1, 1)),
+		"u16" | "i16" => Some((2, 2)),
+		"u32" | "i32" | "f32" => Some((4, 4)),
+		"u64" | "i64" | "f64" | "usize" => Some((8, 8)),
+		"xcb_window_t" | "xcb_visualid_t" => Some((4, 4)),
+		_ => None
+	}
+}
+
+// Size, alignment and member offsets
+type StructLayout = (usize, usize, Vec<(String, usize)>);
+
+// C layout of a struct or union on a target
+// Err with the reason if it has a member whose layout is not known (platform types, bitfields), or that
+// does not match the bindings' type on the target (dispatchable handles are pointers in C, u64 in the bindings)
+fn c_struct_layout(name: &String, target: &LayoutTarget, layout_types: &BTreeMap<String, LayoutType>, type_layouts: &BTreeMap<String, (usize, usize)>, dispatchable_handles: &BTreeSet<String>, array_constants: &BTreeMap<String, usize>) -> Result<StructLayout, String> {
+
+	let layout_type = layout_types.get(name).ok_or_else(|| format!("the layout of {} is not known", name))?;
+
+	let mut size = 0;
+	let mut align = 1;
+	let mut offsets = vec![];
+	for member in &layout_type.members {
+
+		if member.bitfield {
+			return Err(format!("{}.{} is a bitfield", name, member.name));
+		}
+
+		// Function pointers are pointers too
+		let (member_size, member_align) = if member.ptr || member.type_name.starts_with("PFN_") || member.type_name == "usize" {
+			(target.pointer_size, target.pointer_size)
+		} else if dispatchable_handles.contains(&member.type_name) && target.pointer_size != 8 {
+			return Err(format!("{}.{} is a dispatchable handle, a pointer in C", name, member.name));
+		} else if let Some(&(size, align)) = type_layouts.get(&member.type_name) {
+			(size, std::cmp::min(align, target.align64))
+		} else if let Some((size, align)) = c_scalar_type_layout(&member.type_name) {
+			(size, std::cmp::min(align, target.align64))
+		} else {
+			let (struct_size, struct_align, _) = c_struct_layout(&member.type_name, target, layout_types, type_layouts, dispatchable_handles, array_constants)?;
+			(struct_size, struct_align)
+		};
+
+		let mut count = 1;
+		for array_size in &member.array_sizes {
+			count *= match array_size.parse::<usize>() {
+				Ok(n) => n,
+				Err(_) => *array_constants.get(array_size).ok_or_else(|| format!("the array size {} of {}.{} is not known", array_size, name, member.name))?
+			};
+		}
+
+		if layout_type.union {
+			offsets.push((member.name.clone(), 0));
+			size = std::cmp::max(size, member_size * count);
+		} else {
+			size = size.next_multiple_of(member_align);
+			offsets.push((member.name.clone(), size));
+			size += member_size * count;
+		}
+		align = std::cmp::max(align, member_align);
+	}
+	size = size.next_multiple_of(align);
+
+	Ok((size, align, offsets))
+}
+
+// Extensions for a platform also need the crate that provides the platform types
+// Platforms without a crate in vkraw's Cargo.toml have their types as u64 placeholders (see c_wsi_types_to_rust_types)
+fn c_platform_to_cargo_feature(platform: &String) -> String {
+	match platform.as_ref() {
+		"xcb" => "xcb".to_string(),
+		"win32" => "winapi".to_string(),
+		_ => "".to_string()
+	}
+}
+
+// Replace the generated part of the [features] table in a Cargo.toml
+// Each feature is (name, the features it turns on, notes written as comments above it)
+fn write_cargo_features(cargo_toml_filename: &str, features: &[(String, Vec<String>, Vec<String>)]) {
+
+	let begin_marker = "# BEGIN vkgen features";
+	let end_marker = "# END vkgen features";
+
+	let contents = std::fs::read_to_string(cargo_toml_filename).expect("Could not read Cargo.toml");
+	let begin = contents.find(begin_marker).expect("Cargo.toml has no \"# BEGIN vkgen features\" marker");
+	let end = contents.find(end_marker).expect("Cargo.toml has no \"# END vkgen features\" marker");
+	assert!(begin < end, "vkgen feature markers are in the wrong order");
+
+	let mut table = String::new();
+	for feature in features {
+		for note in &feature.2 {
+			table.write_fmt(format_args!("# {} {}\n", feature.0, note)).expect("Could not format string");
+		}
+		let deps: Vec<String> = feature.1.iter().map(|d| format!("\"{}\"", d)).collect();
+		table.write_fmt(format_args!("{} = [{}]\n", feature.0, deps.join(", "))).expect("Could not format string");
+	}
+
+	let line_end = begin + contents[begin..].find('\n').expect("Cargo.toml ends after the begin marker") + 1;
+	let patched = format!("{}{}{}", &contents[..line_end], table, &contents[end..]);
+	std::fs::write(cargo_toml_filename, patched).expect("Could not write Cargo.toml");
+}
+
+// Whether an element is for the profile's API, i.e. not one of the api="vulkansc" definitions
+// Extensions only supported by other APIs are left out too, disabled ones are kept
+fn for_api(e: &BytesStart, api: &str) -> bool {
+	for tmp in e.attributes().flatten() {
+		if tmp.key == b"api" || (e.name() == b"extension" && tmp.key == b"supported") {
+			let apis = str::from_utf8(tmp.value).unwrap_or("");
+			if apis != "disabled" && !apis.split(',').any(|a| a == api) {
+				return false;
+			}
+		}
+	}
+	true
+}
+
 fn help() {
 
-	println!("usage: ./main xml_input_filename (-o rs_output_filename)");
+	println!("usage: ./main xml_input_filename (-o rs_output_filename) (--cargo-toml vkraw_cargo_toml_filename) (--overlay overlay_xml_filename)... (--profile vulkan|openxr) (--traced) (--validate) (--serde) (--newtype-enums) (--include) (--emit rust|json)");
+	println!("       ./main check xml_input_filename");
+	println!("       ./main query xml_input_filename name (--overlay overlay_xml_filename)... (--profile vulkan|openxr)");
 }
 
 fn main() {
@@ -105,39 +771,153 @@ fn main() {
 	// Parse arguments
 	let args: Vec<String> = std::env::args().collect();
 
+	// Validate the registry instead of generating from it
+	if args.len() > 1 && args[1] == "check" {
+		if args.len() != 3 {
+			help();
+			return;
+		}
+
+		let contents = std::fs::read_to_string(&args[2]).expect("Could not read file");
+		let problems = check::check(&contents);
+		for problem in &problems {
+			println!("{}:{}", args[2], problem);
+		}
+		if !problems.is_empty() {
+			println!("{} problems found", problems.len());
+			std::process::exit(1);
+		}
+		println!("No problems found");
+		return;
+	}
+
+	// Describe a name from the registry instead of generating from it, the other arguments are as for generating
+	let mut args = args;
+	let mut query_name = String::new();
+	if args.len() > 1 && args[1] == "query" {
+		if args.len() < 4 {
+			help();
+			return;
+		}
+		query_name = args.remove(3);
+		args.remove(1);
+	}
+
 	let mut xml_filename = String::new();
 	let mut rs_filename = String::new();
-
-	match args.len() {
-		2 => {
-			xml_filename = args[1].to_string();
-		},
-		4 => {
-			xml_filename = args[1].to_string();
-			if args[2] == "-o" {
-				rs_filename = args[3].to_string();
-			} else {
-				help();
+	let mut cargo_toml_filename = String::new();
+	let mut overlay_filenames = Vec::<String>::new();
+	let mut traced = false;
+	let mut validate = false;
+	let mut serde = false;
+	let mut newtype_enums = false;
+	let mut include = false;
+	let mut profile = &profile::VULKAN;
+	let mut emit = "rust".to_string();
+
+	let mut arg_iter = args.iter().skip(1);
+	while let Some(arg) = arg_iter.next() {
+		match arg.as_ref() {
+			"-o" => {
+				rs_filename = arg_iter.next().expect("-o needs a filename").to_string();
+			},
+			"--cargo-toml" => {
+				cargo_toml_filename = arg_iter.next().expect("--cargo-toml needs a filename").to_string();
+			},
+			"--overlay" => {
+				overlay_filenames.push(arg_iter.next().expect("--overlay needs a filename").to_string());
+			},
+			"--profile" => {
+				let name = arg_iter.next().expect("--profile needs a name");
+				profile = profile::from_name(name).unwrap_or_else(|| panic!("Unknown profile \"{}\"", name));
+			},
+			"--traced" => {
+				traced = true;
+			},
+			"--validate" => {
+				validate = true;
+			},
+			"--serde" => {
+				serde = true;
+			},
+			"--newtype-enums" => {
+				newtype_enums = true;
+			},
+			"--include" => {
+				include = true;
+			},
+			"--emit" => {
+				emit = arg_iter.next().expect("--emit needs a format").to_string();
+				if emit != "rust" && emit != "json" {
+					panic!("Unknown output format \"{}\"", emit);
+				}
+			},
+			_ => {
+				if xml_filename.is_empty() {
+					xml_filename = arg.to_string();
+				} else {
+					help();
+					return;
+				}
 			}
-		},
-		_ => {
-			help();
 		}
 	}
 
-	println!("Using input xml file \"{}\"", xml_filename);
-	println!("Using output rs file \"{}\"", rs_filename);
+	if xml_filename.is_empty() {
+		help();
+		return;
+	}
+	if traced && !profile.frameworks {
+		println!("--traced is not supported with the {} profile", profile.name);
+		std::process::exit(1);
+	}
+	if validate && !profile.frameworks {
+		println!("--validate is not supported with the {} profile", profile.name);
+		std::process::exit(1);
+	}
+	if serde && !profile.frameworks {
+		println!("--serde is not supported with the {} profile", profile.name);
+		std::process::exit(1);
+	}
+
+	if query_name.is_empty() {
+		println!("Using input xml file \"{}\"", xml_filename);
+		println!("Using output rs file \"{}\"", rs_filename);
+		println!("Using profile \"{}\"", profile.name);
+		println!("Using output format \"{}\"", emit);
+		if !cargo_toml_filename.is_empty() {
+			println!("Using Cargo.toml \"{}\"", cargo_toml_filename);
+		}
+		for overlay_filename in &overlay_filenames {
+			println!("Using overlay xml file \"{}\"", overlay_filename);
+		}
+	}
 
 	let mut f = std::fs::File::open(xml_filename).expect("Failed to open file");
 	let mut contents = String::new();
 	f.read_to_string(&mut contents).expect("Could not read file");
 
-	let mut reader = Reader::from_str(&*contents);
+	// Merge the overlays in the order they were given
+	for overlay_filename in &overlay_filenames {
+		let overlay = std::fs::read_to_string(overlay_filename).expect("Could not read overlay file");
+		match overlay::merge(&contents, &overlay) {
+			Ok(merged) => contents = merged,
+			Err(conflicts) => {
+				for conflict in &conflicts {
+					println!("{}:{}", overlay_filename, conflict);
+				}
+				println!("Could not merge overlay \"{}\"", overlay_filename);
+				std::process::exit(1);
+			}
+		}
+	}
+
+	let mut reader = Reader::from_str(&contents);
 	reader.trim_text(true);
 
 	let mut buf = Vec::new();
 
-	let array_regex = regex::Regex::new(r"\[([0-9+])\]").unwrap();
+	let array_regex = regex::Regex::new(r"\[([0-9]+)\]").unwrap();
 
 	// List of what we are matching against:
 	// <a><b><c>
@@ -160,17 +940,29 @@ fn main() {
 	let mut param_const = false;
 	let mut param_array = false;
 	let mut param_array_size = String::new();
+	let mut param_len = String::new();
+	let mut param_optional = String::new();
+	let mut param_noautovalidity = false;
+	let mut params = Vec::<CommandParam>::new();
 
 	let mut struct_name = String::new();
 	let mut struct_member_name = String::new();
 	let mut struct_member_type = String::new();
+	let mut struct_member_c_type = String::new();
 	let mut struct_member_ptr = false;
 	let mut struct_member_ptr_ptr = false;
 	let mut struct_member_const = false;
 	let mut struct_members = String::new();
 	let mut struct_contains_arrays = false;
-	let mut struct_member_array = false;
-	let mut struct_member_array_size = String::new();
+	let mut struct_member_array_sizes = Vec::<String>::new();
+	let mut struct_member_bitfield = false;
+	let mut struct_member_len = String::new();
+	let mut struct_member_noautovalidity = false;
+	let mut struct_member_optional = String::new();
+	let mut struct_member_values = String::new();
+	let mut struct_structure_type = String::new();
+	let mut struct_extends = Vec::<String>::new();
+	let mut layout_members = Vec::<LayoutMember>::new();
 
 	let mut enum_name = String::new();
 
@@ -179,6 +971,10 @@ fn main() {
 	let mut type_category = String::new();
 	let mut type_name = String::new();
 	let mut type_requires = String::new();
+	// The macro a handle is defined with, VK_DEFINE_HANDLE ones are pointers in C
+	let mut type_define = String::new();
+	let mut type_parent = String::new();
+	let mut type_objtypeenum = String::new();
 
 	let mut define_type_value = String::new();
 
@@ -188,18 +984,31 @@ fn main() {
 	let mut api_constants = Vec::<(String, String)>::new();
 
 	// name, parameters, return type
-	let mut commands = HashMap::new();
+	let mut commands = BTreeMap::<String, (String, String)>::new();
+	let mut command_params = BTreeMap::<String, Vec<CommandParam>>::new();
 
 	// name
 	let mut types = Vec::<String>::new();
 	let mut bitmask_types = Vec::<(String, String)>::new();
 	let mut handle_types = Vec::<String>::new();
+	let mut dispatchable_handles = BTreeSet::<String>::new();
 	let mut define_types = Vec::<(String, String)>::new();
 
+	// Handle name to its parent handle and its object type value (objtypeenum, or the object type value commented with its name)
+	let mut handle_parents = BTreeMap::<String, String>::new();
+	let mut handle_object_types = BTreeMap::<String, String>::new();
+
+	// name, name of the type or command it is an alias of
+	let mut type_aliases = Vec::<(String, String)>::new();
+	let mut command_aliases = Vec::<(String, String)>::new();
+
 	// Enums
 	struct Enum {
 		name: String,
-		values: Vec<(String, i32)>
+		values: Vec<(String, i32)>,
+
+		// name, name of the value it is an alias of
+		aliases: Vec<(String, String)>
 	}
 	let mut enums = Vec::<Enum>::new();
 
@@ -216,6 +1025,9 @@ fn main() {
 	// name, members, contains arrays
 	let mut structs = Vec::<(String, String, bool)>::new();
 
+	// Structs and unions by name
+	let mut layout_types = BTreeMap::<String, LayoutType>::new();
+
 	// Features
 	enum FeatureContent {
 		Command(String),
@@ -224,16 +1036,23 @@ fn main() {
 	}
 
 	struct FeatureBlock {
+
+		// The <feature> the <require> block is in, i.e. VK_VERSION_1_0
+		feature: String,
 		comment: String,
 		contents: Vec<FeatureContent>
 	}
 
 	let mut features = Vec::<FeatureBlock>::new();
+	let mut feature_name = String::new();
 
 	// Extensions
 	enum ExtensionNewType {
 		EnumExtension { name: String, offset: u32, extends: String, comment: String, dir: String },
-		BitflagsExtension { name: String, bitpos: u32, extends: String, comment: String },
+		BitflagsExtension { name: String, bitpos: u32, extends: String },
+		EnumAlias { name: String, alias: String, extends: String },
+		Constant { name: String, value: String },
+		ConstantAlias { name: String, alias: String },
 		Command(String),
 		Type(String)
 	}
@@ -242,22 +1061,84 @@ fn main() {
 		name: String,
 		number: u32,
 		extension_type: String,
-		author: String,
-		contact: String,
 		supported: String,
+		platform: String,
+		requires: Vec<String>,
+
+		// Boolean expression of the extensions and versions it depends on, see depends.rs
+		depends: String,
+
+		// Commands and types from <require> blocks with their own depends, with the condition
+		conditions: BTreeMap<String, String>,
+
+		promoted_to: String,
+		deprecated_by: String,
+		obsoleted_by: String,
+		spec_version: u32,
 		types: Vec<ExtensionNewType>
 	}
 
+	impl Extension {
+
+		// What a command or type of the extension is generated for, see depends::provider
+		fn provider(&self, name: &String) -> String {
+			depends::provider(&self.name, self.conditions.get(name).map(|c| c.as_str()).unwrap_or(""))
+		}
+	}
+
 	let mut extensions = Vec::<Extension>::new();
 
+	// Formats, from <formats>
+	struct FormatComponent {
+		name: String,
+
+		// Number of bits, or "compressed"
+		bits: String,
+		numeric_format: String
+	}
+
+	struct FormatPlane {
+		index: u32,
+		width_divisor: u32,
+		height_divisor: u32,
+		compatible: String
+	}
+
+	struct Format {
+		name: String,
+		class: String,
+		block_size: u32,
+		texels_per_block: u32,
+		block_extent: (u32, u32, u32),
+
+		// Compression scheme, i.e. "BC" or "ASTC LDR", empty if it is not compressed
+		compressed: String,
+		packed: String,
+		chroma: String,
+		components: Vec<FormatComponent>,
+		planes: Vec<FormatPlane>
+	}
+
+	let mut formats = Vec::<Format>::new();
+
 	// TODO: hack
 	let mut require_feature = String::new();
 
+	// Condition of the current <require> block of an extension (depends, or extension in older registries)
+	let mut require_depends = String::new();
+
 	let mut attributes: HashMap<String, String> = HashMap::new();
 
+	// Depth inside an element for another API, which is skipped with everything in it
+	let mut skipped_depth = 0;
+
 	// Loop over each xml element
 	loop {
 		match reader.read_event(&mut buf) {
+			Ok(Event::Start(ref e)) if skipped_depth > 0 || !for_api(e, profile.name) => skipped_depth += 1,
+			Ok(Event::End(_)) if skipped_depth > 0 => skipped_depth -= 1,
+			Ok(Event::Empty(ref e)) if skipped_depth > 0 || !for_api(e, profile.name) => (),
+			Ok(Event::Text(_)) if skipped_depth > 0 => (),
 			Ok(Event::Start(ref e)) => {
 
 				attributes.clear();
@@ -267,66 +1148,77 @@ fn main() {
 				}
 
 				match e.name() {
-					b"enums" => {
-						if matching_what[0] == "registry" {
-							let mut etype = "";
-							if let Some(etypes) = attributes.get("type") {
-								etype = etypes;
-							}
-							if let Some(name) = attributes.get("name") {
-								enum_name = name.to_string();
-							}
-							if etype == "enum" {
-								enum_type_bitmask = false;
-								enums.push(Enum{
-									name: enum_name.clone(),
-									values: vec![]
-								});
+					b"enums" if matching_what[0] == "registry" => {
+						let mut etype = "";
+						if let Some(etypes) = attributes.get("type") {
+							etype = etypes;
+						}
+						if let Some(name) = attributes.get("name") {
+							enum_name = name.to_string();
+						}
+						if etype == "enum" {
+							enum_type_bitmask = false;
+							enums.push(Enum{
+								name: enum_name.clone(),
+								values: vec![],
+								aliases: vec![]
+							});
+
+						} else if etype == "bitmask" {
+							enum_type_bitmask = true;
+							bitflags.push(Bitflags{
+								name: enum_name.clone(),
+								values: vec![]
+							});
+
+						} else if enum_name == "API Constants" {
+							matching_api_constants = true
+						}
+					},
+					b"member" if matching_what[0] == "type" => {
+						struct_member_const = false;
+						struct_member_ptr = false;
+						struct_member_ptr_ptr = false;
+						struct_member_array_sizes.clear();
+						struct_member_bitfield = false;
+						struct_member_len = attributes.get("len").cloned().unwrap_or(String::new());
+						struct_member_noautovalidity = attributes.get("noautovalidity").map(|v| v == "true").unwrap_or(false);
+						struct_member_values = attributes.get("values").cloned().unwrap_or(String::new());
+						struct_member_optional = attributes.get("optional").cloned().unwrap_or(String::new());
+					},
+					b"param" if matching_what[0] == "command" => {
+						param_len = if let Some(len) = attributes.get("len") { len.to_string() } else { "".to_string() };
+						param_optional = if let Some(optional) = attributes.get("optional") { optional.to_string() } else { "".to_string() };
+						param_noautovalidity = attributes.get("noautovalidity").map(|v| v == "true").unwrap_or(false);
+					},
+					b"type" if matching_what[0] == "types" => {
+						if let Some(category) = attributes.get("category") {
+							type_category = category.to_string();
+						}
+						if let Some(requires) = attributes.get("requires").or(attributes.get("bitvalues")) {
+							type_requires = requires.to_string();
+						} else {
+							type_requires.clear();
+						}
 
-							} else if etype == "bitmask" {
-								enum_type_bitmask = true;
-								bitflags.push(Bitflags{
-									name: enum_name.clone(),
-									values: vec![]
-								});
+						// Handles can have several parents, the first is kept
+						type_parent = attributes.get("parent").map(|p| p.split(',').next().unwrap_or("").to_string()).unwrap_or_default();
+						type_objtypeenum = attributes.get("objtypeenum").cloned().unwrap_or(String::new());
 
-							} else if enum_name == "API Constants" {
-								matching_api_constants = true
-							}
-						}
-					},
-					b"member" => {
-						if matching_what[0] == "type" {
-							struct_member_const = false;
-							struct_member_ptr = false;
-							struct_member_ptr_ptr = false;
-							struct_member_array = false;
-							struct_member_array_size.clear();
-						}
-					},
-					b"type" => {
-						if matching_what[0] == "types" {
-							if let Some(category) = attributes.get("category") {
-								type_category = category.to_string();
-							}
-							if let Some(requires) = attributes.get("requires") {
-								type_requires = requires.to_string();
-							} else {
-								type_requires.clear();
-							}
-
-							if type_category == "struct" {
-								if let Some(name) = attributes.get("name") {
-									struct_name = name.to_string();
-								}
+						type_define.clear();
+
+						if type_category == "struct" || type_category == "union" {
+							if let Some(name) = attributes.get("name") {
+								struct_name = name.to_string();
 							}
+							struct_extends = attributes.get("structextends").map(|e| e.split(',').map(|s| s.to_string()).collect()).unwrap_or(vec![]);
 						}
 					},
 					b"require" => {
 						if matching_what[0] == "feature" {
 							if let Some(comment) = attributes.get("comment") {
 								features.push(FeatureBlock{
-									comment: comment.to_string(), contents: vec![]
+									feature: feature_name.clone(), comment: comment.to_string(), contents: vec![]
 								});
 							}
 						}
@@ -335,21 +1227,53 @@ fn main() {
 						} else {
 							require_feature = "".to_string();
 						}
+						require_depends = attributes.get("depends").or(attributes.get("extension")).cloned().unwrap_or(String::new());
 					},
-					b"extension" => {
-						if matching_what[0] == "extensions" {
-							if let (Some(name), Some(number), Some(supported)) = (attributes.get("name"), attributes.get("number"), attributes.get("supported")) {
-
-								extensions.push(Extension{
-									name: name.to_string(),
-									number: number.parse::<u32>().unwrap(),
-									extension_type: if let Some(extension_type) = attributes.get("type") { extension_type.to_string() } else { "".to_string() },
-									author: if let Some(author) = attributes.get("author") { author.to_string() } else { "".to_string() },
-									contact: if let Some(contact) = attributes.get("contact") { contact.to_string() } else { "".to_string() },
-									supported: supported.to_string(),
-									types: vec![]
-								});
-							}
+					b"feature" => {
+						feature_name = attributes.get("name").cloned().unwrap_or(String::new());
+					},
+					b"format" if matching_what[0] == "formats" => {
+						let block_extent: Vec<u32> = attributes.get("blockExtent").map(|e| e.split(',').map(|v| v.parse::<u32>().unwrap()).collect()).unwrap_or(vec![1, 1, 1]);
+						formats.push(Format {
+							name: attributes.get("name").cloned().unwrap_or(String::new()),
+							class: attributes.get("class").cloned().unwrap_or(String::new()),
+							block_size: attributes.get("blockSize").map(|v| v.parse::<u32>().unwrap()).unwrap_or(0),
+							texels_per_block: attributes.get("texelsPerBlock").map(|v| v.parse::<u32>().unwrap()).unwrap_or(1),
+							block_extent: (block_extent[0], block_extent[1], block_extent[2]),
+							compressed: attributes.get("compressed").cloned().unwrap_or(String::new()),
+							packed: attributes.get("packed").cloned().unwrap_or(String::new()),
+							chroma: attributes.get("chroma").cloned().unwrap_or(String::new()),
+							components: vec![],
+							planes: vec![]
+						});
+					},
+					b"extension" if matching_what[0] == "extensions" => {
+						if let (Some(name), Some(number), Some(supported)) = (attributes.get("name"), attributes.get("number"), attributes.get("supported")) {
+
+							let depends = attributes.get("depends").cloned().unwrap_or(String::new());
+							let requires = if let Some(requires) = attributes.get("requires") {
+								requires.split(',').map(|r| r.to_string()).collect()
+							} else if !depends.is_empty() {
+								depends::parse(&depends).unwrap_or_else(|e| panic!("Invalid depends of {}: {}", name, e)).required()
+							} else {
+								vec![]
+							};
+
+							extensions.push(Extension{
+								name: name.to_string(),
+								number: number.parse::<u32>().unwrap(),
+								extension_type: if let Some(extension_type) = attributes.get("type") { extension_type.to_string() } else { "".to_string() },
+								supported: supported.to_string(),
+								platform: if let Some(platform) = attributes.get("platform") { platform.to_string() } else { "".to_string() },
+								requires,
+								depends,
+								conditions: BTreeMap::new(),
+								promoted_to: if let Some(promoted_to) = attributes.get("promotedto") { promoted_to.to_string() } else { "".to_string() },
+								deprecated_by: if let Some(deprecated_by) = attributes.get("deprecatedby") { deprecated_by.to_string() } else { "".to_string() },
+								obsoleted_by: if let Some(obsoleted_by) = attributes.get("obsoletedby") { obsoleted_by.to_string() } else { "".to_string() },
+								spec_version: 0,
+								types: vec![]
+							});
 						}
 					}
 					_ => (),
@@ -366,12 +1290,7 @@ fn main() {
 				}
 
 				match e.name() {
-					b"enum" => {
-
-
-if require_feature != "VK_VERSION_1_1" {
-
-
+					b"enum" if !profile.skipped_features.contains(&require_feature.as_ref()) => {
 						if matching_what[0] == "enums" {
 							let name = if let Some(name) = attributes.get("name") { name.to_string() } else { "".to_string() };
 							let value = if let Some(value) = attributes.get("value") { value.to_string() } else { if let Some(alias) = attributes.get("alias") { alias.to_string() } else { "".to_string() } };
@@ -387,7 +1306,15 @@ if require_feature != "VK_VERSION_1_1" {
 									}
 								} else {
 									// TODO: Some enums have no value VK_COLORSPACE_SRGB_NONLINEAR_KHR
-									if !attributes.get("alias").is_some() {
+									if let Some(alias) = attributes.get("alias") {
+										enums.last_mut().unwrap().aliases.push((name, alias.to_string()));
+									} else {
+										// Older registries name the handle of an object type only in a comment
+										if enum_name == format!("{}ObjectType", profile.type_prefix) {
+											if let Some(handle) = attributes.get("comment") {
+												handle_object_types.entry(handle.to_string()).or_insert(name.clone());
+											}
+										}
 										if value.find('x').is_some() {
 											let without_prefix = value.trim_start_matches("0x");
 											let z = i32::from_str_radix(without_prefix, 16);
@@ -411,8 +1338,7 @@ if require_feature != "VK_VERSION_1_1" {
 								extensions.last_mut().unwrap().types.push(ExtensionNewType::BitflagsExtension{
 									name: name.to_string(),
 									bitpos: bitpos.to_string().parse::<u32>().unwrap(),
-									extends: extends,
-									comment: comment,
+									extends,
 								});
 							// Extends an enum
 							} else if let Some(offset) = attributes.get("offset") {
@@ -420,33 +1346,46 @@ if require_feature != "VK_VERSION_1_1" {
 								extensions.last_mut().unwrap().types.push(ExtensionNewType::EnumExtension{
 									name: name.to_string(),
 									offset: offset.to_string().parse::<u32>().unwrap(),
-									extends: extends,
-									comment: comment,
-									dir: dir,
+									extends,
+									comment,
+									dir,
 								});
+							// Extension name and version
+							// Another name for an enum value
+							} else if let (Some(alias), false) = (attributes.get("alias"), extends.is_empty()) {
+								extensions.last_mut().unwrap().types.push(ExtensionNewType::EnumAlias{
+									name: name.to_string(),
+									alias: alias.to_string(),
+									extends,
+								});
+							// Extension name and version
+							} else if name.ends_with("_SPEC_VERSION") || name.ends_with("_EXTENSION_NAME") {
+								if let Some(value) = attributes.get("value") {
+									if name.ends_with("_SPEC_VERSION") {
+										if let Ok(spec_version) = value.parse::<u32>() {
+											extensions.last_mut().unwrap().spec_version = spec_version;
+										}
+									}
+									extensions.last_mut().unwrap().types.push(ExtensionNewType::Constant{
+										name: name.to_string(),
+										value: value.replace("&quot;", "").replace("\"", ""),
+									});
+								} else if let Some(alias) = attributes.get("alias") {
+									extensions.last_mut().unwrap().types.push(ExtensionNewType::ConstantAlias{
+										name: name.to_string(),
+										alias: alias.to_string(),
+									});
+								}
 							}
 						}
-
-}
-
-
 					},
-					b"type" => {
-
-
-
-
-
-if require_feature != "VK_VERSION_1_1" {
-
-
-
-
-
+					b"type" if !profile.skipped_features.contains(&require_feature.as_ref()) => {
 						if matching_what[0] == "types" {
 							let name = if let Some(name) = attributes.get("name") { name.to_string() } else { "".to_string() };
 							if attributes.contains_key("requires") {
 								types.push(name.to_string());
+							} else if let Some(alias) = attributes.get("alias") {
+								type_aliases.push((name.to_string(), alias.to_string()));
 							}
 						} else if matching_what[0] == "require" && matching_what[1] == "feature" {
 							let name = if let Some(name) = attributes.get("name") { name.to_string() } else { "".to_string() };
@@ -454,38 +1393,47 @@ if require_feature != "VK_VERSION_1_1" {
 						} else if matching_what[0] == "require" && matching_what[1] == "extension" {
 
 							if let Some(name) = attributes.get("name") {
-								extensions.last_mut().unwrap().types.push(ExtensionNewType::Type(name.to_string()));
+								let ext = extensions.last_mut().unwrap();
+								ext.types.push(ExtensionNewType::Type(name.to_string()));
+								if !require_depends.is_empty() {
+									ext.conditions.insert(name.to_string(), require_depends.clone());
+								}
 							}
 						}
-
-
-
-
-}
-
-
 					},
-					b"command" => {
-
-
-if require_feature != "VK_VERSION_1_1" {
-
-
-
-						if matching_what[0] == "require" && matching_what[1] == "feature" {
+					b"command" if !profile.skipped_features.contains(&require_feature.as_ref()) => {
+						if matching_what[0] == "commands" {
+							if let (Some(name), Some(alias)) = (attributes.get("name"), attributes.get("alias")) {
+								command_aliases.push((name.to_string(), alias.to_string()));
+							}
+						} else if matching_what[0] == "require" && matching_what[1] == "feature" {
 							let name = if let Some(name) = attributes.get("name") { name.to_string() } else { "".to_string() };
 							features.last_mut().unwrap().contents.push(FeatureContent::Command(name.to_string()));
 						} else if matching_what[0] == "require" && matching_what[1] == "extension" {
 
 							if let Some(name) = attributes.get("name") {
-								extensions.last_mut().unwrap().types.push(ExtensionNewType::Command(name.to_string()));
+								let ext = extensions.last_mut().unwrap();
+								ext.types.push(ExtensionNewType::Command(name.to_string()));
+								if !require_depends.is_empty() {
+									ext.conditions.insert(name.to_string(), require_depends.clone());
+								}
 							}
 						}
-
-
-
-}
-
+					},
+					b"component" if matching_what[0] == "format" => {
+						formats.last_mut().unwrap().components.push(FormatComponent {
+							name: attributes.get("name").cloned().unwrap_or(String::new()),
+							bits: attributes.get("bits").cloned().unwrap_or(String::new()),
+							numeric_format: attributes.get("numericFormat").cloned().unwrap_or(String::new())
+						});
+					},
+					b"plane" if matching_what[0] == "format" => {
+						formats.last_mut().unwrap().planes.push(FormatPlane {
+							index: attributes.get("index").map(|v| v.parse::<u32>().unwrap()).unwrap_or(0),
+							width_divisor: attributes.get("widthDivisor").map(|v| v.parse::<u32>().unwrap()).unwrap_or(1),
+							height_divisor: attributes.get("heightDivisor").map(|v| v.parse::<u32>().unwrap()).unwrap_or(1),
+							compatible: attributes.get("compatible").cloned().unwrap_or(String::new())
+						});
 					},
 					_ => (),
 				}
@@ -510,13 +1458,16 @@ if require_feature != "VK_VERSION_1_1" {
 				} else if matching_what[0] == "type" {
 
 					if matching_what[1] == "proto" {
-						return_value = translate_types(text);
+						return_value = if text == "void" { "()".to_string() } else { translate_types(text) };
 					} else if matching_what[1] == "param" {
 						param_type = translate_types(text);
 					} else if matching_what[1] == "member" {
+						struct_member_c_type = text.to_string();
 						struct_member_type = translate_types(text);
 					} else if matching_what[1] == "types" {
 						define_type_value.push_str(&text);
+					} else if matching_what[1] == "type" {
+						type_define = text;
 					}
 
 				// <member>blah</member>
@@ -525,12 +1476,9 @@ if require_feature != "VK_VERSION_1_1" {
 					struct_member_const |= text.find("const").is_some();
 					struct_member_ptr |= text.find("*").is_some();
 					struct_member_ptr_ptr |= text.find("*").is_some() && (text.find("*").unwrap() != text.rfind("*").unwrap());
-					if text.find("[").is_some() {
-						struct_member_array = true;
-
-						for cap in array_regex.captures_iter(&text) {
-							struct_member_array_size = cap[1].to_string();
-						}
+					struct_member_bitfield |= text.find(":").is_some();
+					for cap in array_regex.captures_iter(&text) {
+						struct_member_array_sizes.push(cap[1].to_string());
 					}
 
 				// <param>blah</param>
@@ -551,7 +1499,7 @@ if require_feature != "VK_VERSION_1_1" {
 				} else if matching_what[0] == "enum" {
 
 					if matching_what[1] == "member" {
-						struct_member_array_size = text.to_string();
+						struct_member_array_sizes.push(text.to_string());
 					} else if matching_what[1] == "param" {
 						param_array_size = text.to_string();
 					}
@@ -564,120 +1512,108 @@ if require_feature != "VK_VERSION_1_1" {
 			},
 			Ok(Event::End(ref e)) => {
 				match e.name() {
-					b"enums" => {
-						if matching_api_constants {
-							matching_api_constants = false;
-						}
+					b"enums" if matching_api_constants => {
+						matching_api_constants = false;
 					},
-					b"member" => {
-						if matching_what[0] == "member" {
-							if struct_member_ptr_ptr {
-								if struct_member_array {
-									struct_members.write_fmt(format_args!("\tpub {}: [{}{}{}{} {}; {}],\n",
-										struct_member_name,
-										if struct_member_ptr { "*" } else { "" },
-										if struct_member_const { if struct_member_ptr { "const" } else { "" } } else { if struct_member_ptr { "mut" } else { "" } },
-										if struct_member_ptr { "*" } else { "" },
-										if struct_member_const { if struct_member_ptr { "const" } else { "" } } else { if struct_member_ptr { "mut" } else { "" } },
-										struct_member_type,
-										struct_member_array_size)).expect("Could not format string");
-									struct_contains_arrays = true;
-								} else {
-									struct_members.write_fmt(format_args!("\tpub {}: {}{}{}{} {},\n",
-										struct_member_name,
-										if struct_member_ptr { "*" } else { "" },
-										if struct_member_const { if struct_member_ptr { "const" } else { "" } } else { if struct_member_ptr { "mut" } else { "" } },
-										if struct_member_ptr { "*" } else { "" },
-										if struct_member_const { if struct_member_ptr { "const" } else { "" } } else { if struct_member_ptr { "mut" } else { "" } },
-										struct_member_type)).expect("Could not format string");
-								}
-							} else {
-								if struct_member_array {
-									struct_members.write_fmt(format_args!("\tpub {}: [{}{} {}; {}],\n",
-										struct_member_name,
-										if struct_member_ptr { "*" } else { "" },
-										if struct_member_const { if struct_member_ptr { "const" } else { "" } } else { if struct_member_ptr { "mut" } else { "" } },
-										struct_member_type,
-										struct_member_array_size)).expect("Could not format string");
-									struct_contains_arrays = true;
-								} else {
-									struct_members.write_fmt(format_args!("\tpub {}: {}{} {},\n",
-										struct_member_name,
-										if struct_member_ptr { "*" } else { "" },
-										if struct_member_const { if struct_member_ptr { "const" } else { "" } } else { if struct_member_ptr { "mut" } else { "" } },
-										struct_member_type)).expect("Could not format string");
-								}
-							}
+					b"member" if matching_what[0] == "member" => {
+						let ptr = if struct_member_ptr { "*" } else { "" };
+						let ptr_const = if struct_member_const { if struct_member_ptr { "const" } else { "" } } else { if struct_member_ptr { "mut" } else { "" } };
+						let mut member_type = if struct_member_ptr_ptr {
+							format!("{}{}{}{} {}", ptr, ptr_const, ptr, ptr_const, struct_member_type)
+						} else {
+							format!("{}{} {}", ptr, ptr_const, struct_member_type)
+						};
+
+						// Multi-dimensional arrays nest with the last dimension innermost
+						for array_size in struct_member_array_sizes.iter().rev() {
+							member_type = format!("[{}; {}]", member_type, array_size);
+							struct_contains_arrays = true;
 						}
-					},
-					b"command" => {
-						if matching_what[0] == "command" {
-							commands.insert(function_name.clone(), (parameters.clone(), return_value.clone()));
-							parameters.clear();
+						struct_members.write_fmt(format_args!("\tpub {}: {},\n", struct_member_name, member_type)).expect("Could not format string");
+
+						layout_members.push(LayoutMember {
+							name: struct_member_name.clone(),
+							type_name: struct_member_type.clone(),
+							ptr: struct_member_ptr,
+							ptr_ptr: struct_member_ptr_ptr,
+							is_const: struct_member_const,
+							array_sizes: struct_member_array_sizes.clone(),
+							bitfield: struct_member_bitfield,
+							len: struct_member_len.clone(),
+							optional: struct_member_optional.clone(),
+							noautovalidity: struct_member_noautovalidity,
+							char_array: struct_member_c_type == "char" && struct_member_array_sizes.len() == 1
+						});
+						if struct_member_name == "sType" {
+							struct_structure_type = struct_member_values.clone();
 						}
 					},
-					b"param" => {
-						if matching_what[0] == "param" && matching_what[1] == "command" {
-							if param_ptr_ptr {
-								if param_array {
-									parameters.write_fmt(format_args!("{}: [{}{}{}{} {}; {}], ",
-										param_name,
-										if param_ptr { "*" } else { "" },
-										if param_const { if param_ptr { "const" } else { "" } } else { if param_ptr { "mut" } else { "" } },
-										if param_ptr { "*" } else { "" },
-										if param_const { if param_ptr { "const" } else { "" } } else { if param_ptr { "mut" } else { "" } },
-										param_type,
-										param_array_size)).expect("Could not format string");
-								} else {
-									parameters.write_fmt(format_args!("{}: {}{}{}{} {}, ",
-										param_name,
-										if param_ptr { "*" } else { "" },
-										if param_const { if param_ptr { "const" } else { "" } } else { if param_ptr { "mut" } else { "" } },
-										if param_ptr { "*" } else { "" },
-										if param_const { if param_ptr { "const" } else { "" } } else { if param_ptr { "mut" } else { "" } },
-										param_type)).expect("Could not format string");
-								}
-							} else {
-								if param_array {
-									parameters.write_fmt(format_args!("{}: [{}{} {}; {}], ",
-										param_name,
-										if param_ptr { "*" } else { "" },
-										if param_const { if param_ptr { "const" } else { "" } } else { if param_ptr { "mut" } else { "" } },
-										param_type,
-										param_array_size)).expect("Could not format string");
-								} else {
-									parameters.write_fmt(format_args!("{}: {}{} {}, ",
-										param_name,
-										if param_ptr { "*" } else { "" },
-										if param_const { if param_ptr { "const" } else { "" } } else { if param_ptr { "mut" } else { "" } },
-										param_type)).expect("Could not format string");
-								}
-							}
-							param_ptr = false;
-							param_ptr_ptr = false;
-							param_const = false;
-							param_array = false;
-							param_array_size.clear();
+					b"command" if matching_what[0] == "command" => {
+						commands.insert(function_name.clone(), (parameters.clone(), return_value.clone()));
+						command_params.insert(function_name.clone(), std::mem::take(&mut params));
+						parameters.clear();
+					},
+					b"param" if matching_what[0] == "param" && matching_what[1] == "command" => {
+						let ptr = if param_ptr { "*" } else { "" };
+						let ptr_const = if param_const { if param_ptr { "const" } else { "" } } else { if param_ptr { "mut" } else { "" } };
+						let mut declared_type = if param_ptr_ptr {
+							format!("{}{}{}{} {}", ptr, ptr_const, ptr, ptr_const, param_type)
+						} else {
+							format!("{}{} {}", ptr, ptr_const, param_type)
+						};
+						if param_array {
+							declared_type = format!("[{}; {}]", declared_type, param_array_size);
 						}
+						let declaration = format!("{}: {}", param_name, declared_type);
+						parameters.write_fmt(format_args!("{}, ", declaration)).expect("Could not format string");
+
+						params.push(CommandParam {
+							name: param_name.clone(),
+							declaration,
+							type_name: param_type.clone(),
+							ptr: param_ptr && !param_ptr_ptr,
+							ptr_ptr: param_ptr_ptr,
+							is_const: param_const,
+							array_size: if param_array { param_array_size.clone() } else { String::new() },
+							len: param_len.clone(),
+							optional: param_optional.clone(),
+							noautovalidity: param_noautovalidity
+						});
+						param_ptr = false;
+						param_ptr_ptr = false;
+						param_const = false;
+						param_array = false;
+						param_array_size.clear();
 					},
-					b"type" => {
-						if matching_what[0] == "type" {
-							if matching_what[1] == "types" {
-
-								if type_category == "bitmask" {
-									bitmask_types.push((type_name.clone(), type_requires.clone()));
-								} else if type_category == "handle" {
-									handle_types.push(type_name.clone());
-								} else if type_category == "struct" && !struct_members.is_empty() {
-									structs.push((struct_name.clone(), struct_members.clone(), struct_contains_arrays));
-								} else if type_category == "define" {
-									define_types.push((type_name.clone(), define_type_value.clone()));
-									define_type_value.clear();
-								}
-								struct_members.clear();
-								struct_contains_arrays = false;
+					b"type" if matching_what[0] == "type" && matching_what[1] == "types" => {
+
+						if type_category == "bitmask" {
+							bitmask_types.push((type_name.clone(), type_requires.clone()));
+						} else if type_category == "handle" {
+							handle_types.push(type_name.clone());
+							handle_parents.insert(type_name.clone(), type_parent.clone());
+							if !type_objtypeenum.is_empty() {
+								handle_object_types.insert(type_name.clone(), type_objtypeenum.clone());
 							}
+							if type_define == "VK_DEFINE_HANDLE" {
+								dispatchable_handles.insert(type_name.clone());
+							}
+						} else if type_category == "struct" && !struct_members.is_empty() {
+							structs.push((struct_name.clone(), struct_members.clone(), struct_contains_arrays));
+						} else if type_category == "define" {
+							define_types.push((type_name.clone(), define_type_value.clone()));
+							define_type_value.clear();
+						}
+						if type_category == "struct" || type_category == "union" {
+							layout_types.insert(struct_name.clone(), LayoutType {
+								union: type_category == "union",
+								members: std::mem::take(&mut layout_members),
+								structure_type: std::mem::take(&mut struct_structure_type),
+								extends: std::mem::take(&mut struct_extends)
+							});
 						}
+						struct_members.clear();
+						struct_contains_arrays = false;
 					},
 					_ => (),
 				}
@@ -691,318 +1627,1571 @@ if require_feature != "VK_VERSION_1_1" {
 		buf.clear();
 	}
 
+	// Update the cargo features, one per extension. Each feature turns on the extensions it requires
+	if !cargo_toml_filename.is_empty() {
+
+		// Only extensions that get a feature can be turned on, cargo rejects the others
+		let featured: BTreeSet<&str> = extensions.iter().filter(|ext| ext.supported != "disabled").map(|ext| ext.name.as_ref()).collect();
+		let mut cargo_features = Vec::<(String, Vec<String>, Vec<String>)>::new();
+		for ext in extensions.iter().filter(|ext| ext.supported != "disabled") {
+			let expression = if ext.depends.is_empty() {
+				depends::Depends::All(ext.requires.iter().map(|r| depends::Depends::Name(r.clone())).collect())
+			} else {
+				depends::parse(&ext.depends).unwrap_or_else(|e| panic!("Invalid depends of {}: {}", ext.name, e))
+			};
+			let mut notes = vec![];
+			let mut missing = vec![];
+			let mut deps = expression.enabling(&|name| featured.contains(name), &mut notes, &mut missing);
+			for name in &missing {
+				notes.push(format!("needs {}, which has no feature", name));
+			}
+			let platform_feature = c_platform_to_cargo_feature(&ext.platform);
+			if !platform_feature.is_empty() {
+				deps.push(platform_feature);
+			}
+			cargo_features.push((ext.name.clone(), deps, notes));
+		}
+		write_cargo_features(&cargo_toml_filename, &cargo_features);
+	}
+
+	// Enum and type names to the extensions that add to them, in registry order
+	let mut enum_extensions = BTreeMap::<String, Vec<usize>>::new();
+	let mut type_extensions = BTreeMap::<String, Vec<usize>>::new();
+	for (i, ext) in extensions.iter().enumerate() {
+
+		if ext.supported != "disabled" {
+
+			for ext_type in &ext.types {
+
+				let indices = match *ext_type {
+					ExtensionNewType::EnumExtension { ref extends, .. } => enum_extensions.entry(extends.clone()).or_insert(vec![]),
+					ExtensionNewType::Type(ref name) => type_extensions.entry(name.clone()).or_insert(vec![]),
+					_ => continue
+				};
+				if indices.last() != Some(&i) {
+					indices.push(i);
+				}
+			}
+		}
+	}
+
+	// The parsed registry instead of the bindings, or what it says about one name
+	if emit == "json" || !query_name.is_empty() {
+
+		use json::Value;
+
+		let extension_names = |indices: Option<&Vec<usize>>| Value::Array(indices.map(|indices| indices.iter().map(|&i| Value::string(&extensions[i].name)).collect()).unwrap_or(vec![]));
+
+		let mut command_extensions = BTreeMap::<String, Vec<usize>>::new();
+		for (i, ext) in extensions.iter().enumerate() {
+			if ext.supported != "disabled" {
+				for ext_type in &ext.types {
+					if let ExtensionNewType::Command(ref name) = *ext_type {
+						command_extensions.entry(name.clone()).or_insert(vec![]).push(i);
+					}
+				}
+			}
+		}
+
+		// Aliases have the value of the constant they stand for, values are the numbers their C expressions work out to
+		let constants = api_constants.iter().map(|(name, value)| {
+			let alias = api_constants.iter().any(|c| &c.0 == value);
+			let mut resolved = value;
+			while let Some(constant) = api_constants.iter().find(|c| &c.0 == resolved) {
+				resolved = &constant.1;
+			}
+			Value::Object(vec![
+				("name", Value::string(name)),
+				("value", json::c_number(resolved).unwrap_or_else(|| Value::string(resolved))),
+				("alias_of", if alias { Value::string(value) } else { Value::Null })
+			])
+		}).collect();
+
+		let handles = handle_types.iter().map(|name| Value::Object(vec![
+			("name", Value::string(name)),
+			("parent", Value::optional(handle_parents.get(name).map(|p| p.as_str()).unwrap_or(""))),
+			("extensions", extension_names(type_extensions.get(name)))
+		])).collect();
+
+		let bitmask_types_json = bitmask_types.iter().map(|(name, bits)| Value::Object(vec![
+			("name", Value::string(name)),
+			("bits", Value::optional(bits))
+		])).collect();
+
+		let enum_value = |name: &String, value: i128, extension: Option<&String>, alias_of: Option<&String>| Value::Object(vec![
+			("name", Value::string(name)),
+			("value", Value::Number(value)),
+			("extension", extension.map(|e| Value::string(e)).unwrap_or(Value::Null)),
+			("alias_of", alias_of.map(|a| Value::string(a)).unwrap_or(Value::Null))
+		]);
+
+		let enums_json = enums.iter().map(|e| {
+
+			// name, value, extension
+			let mut values: Vec<(&String, i128, Option<&String>)> = e.values.iter().map(|v| (&v.0, v.1 as i128, None)).collect();
+			let mut aliases: Vec<(&String, &String, Option<&String>)> = e.aliases.iter().map(|a| (&a.0, &a.1, None)).collect();
+			for ext in extensions.iter().filter(|ext| ext.supported != "disabled") {
+				for ext_type in &ext.types {
+					match *ext_type {
+						ExtensionNewType::EnumExtension { ref name, offset, ref extends, ref dir, .. } if *extends == e.name => {
+							let value = (profile.extension_base_number + (ext.number - 1) * profile.extension_block_size + offset) as i128;
+							values.push((name, if dir == "-" { -value } else { value }, Some(&ext.name)));
+						},
+						ExtensionNewType::EnumAlias { ref name, ref alias, ref extends } if *extends == e.name => {
+							aliases.push((name, alias, Some(&ext.name)));
+						},
+						_ => {}
+					}
+				}
+			}
+
+			let mut values_json: Vec<Value> = values.iter().map(|v| enum_value(v.0, v.1, v.2, None)).collect();
+			for alias in &aliases {
+				if let Some(target) = values.iter().find(|v| v.0 == alias.1) {
+					values_json.push(enum_value(alias.0, target.1, alias.2, Some(alias.1)));
+				}
+			}
+
+			Value::Object(vec![
+				("name", Value::string(&e.name)),
+				("values", Value::Array(values_json))
+			])
+		}).collect();
+
+		let bitmasks = bitflags.iter().map(|b| {
+
+			let bitmask_value = |name: &String, value: Option<i128>, bitpos: Option<u32>, extension: Option<&String>| Value::Object(vec![
+				("name", Value::string(name)),
+				("value", value.map(Value::Number).unwrap_or(Value::Null)),
+				("bitpos", bitpos.map(|b| Value::Number(b as i128)).unwrap_or(Value::Null)),
+				("extension", extension.map(|e| Value::string(e)).unwrap_or(Value::Null))
+			]);
+
+			let mut values: Vec<Value> = b.values.iter().map(|(name, value)| match *value {
+				BitflagsValueType::Bitpos(bitpos) => bitmask_value(name, Some(1i128 << bitpos), Some(bitpos), None),
+				BitflagsValueType::Value(ref value) => bitmask_value(name, check::parse_enum_value(value).map(|v| v as i128), None, None)
+			}).collect();
+			for ext in extensions.iter().filter(|ext| ext.supported != "disabled") {
+				for ext_type in &ext.types {
+					if let ExtensionNewType::BitflagsExtension { ref name, bitpos, ref extends, .. } = *ext_type {
+						if *extends == b.name {
+							values.push(bitmask_value(name, Some(1i128 << bitpos), Some(bitpos), Some(&ext.name)));
+						}
+					}
+				}
+			}
+
+			Value::Object(vec![
+				("name", Value::string(&b.name)),
+				("values", Value::Array(values))
+			])
+		}).collect();
+
+		let struct_json = |name: &String, alias_of: Option<&String>, layout: &LayoutType| Value::Object(vec![
+			("name", Value::string(name)),
+			("alias_of", alias_of.map(|a| Value::string(a)).unwrap_or(Value::Null)),
+			("union", Value::Bool(layout.union)),
+			("structure_type", Value::optional(&layout.structure_type)),
+			("extends", Value::strings(&layout.extends)),
+			("extensions", extension_names(type_extensions.get(name))),
+			("members", Value::Array(layout.members.iter().map(|m| Value::Object(vec![
+				("name", Value::string(&m.name)),
+				("type", Value::string(&m.type_name)),
+				("pointer_depth", Value::Number(if m.ptr_ptr { 2 } else if m.ptr { 1 } else { 0 })),
+				("const", Value::Bool(m.is_const)),
+				("array_sizes", Value::strings(&m.array_sizes)),
+				("bitfield", Value::Bool(m.bitfield)),
+				("len", Value::optional(&m.len)),
+				("optional", Value::optional(&m.optional)),
+				("noautovalidity", Value::Bool(m.noautovalidity))
+			])).collect()))
+		]);
+
+		let mut structs_json: Vec<Value> = layout_types.iter().map(|(name, layout)| struct_json(name, None, layout)).collect();
+		for (name, alias) in &type_aliases {
+			if let Some(layout) = layout_types.get(alias) {
+				structs_json.push(struct_json(name, Some(alias), layout));
+			}
+		}
+
+		let command_json = |name: &String, alias_of: Option<&String>| {
+			let target = alias_of.unwrap_or(name);
+			Value::Object(vec![
+				("name", Value::string(name)),
+				("alias_of", alias_of.map(|a| Value::string(a)).unwrap_or(Value::Null)),
+				("return_type", Value::string(&commands[target].1)),
+				("extensions", extension_names(command_extensions.get(name))),
+				("params", Value::Array(command_params[target].iter().map(|p| Value::Object(vec![
+					("name", Value::string(&p.name)),
+					("type", Value::string(&p.type_name)),
+					("pointer_depth", Value::Number(if p.ptr_ptr { 2 } else if p.ptr { 1 } else { 0 })),
+					("const", Value::Bool(p.is_const)),
+					("array_size", Value::optional(&p.array_size)),
+					("len", Value::optional(&p.len)),
+					("optional", Value::optional(&p.optional)),
+					("noautovalidity", Value::Bool(p.noautovalidity))
+				])).collect()))
+			])
+		};
+
+		let mut commands_json: Vec<Value> = commands.keys().map(|name| command_json(name, None)).collect();
+		for (name, alias) in &command_aliases {
+			if commands.contains_key(alias) {
+				commands_json.push(command_json(name, Some(alias)));
+			}
+		}
+
+		let features_json = features.iter().map(|f| {
+			let contents = |kind: &str| Value::Array(f.contents.iter().filter_map(|c| match (kind, c) {
+				("command", &FeatureContent::Command(ref name)) | ("type", &FeatureContent::Type(ref name)) | ("enum", &FeatureContent::Enum(ref name)) => Some(Value::string(name)),
+				_ => None
+			}).collect());
+			Value::Object(vec![
+				("feature", Value::string(&f.feature)),
+				("comment", Value::optional(&f.comment)),
+				("commands", contents("command")),
+				("types", contents("type")),
+				("enums", contents("enum"))
+			])
+		}).collect();
+
+		let extensions_json = extensions.iter().map(|ext| {
+			let mut ext_commands = vec![];
+			let mut ext_types = vec![];
+			let mut ext_enums = vec![];
+			let mut ext_constants = vec![];
+			for ext_type in &ext.types {
+				match *ext_type {
+					ExtensionNewType::EnumExtension { ref name, .. } | ExtensionNewType::BitflagsExtension { ref name, .. } | ExtensionNewType::EnumAlias { ref name, .. } => ext_enums.push(Value::string(name)),
+					ExtensionNewType::Constant { ref name, .. } | ExtensionNewType::ConstantAlias { ref name, .. } => ext_constants.push(Value::string(name)),
+					ExtensionNewType::Command(ref name) => ext_commands.push(Value::string(name)),
+					ExtensionNewType::Type(ref name) => ext_types.push(Value::string(name))
+				}
+			}
+			Value::Object(vec![
+				("name", Value::string(&ext.name)),
+				("number", Value::Number(ext.number as i128)),
+				("type", Value::optional(&ext.extension_type)),
+				("supported", Value::optional(&ext.supported)),
+				("platform", Value::optional(&ext.platform)),
+				("requires", Value::strings(&ext.requires)),
+				("depends", Value::optional(&ext.depends)),
+				("promoted_to", Value::optional(&ext.promoted_to)),
+				("deprecated_by", Value::optional(&ext.deprecated_by)),
+				("obsoleted_by", Value::optional(&ext.obsoleted_by)),
+				("spec_version", Value::Number(ext.spec_version as i128)),
+				("commands", Value::Array(ext_commands)),
+				("types", Value::Array(ext_types)),
+				("enums", Value::Array(ext_enums)),
+				("constants", Value::Array(ext_constants))
+			])
+		}).collect();
+
+		let formats_json = formats.iter().map(|f| Value::Object(vec![
+			("name", Value::string(&f.name)),
+			("class", Value::string(&f.class)),
+			("block_size", Value::Number(f.block_size as i128)),
+			("texels_per_block", Value::Number(f.texels_per_block as i128)),
+			("block_extent", Value::Array(vec![Value::Number(f.block_extent.0 as i128), Value::Number(f.block_extent.1 as i128), Value::Number(f.block_extent.2 as i128)])),
+			("compressed", Value::optional(&f.compressed)),
+			("packed", Value::optional(&f.packed)),
+			("chroma", Value::optional(&f.chroma)),
+			("components", Value::Array(f.components.iter().map(|c| Value::Object(vec![
+				("name", Value::string(&c.name)),
+				("bits", Value::string(&c.bits)),
+				("numeric_format", Value::string(&c.numeric_format))
+			])).collect())),
+			("planes", Value::Array(f.planes.iter().map(|p| Value::Object(vec![
+				("index", Value::Number(p.index as i128)),
+				("width_divisor", Value::Number(p.width_divisor as i128)),
+				("height_divisor", Value::Number(p.height_divisor as i128)),
+				("compatible", Value::string(&p.compatible))
+			])).collect()))
+		])).collect();
+
+		let registry = Value::Object(vec![
+			("format_version", Value::Number(json::FORMAT_VERSION)),
+			("profile", Value::string(profile.name)),
+			("constants", Value::Array(constants)),
+			("external_types", Value::strings(&types)),
+			("handles", Value::Array(handles)),
+			("bitmask_types", Value::Array(bitmask_types_json)),
+			("enums", Value::Array(enums_json)),
+			("bitmasks", Value::Array(bitmasks)),
+			("structs", Value::Array(structs_json)),
+			("commands", Value::Array(commands_json)),
+			("features", Value::Array(features_json)),
+			("extensions", Value::Array(extensions_json)),
+			("formats", Value::Array(formats_json))
+		]);
+
+		if !query_name.is_empty() {
+			let blocks: Vec<String> = query::query(&registry, &query_name).iter().map(|lines| lines.join("\n")).collect();
+			if blocks.is_empty() {
+				println!("Nothing in the registry is called \"{}\"", query_name);
+				std::process::exit(1);
+			}
+			println!("{}", blocks.join("\n\n"));
+		} else if rs_filename.is_empty() {
+			print!("{}", json::to_string(&registry));
+		} else {
+			std::fs::write(&rs_filename, json::to_string(&registry)).expect("Could not write json file");
+		}
+		return;
+	}
+
+	// Extensions (or depends expressions) a type is declared for
+	let type_providers = |name: &String| -> Vec<String> {
+		type_extensions.get(name).unwrap_or(&vec![]).iter().map(|i| extensions[*i].provider(name)).collect()
+	};
+
+	// Names a command can be loaded by, its own first, then the command it is an alias of and that command's other aliases
+	let command_names = |name: &String| -> Vec<String> {
+		let target = command_alias_target(&command_aliases, name);
+		let mut names = vec![name.clone()];
+		if target != *name {
+			names.push(target.clone());
+		}
+		for alias in &command_aliases {
+			if !names.contains(&alias.0) && command_alias_target(&command_aliases, &alias.0) == target {
+				names.push(alias.0.clone());
+			}
+		}
+		names
+	};
+
 	// Write output to file or stdout
-	let mut output: std::io::BufWriter<Box<std::io::Write>> =
+	let mut output: std::io::BufWriter<Box<dyn std::io::Write>> =
 		std::io::BufWriter::new(if rs_filename.is_empty() {
 			Box::new(std::io::stdout())
 		} else {
 			Box::new(std::fs::File::create(rs_filename).unwrap())
 		});
 
-	// TODO:
-	let fluff1 = r#"
+	// The crate root, left out with --include where the lib.rs that include!s the bindings has it
+	let fluff_crate = r#"
 #![allow(non_snake_case)]
-#![feature(const_fn)]
-#![feature(untagged_unions)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 #[macro_use]
 extern crate bitflags;
+"#;
 
-use std::mem;
-use libc::{c_void};
-
-pub const VK_VERSION_1_0: u32 = 1;
-
-pub const fn VK_MAKE_VERSION(major: u32, minor: u32, patch: u32) -> u32 {
-	((major) << 22) | ((minor) << 12) | (patch)
-}
-
-pub const VK_API_VERSION_1_0: u32 = VK_MAKE_VERSION(1, 0, 0);
+	// TODO:
+	let fluff1 = r#"
+use core::ffi::c_void;
+"#;
 
-pub const fn VK_VERSION_MAJOR(version: u32) -> u32 {
-	version >> 22
+	let fluff3 = r#"
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ExtensionType {
+	Instance,
+	Device
 }
 
-pub const fn VK_VERSION_MINOR(version: u32) -> u32 {
-	(version >> 22) & 0x3ff
+#[derive(Copy, Clone, Debug)]
+pub struct ExtensionInfo {
+	pub name: &'static str,
+	pub number: u32,
+	pub extension_type: ExtensionType,
+	pub spec_version: u32,
+	pub requires: &'static [&'static str],
+
+	// Boolean expression of what it depends on, '+' is and, ',' is or
+	pub depends: Option<&'static str>,
+	pub promoted_to: Option<&'static str>,
+	pub deprecated_by: Option<&'static str>,
+	pub obsoleted_by: Option<&'static str>
 }
 
-pub const fn VK_VERSION_PATCH(version: u32) -> u32 {
-	version & 0x3ff
+pub fn extension_info(name: &str) -> Option<&'static ExtensionInfo> {
+	EXTENSIONS.iter().find(|e| e.name == name)
 }
 "#;
 
-	let fluff2 = r#"
-
-#[allow(non_camel_case_types)]
-pub type VkDeviceSize = u64;
-#[allow(non_camel_case_types)]
-pub type VkSampleMask = u32;
-
-#[allow(non_camel_case_types)]
-pub type PFN_vkAllocationFunction = *const c_void;
-#[allow(non_camel_case_types)]
-pub type PFN_vkReallocationFunction = *const c_void;
-#[allow(non_camel_case_types)]
-pub type PFN_vkFreeFunction = *const c_void;
-#[allow(non_camel_case_types)]
-pub type PFN_vkInternalAllocationNotification = *const c_void;
-#[allow(non_camel_case_types)]
-pub type PFN_vkInternalFreeNotification = *const c_void;
-#[allow(non_camel_case_types)]
-pub type PFN_vkDebugReportCallbackEXT = *const c_void;
-#[allow(non_camel_case_types)]
-pub type PFN_vkDebugUtilsMessengerCallbackEXT = *const c_void;
-#[allow(non_camel_case_types)]
-pub type PFN_vkVoidFunction = *const c_void;
-
-// Rust assumes bool is u8, vulkan assumes it is u32
-pub type VkBool32 = u32;
-
-// TODO: how to do unions in rust?
-#[derive(Copy, Clone)]
-#[repr(C)]
-pub union VkClearColorValue {
-	pub float32: [f32; 4],
-	pub int32: [i32; 4],
-	pub uint32: [u32; 4]
-}
+	let fluff_validate = r#"
+	use std::fmt;
 
-#[derive(Copy, Clone)]
-#[repr(C)]
-pub union VkClearValue {
-	pub colour: VkClearColorValue,
-	pub depthStencil: VkClearDepthStencilValue
-}
+	// What is wrong with a parameter or member
+	#[derive(Copy, Clone, PartialEq, Debug)]
+	pub enum Problem {
 
-impl std::fmt::Debug for VkClearColorValue {
-	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-		unsafe { write!(f, "VkClearColorValue {{float32: {:?}; or int32: {:?}; or uint32: {:?}}}", self.float32, self.int32, self.uint32) }
-	}
-}
+		// A pointer or handle that is not optional is null, or an array is null while its count is not 0
+		Null,
 
-impl std::fmt::Debug for VkClearValue {
-	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-		unsafe { write!(f, "VkClearValue {{colour: {:?}; or depthStencil: {:?}}}", self.colour, self.depthStencil) }
-	}
-}
+		// The count of an array that is not optional is 0
+		ZeroCount,
 
-// TODO
-// Cannot implement Debug for [u8; x > 32]
-impl std::fmt::Debug for VkPhysicalDeviceProperties {
-	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-		write!(f, "not implemented")
-	}
-}
+		// sType is not the struct's, with --newtype-enums found can be a value vkraw does not know
+		StructureType { expected: VkStructureType, found: VkStructureType },
 
-impl std::fmt::Debug for VkLayerProperties {
-	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-		write!(f, "not implemented")
+		// A value the enum does not have, without --newtype-enums also an sType vkraw does not know
+		EnumValue(i32)
 	}
-}
 
-impl std::fmt::Debug for VkExtensionProperties {
-	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-		write!(f, "not implemented")
+	// The implicit valid usage a call breaks, parameter is the path to it, i.e. "pCreateInfo->pApplicationInfo->sType"
+	#[derive(Clone, PartialEq, Debug)]
+	pub struct ValidationError {
+		pub command: &'static str,
+		pub parameter: String,
+		pub problem: Problem
 	}
-}
 
-impl std::fmt::Debug for VkPhysicalDeviceMemoryProperties {
-	fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-		write!(f, "not implemented")
+	impl fmt::Display for ValidationError {
+		fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+			match self.problem {
+				Problem::Null => write!(f, "{}: {} is null", self.command, self.parameter),
+				Problem::ZeroCount => write!(f, "{}: {} is 0", self.command, self.parameter),
+				Problem::StructureType { expected, found } => write!(f, "{}: {} is {:?} instead of {:?}", self.command, self.parameter, found, expected),
+				Problem::EnumValue(value) => write!(f, "{}: {} has the unknown value {}", self.command, self.parameter, value)
+			}
+		}
 	}
-}"#;
-	{
-		use std::io::Write;
-
-
 
+	impl std::error::Error for ValidationError {}
 
+	fn fail<T>(command: &'static str, path: &str, name: &str, problem: Problem) -> Result<T, ValidationError> {
+		Err(ValidationError { command: command, parameter: format!("{}{}", path, name), problem: problem })
+	}
+"#;
 
+	let fluff_serde = r#"
+	use serde::ser::{Serialize, Serializer, SerializeStruct, SerializeSeq};
+	use serde::de::{Deserialize, Deserializer, Visitor, MapAccess, IgnoredAny, Error};
+	use std::convert::TryFrom;
+	use std::ffi::CStr;
+	use std::fmt;
 
-		write!(output, "/*\n{}\n*/\n", comments[0]).expect("Failed to write");
-		write!(output, "{}\n", fluff1).expect("Failed to write");
+	// A fixed size char array, as a string up to its null terminator
+	struct CharArray<'a>(&'a [u8]);
 
-		for define in define_types {
-			write!(output, "{}", translate_define(define)).expect("Failed to write");
+	impl<'a> Serialize for CharArray<'a> {
+		fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+			let end = self.0.iter().position(|c| *c == 0).unwrap_or(self.0.len());
+			serializer.serialize_str(&String::from_utf8_lossy(&self.0[..end]))
 		}
+	}
 
-		write!(output, "{}\n", fluff2).expect("Failed to write");
+	// A pointer to a null-terminated string, None if it is null
+	struct NullTerminated(*const u8);
 
-		// Print constants
-		for consts in api_constants {
-			let tmp = &consts.0;
-			write!(output, "pub const {}: {} = {};\n", consts.0, guess_type_from_name(tmp), translate_values(consts.1)).expect("Failed to write");
+	impl Serialize for NullTerminated {
+		fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+			if self.0.is_null() {
+				serializer.serialize_none()
+			} else {
+				serializer.serialize_some(&unsafe { CStr::from_ptr(self.0 as *const _) }.to_string_lossy())
+			}
 		}
+	}
 
-		// Print typedefs
-		for t in types {
-			let cfg = c_wsi_types_to_cfg_var(&t);
-			if cfg.is_empty() {
-				write!(output, "#[allow(non_camel_case_types)]\n{}\n", c_wsi_types_to_rust_types(&t)).expect("Failed to write");
-			} else {
-				write!(output, "#[allow(non_camel_case_types)]\n#[cfg(feature=\"{}\")]\n{}\n", cfg, c_wsi_types_to_rust_types(&t)).expect("Failed to write");
+	// An array of null-terminated strings, None if it is null
+	struct Strings(*const *const u8, usize);
+
+	impl Serialize for Strings {
+		fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+			if self.0.is_null() {
+				return serializer.serialize_none();
+			}
+			let mut seq = serializer.serialize_seq(Some(self.1))?;
+			for i in 0..self.1 {
+				seq.serialize_element(&NullTerminated(unsafe { *self.0.add(i) }))?;
 			}
+			seq.end()
 		}
-		for t in bitmask_types {
-			if t.1.is_empty() {
-				write!(output, "#[allow(non_camel_case_types)]\npub type {} = u32;\n", t.0).expect("Failed to write");
+	}
+
+	// A len= array, None if it is null
+	struct Array<T>(*const T, usize);
+
+	impl<T: Serialize> Serialize for Array<T> {
+		fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+			if self.0.is_null() {
+				serializer.serialize_none()
 			} else {
-				write!(output, "#[allow(non_camel_case_types)]\npub type {} = {};\n", t.0, t.1).expect("Failed to write");
+				serializer.serialize_some(unsafe { std::slice::from_raw_parts(self.0, self.1) })
 			}
 		}
-		for t in handle_types {
-			write!(output, "#[allow(non_camel_case_types)]\npub type {} = u64;\n", t).expect("Failed to write");
-		}
+	}
 
-		// See https://github.com/KhronosGroup/Vulkan-LoaderAndValidationLayers/blob/master/scripts/generator.py
-		let extension_base_number = 1000000000;
-		let extension_block_size = 1000;
+	// A pointer to one value, None if it is null
+	struct Pointer<T>(*const T);
 
-		// Print enums
-		for e in enums {
-			write!(output, "#[allow(non_camel_case_types)]\n#[derive(Copy, Clone, PartialEq, Debug)]\n#[repr(C)]\npub enum {} {{\n", &e.name).expect("Failed to write");
-			for v in e.values {
-				write!(output, "\t{} = {},\n", v.0, v.1).expect("Failed to write");
+	impl<T: Serialize> Serialize for Pointer<T> {
+		fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+			if self.0.is_null() {
+				serializer.serialize_none()
+			} else {
+				serializer.serialize_some(unsafe { &*self.0 })
 			}
+		}
+	}
 
-			// TODO: probably should use a hash map here
-			for ext in &extensions {
+	fn char_array<'de, A: MapAccess<'de>, const N: usize>(map: &mut A) -> Result<[u8; N], A::Error> {
+		let value: String = map.next_value()?;
+		if value.len() >= N {
+			return Err(A::Error::invalid_length(value.len(), &"a string that fits the array with its null terminator"));
+		}
+		let mut array = [0; N];
+		array[..value.len()].copy_from_slice(value.as_bytes());
+		Ok(array)
+	}
 
-				if ext.supported != "disabled" {
+	fn fixed_array<'de, A: MapAccess<'de>, T: Deserialize<'de>, const N: usize>(map: &mut A) -> Result<[T; N], A::Error> {
+		let values: Vec<T> = map.next_value()?;
+		let len = values.len();
+		<[T; N]>::try_from(values).map_err(|_| A::Error::invalid_length(len, &"as many values as the array has"))
+	}
+"#;
 
-					let mut once = true;
-					for ext_enum in &ext.types {
+	// With --newtype-enums values vkraw does not know are numbers
+	let fluff_serde_newtype = r#"
+	enum EnumValue {
+		Name(String),
+		Number(i32)
+	}
 
-						match *ext_enum {
-							ExtensionNewType::EnumExtension { ref name, ref offset, ref extends, ref comment, ref dir } => {
-								if *extends == e.name {
-									if once {
-										write!(output, "\n\t// {}\n", ext.name).expect("Failed to write");
-										write!(output, "\n#[cfg(feature = \"{}\")]\n", ext.name).expect("Failed to write");
-										once = false;
-									}
-									write!(output, "\t{} = {}{},\n", name, dir, extension_base_number + (ext.number - 1) * extension_block_size + offset).expect("Failed to write");
-								}
-							},
-							_ => ()
-						}
-					}
+	impl<'de> Deserialize<'de> for EnumValue {
+		fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+			struct NameOrNumber;
+			impl<'de> Visitor<'de> for NameOrNumber {
+				type Value = EnumValue;
+				fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+					f.write_str("the name or the value of an enum")
+				}
+				fn visit_str<E: Error>(self, value: &str) -> Result<EnumValue, E> {
+					Ok(EnumValue::Name(value.to_string()))
+				}
+				fn visit_i64<E: Error>(self, value: i64) -> Result<EnumValue, E> {
+					i32::try_from(value).map(EnumValue::Number).map_err(|_| E::invalid_value(serde::de::Unexpected::Signed(value), &self))
+				}
+				fn visit_u64<E: Error>(self, value: u64) -> Result<EnumValue, E> {
+					i32::try_from(value).map(EnumValue::Number).map_err(|_| E::invalid_value(serde::de::Unexpected::Unsigned(value), &self))
 				}
 			}
-
-			write!(output, "\t{}_MAX_ENUM = 0x7FFFFFFF\n}}\n\n", to_screaming_snake_case(&e.name)).expect("Failed to write");
+			deserializer.deserialize_any(NameOrNumber)
 		}
+	}
+"#;
 
-		// Print bitflags (bitmasks)
-		for b in bitflags {
+	let fluff_first_proc_addr = r#"
+// The first of a command's names (its own, then the ones it is promoted from or to) that get_proc_addr finds
+#[allow(dead_code)]
+fn first_proc_addr(names: &[&str], get_proc_addr: &dyn Fn(*const u8) -> *const c_void) -> *const c_void {
+	names.iter().map(|name| get_proc_addr(name.as_ptr())).find(|function| !function.is_null()).unwrap_or(::core::ptr::null())
+}
+"#;
 
-			if b.values.len() > 0 {
-				write!(output, "bitflags! {{\n#[repr(C)]\n\tpub struct {}: u32 {{\n", b.name).expect("Failed to write");
-				write!(output, "\t\tconst _EMPTY = 0;\n").expect("Failed to write");
+	let fluff_format = r#"
+// A component of a format, bits is 0 for compressed formats
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct FormatComponent {
+	// R, G, B, A, D or S
+	pub name: &'static str,
+	pub bits: u32,
 
-				for v in b.values {
+	// UNORM, SRGB, SFLOAT, ...
+	pub numeric_format: &'static str
+}
+"#;
 
-					match v.1 {
+	let fluff_handle = r#"
+// Generic code over handles (debug names, leak tracking, drop), implemented by the marker types in handle::
+// i.e. handle::VkBuffer for VkBuffer
+pub trait Handle {
+	const NAME: &'static str;
+	const OBJECT_TYPE: VkObjectType;
 
-						BitflagsValueType::Bitpos(bitpos) => {
-							write!(output, "\t\tconst {} = 0b", v.0).expect("Could not format string");
-							for x in (0..32).rev() {
-								if x == bitpos {
-									write!(output, "1").expect("Could not format string");
-								} else {
-									write!(output, "0").expect("Could not format string");
-								}
-							}
-							write!(output, ";\n").expect("Could not format string");
-						},
-						BitflagsValueType::Value(value) => {
-							write!(output, "\t\tconst {} = {};\n", v.0, value).expect("Could not format string");
-						}
-					}
-				}
-				write!(output, "\t}}\n}}\n\n").expect("Failed to write");
-			} else {
-				write!(output, "bitflags! {{\n#[repr(C)]\n\tpub struct {}: u32 {{\n\t\tconst _EMPTY = 0;\n\t}}\n}}\n\n", b.name).expect("Failed to write");
-			}
-		}
+	// From the registry's parent attribute, the handle itself for handles without one
+	type Parent: Handle;
 
-		// Print structs
-		for s in structs {
-			
-			let mut was_ext = false;
-			
-			// TODO: probably should use a hash map here
-			for ext in &extensions {
-
-				if ext.supported != "disabled" {
-
-					let mut once = true;
-					for ext_enum in &ext.types {
-
-						match *ext_enum {
-							ExtensionNewType::Type(ref name) => {
-								if *name == s.0 {
-									if once {
-										write!(output, "\n// {}\n", ext.name).expect("Failed to write");
-										write!(output, "#[cfg(feature = \"{}\")]\n", ext.name).expect("Failed to write");
-										once = false;
-									}
-									// Can't use automatic Debug since rust disallows this for arrays > 32
-									if s.2 {
-										write!(output, "#[derive(Copy, Clone)]\n#[repr(C)]\npub struct {} {{\n{}\n}}\n", s.0, s.1).expect("Failed to write");
-									} else {
-										write!(output, "#[derive(Debug, Copy, Clone)]\n#[repr(C)]\npub struct {} {{\n{}\n}}\n", s.0, s.1).expect("Failed to write");
-									}
-									was_ext = true;
-								}
-							},
-							_ => ()
-						}
-					}
-				}
-			}
-			
-			if !was_ext {
-				// Can't use automatic Debug since rust disallows this for arrays > 32
-				if s.2 {
-					write!(output, "#[derive(Copy, Clone)]\n#[repr(C)]\npub struct {} {{\n{}\n}}\n", s.0, s.1).expect("Failed to write");
-				} else {
-					write!(output, "#[derive(Debug, Copy, Clone)]\n#[repr(C)]\npub struct {} {{\n{}\n}}\n", s.0, s.1).expect("Failed to write");
-				}
-			}
-		}
+	// The vkDestroy* or vkFree* command, if it has one
+	const DESTROY_COMMAND: Option<&'static str>;
 
-		// Print functions
-		write!(output, "#[link(name = \"vulkan\")]\n").expect("Failed to write");
-		write!(output, "extern {{\n").expect("Failed to write");
+	// Calls DESTROY_COMMAND without allocation callbacks, owner is the device or instance it was created from
+	// Returns false if there is nothing it can call, i.e. commands that free from a pool (vkFreeCommandBuffers)
+	unsafe fn destroy(functions: &VulkanFunctionPointers, owner: u64, handle: u64) -> bool;
+"#;
 
-		for feature_block in features {
+	let fluff_layer = r#"
+	use std::collections::BTreeMap;
+	use std::ffi::CStr;
+	use std::sync::{Arc, RwLock};
+	use core::ptr;
+
+	// Loader and layer interface from vk_layer.h, which is not part of vk.xml
+	// sTypes are read as integers as other structures in a pNext chain may have values vkraw was built without
+	const LAYER_NEGOTIATE_INTERFACE_STRUCT: i32 = 1;
+	const VK_STRUCTURE_TYPE_LOADER_INSTANCE_CREATE_INFO: i32 = 47;
+	const VK_STRUCTURE_TYPE_LOADER_DEVICE_CREATE_INFO: i32 = 48;
+	const VK_LAYER_LINK_INFO: i32 = 0;
+
+	pub const LAYER_INTERFACE_VERSION: u32 = 2;
+
+	#[repr(C)]
+	pub struct VkNegotiateLayerInterface {
+		pub sType: i32,
+		pub pNext: *mut c_void,
+		pub loaderLayerInterfaceVersion: u32,
+		pub pfnGetInstanceProcAddr: Option<unsafe extern "C" fn(instance: VkInstance, pName: *const u8) -> PFN_vkVoidFunction>,
+		pub pfnGetDeviceProcAddr: Option<unsafe extern "C" fn(device: VkDevice, pName: *const u8) -> PFN_vkVoidFunction>,
+		pub pfnGetPhysicalDeviceProcAddr: PFN_vkVoidFunction
+	}
 
-			let mut once = true;
-			for feature_content in feature_block.contents {
+	#[repr(C)]
+	pub struct VkLayerInstanceLink {
+		pub pNext: *mut VkLayerInstanceLink,
+		pub pfnNextGetInstanceProcAddr: extern "C" fn(instance: VkInstance, pName: *const u8, ) -> PFN_vkVoidFunction,
+		pub pfnNextGetPhysicalDeviceProcAddr: PFN_vkVoidFunction
+	}
 
-				match feature_content {
-					FeatureContent::Command(name) => {
-						if let Some(cmd) = commands.get(&name) {
+	#[repr(C)]
+	pub struct VkLayerDeviceLink {
+		pub pNext: *mut VkLayerDeviceLink,
+		pub pfnNextGetInstanceProcAddr: extern "C" fn(instance: VkInstance, pName: *const u8, ) -> PFN_vkVoidFunction,
+		pub pfnNextGetDeviceProcAddr: extern "C" fn(device: VkDevice, pName: *const u8, ) -> PFN_vkVoidFunction
+	}
+
+	// Only the pLayerInfo member of the C union is used, when function is VK_LAYER_LINK_INFO
+	#[repr(C)]
+	pub struct VkLayerInstanceCreateInfo {
+		pub sType: i32,
+		pub pNext: *const c_void,
+		pub function: i32,
+		pub pLayerInfo: *mut VkLayerInstanceLink
+	}
+
+	#[repr(C)]
+	pub struct VkLayerDeviceCreateInfo {
+		pub sType: i32,
+		pub pNext: *const c_void,
+		pub function: i32,
+		pub pLayerInfo: *mut VkLayerDeviceLink
+	}
+
+	// Finds the loader's link info for this layer in a create info's pNext chain
+	unsafe fn find_link_info<T>(pNext: *const c_void, sType: i32) -> *mut T {
+		let mut next = pNext as *mut VkLayerInstanceCreateInfo;
+		while !next.is_null() {
+			if (*next).sType == sType && (*next).function == VK_LAYER_LINK_INFO {
+				return next as *mut T;
+			}
+			next = (*next).pNext as *mut VkLayerInstanceCreateInfo;
+		}
+		ptr::null_mut()
+	}
+
+	// Dispatchable handles point at the loader's dispatch table, physical devices share their instance's
+	// and queues and command buffers share their device's
+	unsafe fn dispatch_key(handle: u64) -> usize {
+		*(handle as usize as *const usize)
+	}
+
+	static INSTANCES: RwLock<BTreeMap<usize, Arc<InstanceDispatch>>> = RwLock::new(BTreeMap::new());
+	static DEVICES: RwLock<BTreeMap<usize, Arc<DeviceDispatch>>> = RwLock::new(BTreeMap::new());
+
+	// The next layer's commands for an instance or one of its physical devices
+	pub unsafe fn instance_dispatch(handle: u64) -> Arc<InstanceDispatch> {
+		INSTANCES.read().unwrap().get(&dispatch_key(handle)).expect("Unknown instance or physical device").clone()
+	}
+
+	// The next layer's commands for a device or one of its queues or command buffers
+	pub unsafe fn device_dispatch(handle: u64) -> Arc<DeviceDispatch> {
+		DEVICES.read().unwrap().get(&dispatch_key(handle)).expect("Unknown device, queue or command buffer").clone()
+	}
+
+	// The layer's own command if the next layer has it, so that commands that are not enabled stay null
+	unsafe fn next_or_intercept<L: Layer>(name: &[u8], next: PFN_vkVoidFunction) -> PFN_vkVoidFunction {
+		let own = intercept_address::<L>(name);
+		if next.is_null() || own.is_null() { next } else { own }
+	}
+
+	unsafe extern "C" fn get_instance_proc_addr<L: Layer>(instance: VkInstance, pName: *const u8) -> PFN_vkVoidFunction {
+		let name = CStr::from_ptr(pName as *const _).to_bytes();
+		if name == b"vkGetInstanceProcAddr" || name == b"vkCreateInstance" {
+			return intercept_address::<L>(name);
+		}
+		if instance == VK_NULL_HANDLE {
+			return ptr::null();
+		}
+		let dispatch = match INSTANCES.read().unwrap().get(&dispatch_key(instance)) {
+			Some(dispatch) => dispatch.clone(),
+			None => return ptr::null()
+		};
+		next_or_intercept::<L>(name, dispatch.GetInstanceProcAddr.expect("vkGetInstanceProcAddr is not loaded")(instance, pName))
+	}
+
+	unsafe extern "C" fn get_device_proc_addr<L: Layer>(device: VkDevice, pName: *const u8) -> PFN_vkVoidFunction {
+		let name = CStr::from_ptr(pName as *const _).to_bytes();
+		if name == b"vkGetDeviceProcAddr" {
+			return intercept_address::<L>(name);
+		}
+		let dispatch = match DEVICES.read().unwrap().get(&dispatch_key(device)) {
+			Some(dispatch) => dispatch.clone(),
+			None => return ptr::null()
+		};
+		next_or_intercept::<L>(name, dispatch.GetDeviceProcAddr.expect("vkGetDeviceProcAddr is not loaded")(device, pName))
+	}
+
+	unsafe extern "C" fn create_instance<L: Layer>(pCreateInfo: *const VkInstanceCreateInfo, pAllocator: *const VkAllocationCallbacks, pInstance: *mut VkInstance) -> VkResult {
+		let layer_info = find_link_info::<VkLayerInstanceCreateInfo>((*pCreateInfo).pNext, VK_STRUCTURE_TYPE_LOADER_INSTANCE_CREATE_INFO);
+		if layer_info.is_null() {
+			return VkResult::VK_ERROR_INITIALIZATION_FAILED;
+		}
+
+		// Move the chain on to the next layer before calling down
+		let link = (*layer_info).pLayerInfo;
+		let next_get_instance_proc_addr = (*link).pfnNextGetInstanceProcAddr;
+		(*layer_info).pLayerInfo = (*link).pNext;
+
+		let result = L::get().vkCreateInstance(&InstanceDispatch::load(VK_NULL_HANDLE, next_get_instance_proc_addr), pCreateInfo, pAllocator, pInstance);
+		if result == VkResult::VK_SUCCESS {
+			INSTANCES.write().unwrap().insert(dispatch_key(*pInstance), Arc::new(InstanceDispatch::load(*pInstance, next_get_instance_proc_addr)));
+		}
+		result
+	}
+
+	unsafe extern "C" fn create_device<L: Layer>(physicalDevice: VkPhysicalDevice, pCreateInfo: *const VkDeviceCreateInfo, pAllocator: *const VkAllocationCallbacks, pDevice: *mut VkDevice) -> VkResult {
+		let layer_info = find_link_info::<VkLayerDeviceCreateInfo>((*pCreateInfo).pNext, VK_STRUCTURE_TYPE_LOADER_DEVICE_CREATE_INFO);
+		if layer_info.is_null() {
+			return VkResult::VK_ERROR_INITIALIZATION_FAILED;
+		}
+
+		// Move the chain on to the next layer before calling down
+		let link = (*layer_info).pLayerInfo;
+		let next_get_device_proc_addr = (*link).pfnNextGetDeviceProcAddr;
+		(*layer_info).pLayerInfo = (*link).pNext;
+
+		let result = L::get().vkCreateDevice(&instance_dispatch(physicalDevice), physicalDevice, pCreateInfo, pAllocator, pDevice);
+		if result == VkResult::VK_SUCCESS {
+			DEVICES.write().unwrap().insert(dispatch_key(*pDevice), Arc::new(DeviceDispatch::load(*pDevice, next_get_device_proc_addr)));
+		}
+		result
+	}
+
+	// Called from the vkNegotiateLoaderLayerInterfaceVersion that export_layer! exports
+	pub unsafe fn negotiate_loader_layer_interface_version<L: Layer>(pVersionStruct: *mut VkNegotiateLayerInterface) -> VkResult {
+		if pVersionStruct.is_null() || (*pVersionStruct).sType != LAYER_NEGOTIATE_INTERFACE_STRUCT || (*pVersionStruct).loaderLayerInterfaceVersion < LAYER_INTERFACE_VERSION {
+			return VkResult::VK_ERROR_INITIALIZATION_FAILED;
+		}
+		(*pVersionStruct).loaderLayerInterfaceVersion = LAYER_INTERFACE_VERSION;
+		(*pVersionStruct).pfnGetInstanceProcAddr = Some(get_instance_proc_addr::<L>);
+		(*pVersionStruct).pfnGetDeviceProcAddr = Some(get_device_proc_addr::<L>);
+		(*pVersionStruct).pfnGetPhysicalDeviceProcAddr = ptr::null();
+		VkResult::VK_SUCCESS
+	}
+
+	// Exports the entry point the loader looks for in a layer's library, i.e. vkraw::export_layer!(MyLayer);
+	#[macro_export]
+	macro_rules! export_layer {
+		($layer:ty) => {
+			#[no_mangle]
+			pub unsafe extern "C" fn vkNegotiateLoaderLayerInterfaceVersion(version_struct: *mut $crate::layer::VkNegotiateLayerInterface) -> $crate::VkResult {
+				$crate::layer::negotiate_loader_layer_interface_version::<$layer>(version_struct)
+			}
+		}
+	}
+"#;
+
+	let fluff_traced = r#"
+	use std::fmt::{Debug, Write};
+	use std::sync::RwLock;
+	use std::time::{Duration, Instant};
+	use core::ffi::CStr;
+
+	// Where traced calls are logged, set with set_sink (the default writes to stderr)
+	pub trait TraceSink: Send + Sync {
+		fn call(&self, command: &str, arguments: &str, result: &str, duration: Duration);
+	}
+
+	pub struct StderrSink;
+
+	impl TraceSink for StderrSink {
+		fn call(&self, command: &str, arguments: &str, result: &str, duration: Duration) {
+			eprintln!("{}({}) = {} in {:?}", command, arguments, result, duration);
+		}
+	}
+
+	static SINK: RwLock<Option<Box<dyn TraceSink>>> = RwLock::new(None);
+
+	pub fn set_sink(sink: Box<dyn TraceSink>) {
+		*SINK.write().unwrap() = Some(sink);
+	}
+
+	fn log(command: &str, arguments: &str, result: &str, duration: Duration) {
+		match *SINK.read().unwrap() {
+			Some(ref sink) => sink.call(command, arguments, result, duration),
+			None => StderrSink.call(command, arguments, result, duration)
+		}
+	}
+
+	fn trace_debug<T: Debug>(out: &mut String, value: &T) {
+		let _ = write!(out, "{:?}", value);
+	}
+
+	fn trace_handle(out: &mut String, handle: u64) {
+		let _ = write!(out, "{:#x}", handle);
+	}
+
+	// Fixed size strings, i.e. VkExtensionProperties::extensionName
+	fn trace_char_array(out: &mut String, value: &[u8]) {
+		let end = value.iter().position(|c| *c == 0).unwrap_or(value.len());
+		let _ = write!(out, "{:?}", String::from_utf8_lossy(&value[..end]));
+	}
+
+	unsafe fn trace_string(out: &mut String, value: *const u8) {
+		if value.is_null() {
+			out.push_str("null");
+		} else {
+			let _ = write!(out, "{:?}", CStr::from_ptr(value as *const _).to_string_lossy());
+		}
+	}
+
+	unsafe fn trace_pointer<T>(out: &mut String, value: *const T, trace: impl Fn(&mut String, &T)) {
+		if value.is_null() {
+			out.push_str("null");
+		} else {
+			out.push('&');
+			trace(out, &*value);
+		}
+	}
+
+	unsafe fn trace_array<T>(out: &mut String, values: *const T, count: usize, trace: impl Fn(&mut String, &T)) {
+		if values.is_null() {
+			out.push_str("null");
+			return;
+		}
+		out.push('[');
+		for i in 0..count {
+			if i > 0 {
+				out.push_str(", ");
+			}
+			trace(out, &*values.add(i));
+		}
+		out.push(']');
+	}
+"#;
+
+	let fluff_icd = r#"
+	use std::collections::BTreeMap;
+	use std::ffi::CStr;
+	use std::sync::Mutex;
+	use std::sync::atomic::{AtomicU64, Ordering};
+	use core::ptr;
+
+	// Loader and driver interface from vk_icd.h, which is not part of vk.xml
+	// Version 2 is the first with vk_icdGetInstanceProcAddr, from version 3 surfaces are created by the driver
+	const ICD_LOADER_MAGIC: usize = 0x01CDC0DE;
+	const MIN_ICD_INTERFACE_VERSION: u32 = 2;
+
+	pub const ICD_INTERFACE_VERSION: u32 = 5;
+
+	// How many handles commands that enumerate non-dispatchable handles (i.e. swapchain images) return
+	pub const ENUMERATED_HANDLE_COUNT: usize = 3;
+
+	// A physical device the driver reports
+	pub struct PhysicalDevice {
+		pub properties: VkPhysicalDeviceProperties,
+		pub features: VkPhysicalDeviceFeatures,
+		pub memory: VkPhysicalDeviceMemoryProperties,
+		pub queue_families: Vec<VkQueueFamilyProperties>,
+		pub extensions: Vec<VkExtensionProperties>
+	}
+
+	impl PhysicalDevice {
+
+		// A Vulkan 1.0 device with no features, memory, queues or extensions
+		pub fn new(name: &str) -> PhysicalDevice {
+			let mut properties: VkPhysicalDeviceProperties = unsafe { ::core::mem::zeroed() };
+			properties.apiVersion = VK_MAKE_VERSION(1, 0, 0);
+			copy_name(&mut properties.deviceName, name);
+			PhysicalDevice {
+				properties: properties,
+				features: unsafe { ::core::mem::zeroed() },
+				memory: unsafe { ::core::mem::zeroed() },
+				queue_families: Vec::new(),
+				extensions: Vec::new()
+			}
+		}
+	}
+
+	pub fn extension_properties(name: &str, spec_version: u32) -> VkExtensionProperties {
+		let mut properties: VkExtensionProperties = unsafe { ::core::mem::zeroed() };
+		copy_name(&mut properties.extensionName, name);
+		properties.specVersion = spec_version;
+		properties
+	}
+
+	// Fixed size, nul terminated strings, cut short if they don't fit
+	fn copy_name(to: &mut [u8], name: &str) {
+		let length = name.len().min(to.len() - 1);
+		to[..length].copy_from_slice(&name.as_bytes()[..length]);
+		to[length] = 0;
+	}
+
+	unsafe fn name_of<'a>(name: *const u8) -> &'a [u8] {
+		CStr::from_ptr(name as *const _).to_bytes()
+	}
+
+	// Dispatchable handles point at an object starting with a slot the loader writes its dispatch table into
+	// Devices and the objects they own know which of the driver's physical devices they are for
+	#[repr(C)]
+	struct Dispatchable {
+		loader_data: usize,
+		physical_device: usize,
+		queue: Option<(u32, u32)>,
+		children: Mutex<Vec<u64>>
+	}
+
+	unsafe fn dispatchable<'a>(handle: u64) -> &'a Dispatchable {
+		&*(handle as usize as *const Dispatchable)
+	}
+
+	// Creates a dispatchable object, owned by parent unless that is null
+	unsafe fn new_dispatchable(parent: u64, physical_device: usize, queue: Option<(u32, u32)>) -> u64 {
+		let handle = Box::into_raw(Box::new(Dispatchable {
+			loader_data: ICD_LOADER_MAGIC,
+			physical_device: physical_device,
+			queue: queue,
+			children: Mutex::new(Vec::new())
+		})) as usize as u64;
+		if parent != VK_NULL_HANDLE {
+			dispatchable(parent).children.lock().unwrap().push(handle);
+		}
+		handle
+	}
+
+	// A dispatchable object created by a command called on parent (i.e. a command buffer)
+	unsafe fn new_child(parent: u64) -> u64 {
+		let physical_device = if parent == VK_NULL_HANDLE { 0 } else { dispatchable(parent).physical_device };
+		new_dispatchable(parent, physical_device, None)
+	}
+
+	// Frees a dispatchable object and everything it owns
+	unsafe fn free_dispatchable(parent: u64, handle: u64) {
+		if handle == VK_NULL_HANDLE {
+			return;
+		}
+		if parent != VK_NULL_HANDLE {
+			dispatchable(parent).children.lock().unwrap().retain(|child| *child != handle);
+		}
+		let object = Box::from_raw(handle as usize as *mut Dispatchable);
+		for child in object.children.lock().unwrap().iter() {
+			free_dispatchable(VK_NULL_HANDLE, *child);
+		}
+	}
+
+	static NEXT_HANDLE: AtomicU64 = AtomicU64::new(1);
+
+	// Non-dispatchable handles are just numbers
+	fn new_handle() -> u64 {
+		NEXT_HANDLE.fetch_add(1, Ordering::Relaxed)
+	}
+
+	static ENUMERATED_HANDLES: Mutex<BTreeMap<(&'static str, u64), Vec<u64>>> = Mutex::new(BTreeMap::new());
+
+	// The handles a command enumerates for an object, the same ones every time
+	fn enumerated_handles(command: &'static str, handle: u64) -> Vec<u64> {
+		ENUMERATED_HANDLES.lock().unwrap().entry((command, handle)).or_insert_with(|| (0..ENUMERATED_HANDLE_COUNT).map(|_| new_handle()).collect()).clone()
+	}
+
+	// Second half of a two-call enumeration, the count when values is null and as many values as fit otherwise
+	unsafe fn enumerate<T: Copy>(values: &[T], pCount: *mut u32, pValues: *mut T) -> VkResult {
+		if pValues.is_null() {
+			*pCount = values.len() as u32;
+			return VkResult::VK_SUCCESS;
+		}
+		let count = values.len().min(*pCount as usize);
+		ptr::copy_nonoverlapping(values.as_ptr(), pValues, count);
+		*pCount = count as u32;
+		if count < values.len() { VkResult::VK_INCOMPLETE } else { VkResult::VK_SUCCESS }
+	}
+
+	static MEMORY: Mutex<BTreeMap<u64, (VkDeviceSize, Vec<u8>)>> = Mutex::new(BTreeMap::new());
+
+	unsafe fn create_instance<D: Driver>(pCreateInfo: *const VkInstanceCreateInfo, pAllocator: *const VkAllocationCallbacks, pInstance: *mut VkInstance) -> VkResult {
+		for i in 0..(*pCreateInfo).enabledExtensionCount as usize {
+			let name = name_of(*(*pCreateInfo).ppEnabledExtensionNames.add(i));
+			if !D::get().instance_extensions().iter().any(|e| name_of(e.extensionName.as_ptr()) == name) {
+				return VkResult::VK_ERROR_EXTENSION_NOT_PRESENT;
+			}
+		}
+		*pInstance = new_dispatchable(VK_NULL_HANDLE, 0, None);
+		VkResult::VK_SUCCESS
+	}
+
+	unsafe fn enumerate_instance_extension_properties<D: Driver>(pLayerName: *const u8, pPropertyCount: *mut u32, pProperties: *mut VkExtensionProperties) -> VkResult {
+		if !pLayerName.is_null() {
+			return VkResult::VK_ERROR_LAYER_NOT_PRESENT;
+		}
+		enumerate(D::get().instance_extensions(), pPropertyCount, pProperties)
+	}
+
+	unsafe fn enumerate_instance_version<D: Driver>(pApiVersion: *mut u32) -> VkResult {
+		*pApiVersion = D::get().api_version();
+		VkResult::VK_SUCCESS
+	}
+
+	// An instance's physical devices are created the first time they are enumerated
+	unsafe fn enumerate_physical_devices<D: Driver>(instance: VkInstance, pPhysicalDeviceCount: *mut u32, pPhysicalDevices: *mut VkPhysicalDevice) -> VkResult {
+		let mut children = dispatchable(instance).children.lock().unwrap();
+		if children.is_empty() {
+			for i in 0..D::get().physical_devices().len() {
+				children.push(new_dispatchable(VK_NULL_HANDLE, i, None));
+			}
+		}
+		enumerate(&children, pPhysicalDeviceCount, pPhysicalDevices)
+	}
+
+	unsafe fn physical_device<'a, D: Driver>(handle: u64) -> &'a PhysicalDevice {
+		&D::get().physical_devices()[dispatchable(handle).physical_device]
+	}
+
+	unsafe fn get_physical_device_properties<D: Driver>(physicalDevice: VkPhysicalDevice, pProperties: *mut VkPhysicalDeviceProperties) {
+		*pProperties = physical_device::<D>(physicalDevice).properties;
+	}
+
+	unsafe fn get_physical_device_features<D: Driver>(physicalDevice: VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures) {
+		*pFeatures = physical_device::<D>(physicalDevice).features;
+	}
+
+	unsafe fn get_physical_device_memory_properties<D: Driver>(physicalDevice: VkPhysicalDevice, pMemoryProperties: *mut VkPhysicalDeviceMemoryProperties) {
+		*pMemoryProperties = physical_device::<D>(physicalDevice).memory;
+	}
+
+	unsafe fn get_physical_device_queue_family_properties<D: Driver>(physicalDevice: VkPhysicalDevice, pQueueFamilyPropertyCount: *mut u32, pQueueFamilyProperties: *mut VkQueueFamilyProperties) {
+		enumerate(&physical_device::<D>(physicalDevice).queue_families, pQueueFamilyPropertyCount, pQueueFamilyProperties);
+	}
+
+	unsafe fn enumerate_device_extension_properties<D: Driver>(physicalDevice: VkPhysicalDevice, pLayerName: *const u8, pPropertyCount: *mut u32, pProperties: *mut VkExtensionProperties) -> VkResult {
+		if !pLayerName.is_null() {
+			return VkResult::VK_ERROR_LAYER_NOT_PRESENT;
+		}
+		enumerate(&physical_device::<D>(physicalDevice).extensions, pPropertyCount, pProperties)
+	}
+
+	unsafe fn create_device<D: Driver>(physicalDevice: VkPhysicalDevice, pCreateInfo: *const VkDeviceCreateInfo, pAllocator: *const VkAllocationCallbacks, pDevice: *mut VkDevice) -> VkResult {
+		let physical_device = physical_device::<D>(physicalDevice);
+		for i in 0..(*pCreateInfo).enabledExtensionCount as usize {
+			let name = name_of(*(*pCreateInfo).ppEnabledExtensionNames.add(i));
+			if !physical_device.extensions.iter().any(|e| name_of(e.extensionName.as_ptr()) == name) {
+				return VkResult::VK_ERROR_EXTENSION_NOT_PRESENT;
+			}
+		}
+		*pDevice = new_dispatchable(VK_NULL_HANDLE, dispatchable(physicalDevice).physical_device, None);
+		VkResult::VK_SUCCESS
+	}
+
+	// The same queue is returned every time it is asked for
+	unsafe fn get_device_queue<D: Driver>(device: VkDevice, queueFamilyIndex: u32, queueIndex: u32, pQueue: *mut VkQueue) {
+		let object = dispatchable(device);
+		let existing = object.children.lock().unwrap().iter().cloned().find(|child| dispatchable(*child).queue == Some((queueFamilyIndex, queueIndex)));
+		*pQueue = match existing {
+			Some(queue) => queue,
+			None => new_dispatchable(device, object.physical_device, Some((queueFamilyIndex, queueIndex)))
+		};
+	}
+
+	// Memory is only really allocated when it is mapped
+	unsafe fn allocate_memory<D: Driver>(device: VkDevice, pAllocateInfo: *const VkMemoryAllocateInfo, pAllocator: *const VkAllocationCallbacks, pMemory: *mut VkDeviceMemory) -> VkResult {
+		*pMemory = new_handle();
+		MEMORY.lock().unwrap().insert(*pMemory, ((*pAllocateInfo).allocationSize, Vec::new()));
+		VkResult::VK_SUCCESS
+	}
+
+	unsafe fn free_memory<D: Driver>(device: VkDevice, memory: VkDeviceMemory, pAllocator: *const VkAllocationCallbacks) {
+		MEMORY.lock().unwrap().remove(&memory);
+	}
+
+	unsafe fn map_memory<D: Driver>(device: VkDevice, memory: VkDeviceMemory, offset: VkDeviceSize, size: VkDeviceSize, flags: VkMemoryMapFlags, ppData: *mut *mut c_void) -> VkResult {
+		let mut allocations = MEMORY.lock().unwrap();
+		let allocation = match allocations.get_mut(&memory) {
+			Some(allocation) => allocation,
+			None => return VkResult::VK_ERROR_MEMORY_MAP_FAILED
+		};
+		if allocation.1.is_empty() {
+			allocation.1 = vec![0; allocation.0 as usize];
+		}
+		*ppData = allocation.1.as_mut_ptr().add(offset as usize) as *mut c_void;
+		VkResult::VK_SUCCESS
+	}
+
+	// Also the vk_icdGetInstanceProcAddr and vk_icdGetPhysicalDeviceProcAddr that export_icd! exports
+	pub unsafe fn get_instance_proc_addr<D: Driver>(instance: VkInstance, pName: *const u8) -> PFN_vkVoidFunction {
+		command_address::<D>(name_of(pName))
+	}
+
+	unsafe fn get_device_proc_addr<D: Driver>(device: VkDevice, pName: *const u8) -> PFN_vkVoidFunction {
+		command_address::<D>(name_of(pName))
+	}
+
+	// Called from the vk_icdNegotiateLoaderICDInterfaceVersion that export_icd! exports
+	pub unsafe fn negotiate_loader_icd_interface_version(pSupportedVersion: *mut u32) -> VkResult {
+		if pSupportedVersion.is_null() || *pSupportedVersion < MIN_ICD_INTERFACE_VERSION {
+			return VkResult::VK_ERROR_INCOMPATIBLE_DRIVER;
+		}
+		*pSupportedVersion = (*pSupportedVersion).min(ICD_INTERFACE_VERSION);
+		VkResult::VK_SUCCESS
+	}
+
+	// Exports the entry points the loader looks for in a driver's library, i.e. vkraw::export_icd!(MyDriver);
+	#[macro_export]
+	macro_rules! export_icd {
+		($driver:ty) => {
+			#[no_mangle]
+			pub unsafe extern "C" fn vk_icdNegotiateLoaderICDInterfaceVersion(pSupportedVersion: *mut u32) -> $crate::VkResult {
+				$crate::icd::negotiate_loader_icd_interface_version(pSupportedVersion)
+			}
+
+			#[no_mangle]
+			pub unsafe extern "C" fn vk_icdGetInstanceProcAddr(instance: $crate::VkInstance, pName: *const u8) -> $crate::PFN_vkVoidFunction {
+				$crate::icd::get_instance_proc_addr::<$driver>(instance, pName)
+			}
+
+			#[no_mangle]
+			pub unsafe extern "C" fn vk_icdGetPhysicalDeviceProcAddr(instance: $crate::VkInstance, pName: *const u8) -> $crate::PFN_vkVoidFunction {
+				$crate::icd::get_instance_proc_addr::<$driver>(instance, pName)
+			}
+		}
+	}
+
+	// A driver, what it reports is set up through this and every command succeeds unless it says otherwise
+	pub trait Driver: Sized + Sync + 'static {
+
+		// The driver the loader's calls go to
+		fn get() -> &'static Self;
+
+		fn physical_devices(&self) -> &[PhysicalDevice];
+
+		fn instance_extensions(&self) -> &[VkExtensionProperties] {
+			&[]
+		}
+
+		// What vkEnumerateInstanceVersion reports, the newest of the physical devices
+		fn api_version(&self) -> u32 {
+			self.physical_devices().iter().map(|p| p.properties.apiVersion).max().unwrap_or(VK_MAKE_VERSION(1, 0, 0))
+		}
+
+		// The result of a call to a command, or None for the usual one. Errors are returned straight away and
+		// other results (i.e. VK_SUBOPTIMAL_KHR) after the command has done what it usually does
+		fn result(&self, command: &str) -> Option<VkResult> {
+			None
+		}
+	}
+"#;
+	{
+		use std::io::Write;
+
+
+
+
+
+
+		write!(output, "/*\n{}\n*/\n", comments[0]).expect("Failed to write");
+		if !include {
+			write!(output, "{}", fluff_crate).expect("Failed to write");
+		}
+		writeln!(output, "{}{}", fluff1, profile.version_fluff).expect("Failed to write");
+
+		for define in define_types {
+			write!(output, "{}", translate_define(define, profile)).expect("Failed to write");
+		}
+
+		writeln!(output, "{}", profile.types_fluff).expect("Failed to write");
+
+		// Print constants
+		for consts in &api_constants {
+			let constant_type = profile.constant_type(&consts.0);
+			let mut value = translate_values(consts.1.clone());
+			if constant_type.starts_with('f') && !value.contains('.') {
+				value.push_str(".0");
+			}
+			writeln!(output, "pub const {}: {} = {};", consts.0, constant_type, value).expect("Failed to write");
+		}
+
+		// Print extension names and versions
+		for ext in &extensions {
+
+			if ext.supported != "disabled" {
+
+				for ext_const in &ext.types {
+
+					match *ext_const {
+						ExtensionNewType::Constant { ref name, ref value } => {
+							if name.ends_with("_EXTENSION_NAME") {
+								writeln!(output, "pub const {}: &'static ::core::ffi::CStr = unsafe {{ ::core::ffi::CStr::from_bytes_with_nul_unchecked(b\"{}\\0\") }};", name, value).expect("Failed to write");
+							} else {
+								// OpenXR's spec version constants have the extension's name in them, i.e. XR_KHR_vulkan_enable_SPEC_VERSION
+								if name.chars().any(|c| c.is_lowercase()) {
+									writeln!(output, "#[allow(non_upper_case_globals)]").expect("Failed to write");
+								}
+								writeln!(output, "pub const {}: u32 = {};", name, value).expect("Failed to write");
+							}
+						},
+						ExtensionNewType::ConstantAlias { ref name, ref alias } => {
+							if name.ends_with("_EXTENSION_NAME") {
+								writeln!(output, "pub const {}: &'static ::core::ffi::CStr = {};", name, alias).expect("Failed to write");
+							} else {
+								writeln!(output, "pub const {}: u32 = {};", name, alias).expect("Failed to write");
+							}
+						},
+						_ => ()
+					}
+				}
+			}
+		}
+
+		// Print typedefs
+		for t in &types {
+			let cfg = c_wsi_types_to_cfg_var(t);
+			if cfg.is_empty() {
+				write!(output, "#[allow(non_camel_case_types)]\n{}\n", c_wsi_types_to_rust_types(t)).expect("Failed to write");
+			} else {
+				write!(output, "#[allow(non_camel_case_types)]\n#[cfg(feature=\"{}\")]\n{}\n", cfg, c_wsi_types_to_rust_types(t)).expect("Failed to write");
+			}
+		}
+		for t in &bitmask_types {
+			if t.1.is_empty() {
+				write!(output, "#[allow(non_camel_case_types)]\npub type {} = u{};\n", t.0, profile.flags_bits).expect("Failed to write");
+			} else {
+				write!(output, "#[allow(non_camel_case_types)]\npub type {} = {};\n", t.0, t.1).expect("Failed to write");
+			}
+		}
+		for t in &handle_types {
+			write!(output, "#[allow(non_camel_case_types)]\npub type {} = u64;\n", t).expect("Failed to write");
+		}
+
+		let extension_base_number = profile.extension_base_number;
+		let extension_block_size = profile.extension_block_size;
+
+		// Values of an enum with the cfg of the extension they are from, in the order the enum has them
+		let enum_values = |e: &Enum| -> Vec<(String, String, String)> {
+			let mut values: Vec<(String, String, String)> = e.values.iter().map(|v| (v.0.clone(), v.1.to_string(), String::new())).collect();
+			for i in enum_extensions.get(&e.name).unwrap_or(&vec![]) {
+				let ext = &extensions[*i];
+				for ext_enum in &ext.types {
+					match *ext_enum {
+						ExtensionNewType::EnumExtension { ref name, ref offset, ref extends, ref dir, .. } if *extends == e.name => {
+							values.push((name.clone(), format!("{}{}", dir, extension_base_number + (ext.number - 1) * extension_block_size + offset), extensions_cfg(std::slice::from_ref(&ext.name))));
+						},
+						_ => ()
+					}
+				}
+			}
+			values
+		};
+
+		// Print enums as i32 newtypes, a value the implementation returns that vkraw does not know is not undefined behaviour then
+		// The Rust enums are in the known module, for matching every value
+		if newtype_enums {
+			for e in &enums {
+				let values = enum_values(e);
+				write!(output, "#[allow(non_camel_case_types)]\n#[derive(Copy, Clone, PartialEq, Eq, Hash)]\n#[repr(transparent)]\npub struct {}(pub i32);\n\n#[allow(non_upper_case_globals)]\nimpl {} {{\n", e.name, e.name).expect("Failed to write");
+				for v in &values {
+					writeln!(output, "{}\tpub const {}: {} = {}({});", v.2, v.0, e.name, e.name, v.1).expect("Failed to write");
+				}
+				write!(output, "\n\t// The Rust enum for the value, None for values vkraw does not know\n\tpub fn known(self) -> Option<known::{}> {{\n\t\tmatch self {{\n", e.name).expect("Failed to write");
+				for v in &values {
+					writeln!(output, "{}\t\t\t{}::{} => Some(known::{}::{}),", v.2, e.name, v.0, e.name, v.0).expect("Failed to write");
+				}
+				write!(output, "\t\t\t_ => None\n\t\t}}\n\t}}\n\n\tpub fn name(self) -> Option<&'static str> {{\n\t\tmatch self {{\n").expect("Failed to write");
+				for v in &values {
+					writeln!(output, "{}\t\t\t{}::{} => Some(\"{}\"),", v.2, e.name, v.0, v.0).expect("Failed to write");
+				}
+				write!(output, "\t\t\t_ => None\n\t\t}}\n\t}}\n}}\n\n").expect("Failed to write");
+
+				// Unknown values are written as VkResult(-1000999000)
+				write!(output, "impl core::fmt::Debug for {0} {{\n\tfn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{\n\t\tmatch self.name() {{\n\t\t\tSome(name) => write!(f, \"{{}}\", name),\n\t\t\tNone => write!(f, \"{0}({{}})\", self.0)\n\t\t}}\n\t}}\n}}\n\n", e.name).expect("Failed to write");
+				write!(output, "impl core::fmt::Display for {} {{\n\tfn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{\n\t\tcore::fmt::Debug::fmt(self, f)\n\t}}\n}}\n\n", e.name).expect("Failed to write");
+				write!(output, "impl From<known::{0}> for {0} {{\n\tfn from(value: known::{0}) -> {0} {{\n\t\t{0}(value as i32)\n\t}}\n}}\n\n", e.name).expect("Failed to write");
+			}
+
+			writeln!(output, "pub mod known {{").expect("Failed to write");
+			for e in &enums {
+				write!(output, "\n\t#[allow(non_camel_case_types)]\n\t#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]\n\t#[repr(i32)]\n\tpub enum {} {{\n", e.name).expect("Failed to write");
+				for v in &enum_values(e) {
+					writeln!(output, "{}\t\t{} = {},", v.2, v.0, v.1).expect("Failed to write");
+				}
+				writeln!(output, "\t}}").expect("Failed to write");
+			}
+			write!(output, "}}\n\n").expect("Failed to write");
+		}
+
+		// Print enums
+		for e in enums.iter().filter(|_| !newtype_enums) {
+			write!(output, "#[allow(non_camel_case_types)]\n#[derive(Copy, Clone, PartialEq, Debug)]\n#[repr(C)]\npub enum {} {{\n", &e.name).expect("Failed to write");
+			for v in &e.values {
+				writeln!(output, "\t{} = {},", v.0, v.1).expect("Failed to write");
+			}
+
+			for i in enum_extensions.get(&e.name).unwrap_or(&vec![]) {
+
+				// Every value of the extension has its cfg, an attribute only applies to the variant after it
+				let ext = &extensions[*i];
+				let cfg = extensions_cfg(std::slice::from_ref(&ext.name));
+				let mut once = true;
+				for ext_enum in &ext.types {
+
+					match *ext_enum {
+						ExtensionNewType::EnumExtension { ref name, ref offset, ref extends, ref dir, .. } if *extends == e.name => {
 							if once {
-								write!(output, "\n\t// {}\n", feature_block.comment).expect("Failed to write");
+								write!(output, "\n\t// {}\n", ext.name).expect("Failed to write");
 								once = false;
 							}
-							write!(output, "\tpub fn {}({}) -> {};\n", name, cmd.0, cmd.1).expect("Failed to write");
+							writeln!(output, "{}\t{} = {}{},", cfg, name, dir, extension_base_number + (ext.number - 1) * extension_block_size + offset).expect("Failed to write");
+						},
+						_ => ()
+					}
+				}
+			}
+
+			write!(output, "\t{}_MAX_ENUM = 0x7FFFFFFF\n}}\n\n", to_screaming_snake_case(&e.name)).expect("Failed to write");
+		}
+
+		// Print bitflags (bitmasks)
+		for b in &bitflags {
+
+			if !b.values.is_empty() {
+				write!(output, "bitflags! {{\n#[repr(C)]\n\tpub struct {}: u{} {{\n", b.name, profile.flags_bits).expect("Failed to write");
+				writeln!(output, "\t\tconst _EMPTY = 0;").expect("Failed to write");
+
+				for v in &b.values {
+
+					match v.1 {
+
+						BitflagsValueType::Bitpos(bitpos) => {
+							write!(output, "\t\tconst {} = 0b", v.0).expect("Could not format string");
+							for x in (0..profile.flags_bits).rev() {
+								if x == bitpos {
+									write!(output, "1").expect("Could not format string");
+								} else {
+									write!(output, "0").expect("Could not format string");
+								}
+							}
+							writeln!(output, ";").expect("Could not format string");
+						},
+						BitflagsValueType::Value(ref value) => {
+							writeln!(output, "\t\tconst {} = {};", v.0, value).expect("Could not format string");
 						}
+					}
+				}
+				write!(output, "\t}}\n}}\n\n").expect("Failed to write");
+			} else {
+				write!(output, "bitflags! {{\n#[repr(C)]\n\tpub struct {}: u{} {{\n\t\tconst _EMPTY = 0;\n\t}}\n}}\n\n", b.name, profile.flags_bits).expect("Failed to write");
+			}
+		}
+
+		// Sizes of the non-struct types and array size constants, for the layout checks
+		let mut type_layouts = BTreeMap::<String, (usize, usize)>::new();
+		let flags_size = profile.flags_bits as usize / 8;
+		for t in profile.base_types {
+			type_layouts.insert(t.0.to_string(), (t.1, t.1));
+		}
+		for e in &enums {
+			type_layouts.insert(e.name.clone(), (4, 4));
+		}
+		for b in &bitflags {
+			type_layouts.insert(b.name.clone(), (flags_size, flags_size));
+		}
+		for t in &bitmask_types {
+			type_layouts.insert(t.0.clone(), (flags_size, flags_size));
+		}
+		for t in &handle_types {
+			type_layouts.insert(t.clone(), (8, 8));
+		}
+		let mut array_constants = BTreeMap::<String, usize>::new();
+		for consts in &api_constants {
+			if let Ok(value) = consts.1.trim_end_matches('U').parse::<usize>() {
+				array_constants.insert(consts.0.clone(), value);
+			}
+		}
+		for consts in &api_constants {
+			if let Some(value) = array_constants.get(&consts.1).cloned() {
+				array_constants.insert(consts.0.clone(), value);
+			}
+		}
+
+		// Print structs
+		for s in &structs {
+
+			// A struct required by several extensions is declared once, for any of them
+			let names: Vec<String> = type_providers(&s.0);
+			if !names.is_empty() {
+				write!(output, "\n// {}\n", names.join(", ")).expect("Failed to write");
+			}
+			let cfg = extensions_cfg(&names);
+
+			// Can't use automatic Debug since rust disallows this for arrays > 32
+			if s.2 {
+				write!(output, "{}#[derive(Copy, Clone)]\n#[repr(C)]\npub struct {} {{\n{}\n}}\n", cfg, s.0, s.1).expect("Failed to write");
+			} else {
+				write!(output, "{}#[derive(Debug, Copy, Clone)]\n#[repr(C)]\npub struct {} {{\n{}\n}}\n", cfg, s.0, s.1).expect("Failed to write");
+			}
+
+			// Check the struct against the C layout on each target, the targets with the same layout share the checks.
+			// Structs that can not be checked on a target (platform types, bitfields) are listed with the reason
+			let mut layouts = Vec::<(StructLayout, Vec<&str>)>::new();
+			let mut skipped = Vec::<(String, Vec<&str>)>::new();
+			for target in LAYOUT_TARGETS {
+				match c_struct_layout(&s.0, target, &layout_types, &type_layouts, &dispatchable_handles, &array_constants) {
+					Ok(layout) => match layouts.iter_mut().find(|l| l.0 == layout) {
+						Some(same) => same.1.push(target.cfg),
+						None => layouts.push((layout, vec![target.cfg]))
 					},
-					_ => ()
+					Err(reason) => match skipped.iter_mut().find(|r| r.0 == reason) {
+						Some(same) => same.1.push(target.cfg),
+						None => skipped.push((reason, vec![target.cfg]))
+					}
+				}
+			}
+			for (reason, targets) in skipped {
+				let on = if targets.len() == LAYOUT_TARGETS.len() { String::new() } else { format!(" on {}", targets.join(" or ")) };
+				eprintln!("Warning: the layout of {} is not checked{}, {}", s.0, on, reason);
+				writeln!(output, "// The layout of {} is not checked{}, {}", s.0, on, reason).expect("Failed to write");
+			}
+			for ((size, align, offsets), targets) in layouts {
+				let predicate = if targets.len() == 1 { targets[0].to_string() } else { format!("any({})", targets.join(", ")) };
+				write!(output, "{}#[cfg({})]\nconst _: () = {{\n", cfg, predicate).expect("Failed to write");
+				writeln!(output, "\tassert!(::core::mem::size_of::<{}>() == {});", s.0, size).expect("Failed to write");
+				writeln!(output, "\tassert!(::core::mem::align_of::<{}>() == {});", s.0, align).expect("Failed to write");
+				for offset in offsets {
+					writeln!(output, "\tassert!(::core::mem::offset_of!({}, {}) == {});", s.0, offset.0, offset.1).expect("Failed to write");
+				}
+				writeln!(output, "}};").expect("Failed to write");
+			}
+		}
+
+		// Print format metadata, for the formats in the format enum (the ones from extensions only with them)
+		let format_enum = format!("{}Format", profile.type_prefix);
+		if let Some(format_values) = enums.iter().find(|e| e.name == format_enum) {
+
+			let mut described = Vec::<(&Format, String)>::new();
+			for format in &formats {
+				if format_values.values.iter().any(|v| v.0 == format.name) {
+					described.push((format, String::new()));
+				} else if let Some(ext) = extensions.iter().filter(|ext| ext.supported != "disabled").find(|ext| ext.types.iter().any(|t| match *t {
+					ExtensionNewType::EnumExtension { ref name, ref extends, .. } => *name == format.name && *extends == format_enum,
+					_ => false
+				})) {
+					described.push((format, extensions_cfg(std::slice::from_ref(&ext.name))));
+				}
+			}
+
+			let aspect_flags = format!("{}ImageAspectFlags", profile.type_prefix);
+			let aspect = |bit: &str| format!("{}::{}IMAGE_ASPECT_{}_BIT", aspect_flags, profile.constant_prefix, bit);
+
+			write!(output, "{}", fluff_format).expect("Failed to write");
+			write!(output, "\nimpl {} {{\n", format_enum).expect("Failed to write");
+
+			// One arm per format, so formats from extensions can have their cfg
+			let mut method = |signature: &str, comment: &str, arm: &dyn Fn(&Format) -> Option<String>, default: &str| {
+				write!(output, "\n\t// {}\n\tpub fn {} {{\n\t\tmatch self {{\n", comment, signature).expect("Failed to write");
+				for &(format, ref cfg) in &described {
+					if let Some(value) = arm(format) {
+						writeln!(output, "{}\t\t\t{}::{} => {},", cfg, format_enum, format.name, value).expect("Failed to write");
+					}
+				}
+				write!(output, "\t\t\t_ => {}\n\t\t}}\n\t}}\n", default).expect("Failed to write");
+			};
+
+			let has_component = |format: &Format, name: &str| format.components.iter().any(|c| c.name == name);
+
+			method("block_size(self) -> u32", "Bytes per texel block, 0 for formats without a description",
+				&|f| Some(f.block_size.to_string()), "0");
+			method("block_extent(self) -> (u32, u32, u32)", "Width, height and depth of a texel block",
+				&|f| if f.block_extent != (1, 1, 1) { Some(format!("{:?}", f.block_extent)) } else { None }, "(1, 1, 1)");
+			method("is_compressed(self) -> bool", "Block compressed (BC, ETC2, ASTC, ...)",
+				&|f| if !f.compressed.is_empty() { Some("true".to_string()) } else { None }, "false");
+			method("is_depth(self) -> bool", "Has a depth component",
+				&|f| if has_component(f, "D") { Some("true".to_string()) } else { None }, "false");
+			method("has_stencil(self) -> bool", "Has a stencil component",
+				&|f| if has_component(f, "S") { Some("true".to_string()) } else { None }, "false");
+			method(&format!("aspect_mask(self) -> {}", aspect_flags), "Aspects of an image view of the whole format",
+				&|f| match (has_component(f, "D"), has_component(f, "S")) {
+					(true, true) => Some(format!("{} | {}", aspect("DEPTH"), aspect("STENCIL"))),
+					(true, false) => Some(aspect("DEPTH")),
+					(false, true) => Some(aspect("STENCIL")),
+					(false, false) => None
+				}, &aspect("COLOR"));
+			method("components(self) -> &'static [FormatComponent]", "Components in memory order",
+				&|f| {
+					let components: Vec<String> = f.components.iter().map(|c| format!("FormatComponent {{ name: \"{}\", bits: {}, numeric_format: \"{}\" }}", c.name, c.bits.parse::<u32>().unwrap_or(0), c.numeric_format)).collect();
+					Some(format!("&[{}]", components.join(", ")))
+				}, "&[]");
+
+			writeln!(output, "}}").expect("Failed to write");
+		}
+
+		// Print extension metadata
+		writeln!(output, "{}", fluff3).expect("Failed to write");
+		writeln!(output, "pub static EXTENSIONS: &'static [ExtensionInfo] = &[").expect("Failed to write");
+		for ext in &extensions {
+
+			if ext.supported != "disabled" {
+
+				let requires: Vec<String> = ext.requires.iter().map(|r| format!("\"{}\"", r)).collect();
+				writeln!(output, "\tExtensionInfo {{ name: \"{}\", number: {}, extension_type: ExtensionType::{}, spec_version: {}, requires: &[{}], depends: {}, promoted_to: {}, deprecated_by: {}, obsoleted_by: {} }},",
+					ext.name,
+					ext.number,
+					if ext.extension_type == "instance" { "Instance" } else { "Device" },
+					ext.spec_version,
+					requires.join(", "),
+					if ext.depends.is_empty() { "None".to_string() } else { format!("Some(\"{}\")", ext.depends) },
+					if ext.promoted_to.is_empty() { "None".to_string() } else { format!("Some(\"{}\")", ext.promoted_to) },
+					if ext.deprecated_by.is_empty() { "None".to_string() } else { format!("Some(\"{}\")", ext.deprecated_by) },
+					if ext.obsoleted_by.is_empty() { "None".to_string() } else { format!("Some(\"{}\")", ext.obsoleted_by) }).expect("Failed to write");
+			}
+		}
+		write!(output, "];\n\n").expect("Failed to write");
+
+		// Print functions
+		writeln!(output, "#[link(name = \"{}\")]", profile.library).expect("Failed to write");
+		writeln!(output, "extern \"C\" {{").expect("Failed to write");
+
+		for feature_block in &features {
+
+			let mut once = true;
+			for feature_content in &feature_block.contents {
+
+				if let FeatureContent::Command(ref name) = *feature_content {
+					if let Some(cmd) = commands.get(name) {
+						if once {
+							write!(output, "\n\t// {}\n", feature_block.comment).expect("Failed to write");
+							once = false;
+						}
+						writeln!(output, "\tpub fn {}({}){};", name, cmd.0, returns(&cmd.1)).expect("Failed to write");
+					}
+				}
+			}
+		}
+
+		writeln!(output, "}}").expect("Failed to write");
+
+		// Print Vec returning wrappers for the core two-call enumerations, these need std for Vec
+		// OpenXR's enumerations (capacity, count, array) are not wrapped, so its module is empty
+		let mut wrappers = String::new();
+		for feature_block in &features {
+
+			for feature_content in &feature_block.contents {
+
+				if let FeatureContent::Command(ref name) = *feature_content {
+					if let (Some(cmd), Some(params)) = (commands.get(name), command_params.get(name)) {
+						if let Some(element) = enumeration_element_type(params, &layout_types) {
+							wrappers.push_str(&format!("\n{}", enumeration_wrapper(name, &format!("super::{}", name), "", &cmd.1, params, &element)));
+						}
+					}
 				}
 			}
 		}
 
-		write!(output, "}}\n").expect("Failed to write");
+		writeln!(output, "\n#[cfg(feature = \"std\")]\npub mod enumerate {{\n{}{}}}", if wrappers.is_empty() { "" } else { "\tuse super::*;\n" }, wrappers).expect("Failed to write");
 
 		// Print extension functions
+		write!(output, "{}", fluff_first_proc_addr).expect("Failed to write");
+
 		write!(output, "\n\t// Extensions\n").expect("Failed to write");
 
-		write!(output, "\tpub struct VulkanFunctionPointers {{\n").expect("Failed to write");
+		writeln!(output, "\tpub struct {} {{", profile.function_pointers).expect("Failed to write");
 
 		for ext in &extensions {
 
@@ -1011,26 +3200,53 @@ impl std::fmt::Debug for VkPhysicalDeviceMemoryProperties {
 				let mut once = true;
 				for ext_cmd in &ext.types {
 
-					match *ext_cmd {
-						ExtensionNewType::Command(ref name) => {
-							if let Some(cmd) = commands.get(name) {
-								if once {
-									write!(output, "\n\t\t// {}\n", ext.name).expect("Failed to write");
-									once = false;
-								}
-								write!(output, "\n#[cfg(feature = \"{}\")]\n", ext.name).expect("Failed to write");
-								write!(output, "\t\tpub {}: Option<extern \"C\" fn({}) -> {}>,\n", name.replace("vk", ""), cmd.0, cmd.1).expect("Failed to write");
+					if let ExtensionNewType::Command(ref name) = *ext_cmd {
+						if let Some(cmd) = commands.get(&command_alias_target(&command_aliases, name)) {
+							if once {
+								write!(output, "\n\t\t// {}\n", ext.name).expect("Failed to write");
+								once = false;
 							}
-						},
-						_ => ()
+							write!(output, "\n{}", extensions_cfg(&[ext.provider(name)])).expect("Failed to write");
+							writeln!(output, "\t\tpub {}: Option<extern \"C\" fn({}){}>,", profile.function_pointer_name(name), cmd.0, returns(&cmd.1)).expect("Failed to write");
+						}
 					}
 				}
 			}
 		}
 
+		// Core commands that extensions have aliases for, so the same member works with the extension on older implementations
+		let promoted_commands: Vec<&String> = features.iter().flat_map(|f| f.contents.iter()).filter_map(|c| match *c {
+			FeatureContent::Command(ref name) if commands.contains_key(name) && command_names(name).len() > 1 => Some(name),
+			_ => None
+		}).collect();
 
+		if !promoted_commands.is_empty() {
+			write!(output, "\n\t\t// Promoted to core, loaded by an extension's name when the implementation only has the extension\n").expect("Failed to write");
+		}
+		for name in &promoted_commands {
+			let cmd = &commands[*name];
+			write!(output, "\n\t\tpub {}: Option<extern \"C\" fn({}){}>,\n", profile.function_pointer_name(name), cmd.0, returns(&cmd.1)).expect("Failed to write");
+		}
 
-		write!(output, "\t}}\n\n\timpl VulkanFunctionPointers {{\n\t\tpub fn new(instance: VkInstance) -> VulkanFunctionPointers {{\n\t\t\tassert!(instance != VK_NULL_HANDLE);\n\t\t\tVulkanFunctionPointers {{\n").expect("Failed to write");
+		// Loads a member by the command's names, the first one the implementation has
+		let load_function = |name: &String, cmd: &(String, String)| -> String {
+			let names = command_names(name);
+			let function_type = format!("Option<extern \"C\" fn({}){}>", cmd.0, returns(&cmd.1));
+			if names.len() == 1 && profile.get_proc_addr_returns {
+				format!("unsafe {{ ::core::mem::transmute::<*const c_void,{}>({}GetInstanceProcAddr(instance, \"{}\\0\".as_ptr())) }}", function_type, profile.command_prefix, name)
+			} else if names.len() == 1 {
+				format!("unsafe {{ let mut function: *const c_void = ::core::ptr::null(); {}GetInstanceProcAddr(instance, \"{}\\0\".as_ptr(), &mut function); ::core::mem::transmute::<*const c_void,{}>(function) }}", profile.command_prefix, name, function_type)
+			} else {
+				let names: Vec<String> = names.iter().map(|n| format!("\"{}\\0\"", n)).collect();
+				let get_proc_addr = if profile.get_proc_addr_returns {
+					format!("{}GetInstanceProcAddr(instance, name)", profile.command_prefix)
+				} else {
+					format!("{{ let mut function: *const c_void = ::core::ptr::null(); {}GetInstanceProcAddr(instance, name, &mut function); function }}", profile.command_prefix)
+				};
+				format!("unsafe {{ ::core::mem::transmute::<*const c_void,{}>(first_proc_addr(&[{}], &|name| {})) }}", function_type, names.join(", "), get_proc_addr)
+			}
+		};
+		write!(output, "\t}}\n\n\timpl {0} {{\n\t\tpub fn new(instance: {1}Instance) -> {0} {{\n\t\t\tassert!(instance != {2}NULL_HANDLE);\n\t\t\t{0} {{\n", profile.function_pointers, profile.type_prefix, profile.constant_prefix).expect("Failed to write");
 
 		for ext in &extensions {
 
@@ -1039,23 +3255,769 @@ impl std::fmt::Debug for VkPhysicalDeviceMemoryProperties {
 				let mut once = true;
 				for ext_cmd in &ext.types {
 
-					match *ext_cmd {
-						ExtensionNewType::Command(ref name) => {
-							if let Some(cmd) = commands.get(name) {
-								if once {
-									write!(output, "\n\t\t\t// {}\n", ext.name).expect("Failed to write");
-									once = false;
-								}
-								write!(output, "\n#[cfg(feature = \"{}\")]\n", ext.name).expect("Failed to write");
-								write!(output, "\t\t\t{}: unsafe {{ mem::transmute::<*const c_void,Option<extern \"C\" fn({}) -> {}>>(vkGetInstanceProcAddr(instance, \"{}\\0\".as_ptr())) }},\n", name.replace("vk", ""), cmd.0, cmd.1, name).expect("Failed to write");
+					if let ExtensionNewType::Command(ref name) = *ext_cmd {
+						if let Some(cmd) = commands.get(&command_alias_target(&command_aliases, name)) {
+							if once {
+								write!(output, "\n\t\t\t// {}\n", ext.name).expect("Failed to write");
+								once = false;
 							}
-						},
-						_ => ()
+							write!(output, "\n{}", extensions_cfg(&[ext.provider(name)])).expect("Failed to write");
+							writeln!(output, "\t\t\t{}: {},", profile.function_pointer_name(name), load_function(name, cmd)).expect("Failed to write");
+						}
 					}
 				}
 			}
 		}
 
+		if !promoted_commands.is_empty() {
+			write!(output, "\n\t\t\t// Promoted to core\n").expect("Failed to write");
+		}
+		for name in &promoted_commands {
+			write!(output, "\n\t\t\t{}: {},\n", profile.function_pointer_name(name), load_function(name, &commands[*name])).expect("Failed to write");
+		}
+
 		write!(output, "}}\n\t\t}}\n\t}}\n").expect("Failed to write");
+
+		// Print Vec returning wrappers for the extension two-call enumerations
+		write!(output, "\n#[cfg(feature = \"std\")]\nimpl {} {{\n", profile.function_pointers).expect("Failed to write");
+
+		for ext in &extensions {
+
+			if ext.supported != "disabled" {
+
+				for ext_cmd in &ext.types {
+
+					if let ExtensionNewType::Command(ref name) = *ext_cmd {
+						if let (Some(cmd), Some(params)) = (commands.get(name), command_params.get(name)) {
+							if let Some(element) = enumeration_element_type(params, &layout_types) {
+								let function_pointer = format!("self.{}.expect(\"{} is not loaded\")", profile.function_pointer_name(name), name);
+								write!(output, "\n{}{}", extensions_cfg(&[ext.provider(name)]), enumeration_wrapper(name, &function_pointer, "&self, ", &cmd.1, params, &element)).expect("Failed to write");
+							}
+						}
+					}
+				}
+			}
+		}
+
+		writeln!(output, "}}").expect("Failed to write");
+
+		// Print a loader struct per extension, so only the extensions that are enabled need to be loaded
+		let get_device_proc_addr = format!("{}GetDeviceProcAddr", profile.command_prefix);
+		for ext in extensions.iter().filter(|ext| ext.supported != "disabled") {
+
+			let ext_commands: Vec<(&String, &(String, String))> = ext.types.iter().filter_map(|t| match *t {
+				ExtensionNewType::Command(ref name) => commands.get(&command_alias_target(&command_aliases, name)).map(|cmd| (name, cmd)),
+				_ => None
+			}).collect();
+			if ext_commands.is_empty() {
+				continue;
+			}
+
+			// Device extensions are loaded from the device, unless they have commands for the instance or physical devices
+			let from_device = profile.get_proc_addr_returns && ext.extension_type == "device" && commands.contains_key(&get_device_proc_addr) && ext_commands.iter().all(|&(name, _)| {
+				command_params.get(&command_alias_target(&command_aliases, name)).and_then(|params| dispatch_level(name, params)) == Some(DispatchLevel::Device)
+			});
+			let (handle, get_proc_addr) = if from_device {
+				(format!("device: {}Device", profile.type_prefix), format!("{}(device, name)", get_device_proc_addr))
+			} else if profile.get_proc_addr_returns {
+				(format!("instance: {}Instance", profile.type_prefix), format!("{}GetInstanceProcAddr(instance, name)", profile.command_prefix))
+			} else {
+				(format!("instance: {}Instance", profile.type_prefix), format!("{{ let mut function: *const c_void = ::core::ptr::null(); {}GetInstanceProcAddr(instance, name, &mut function); function }}", profile.command_prefix))
+			};
+
+			let struct_name = extension_struct_name(&ext.name, profile.constant_prefix);
+			let cfg = extensions_cfg(std::slice::from_ref(&ext.name));
+
+			// Commands from <require> blocks with their own depends are optional, the implementation may not have them
+			write!(output, "\n// {}'s commands, commands that depend on other extensions too are optional\n{}#[derive(Copy, Clone)]\npub struct {} {{\n", ext.name, cfg, struct_name).expect("Failed to write");
+			for &(name, cmd) in &ext_commands {
+				if ext.conditions.contains_key(name) {
+					writeln!(output, "{}\tpub {}: Option<extern \"C\" fn({}){}>,", extensions_cfg(&[ext.provider(name)]), profile.function_pointer_name(name), cmd.0, returns(&cmd.1)).expect("Failed to write");
+				} else {
+					writeln!(output, "\tpub {}: extern \"C\" fn({}){},", profile.function_pointer_name(name), cmd.0, returns(&cmd.1)).expect("Failed to write");
+				}
+			}
+
+			write!(output, "}}\n\n{}impl {} {{\n\n\t// Err has the name of the first command the implementation does not have\n\tpub unsafe fn load({}) -> Result<{}, &'static str> {{\n\t\tOk({} {{\n", cfg, struct_name, handle, struct_name, struct_name).expect("Failed to write");
+			for &(name, cmd) in &ext_commands {
+				let names: Vec<String> = command_names(name).iter().map(|n| format!("\"{}\\0\"", n)).collect();
+				let function = format!("::core::mem::transmute::<*const c_void, Option<extern \"C\" fn({}){}>>(first_proc_addr(&[{}], &|name| {}))", cmd.0, returns(&cmd.1), names.join(", "), get_proc_addr);
+				if ext.conditions.contains_key(name) {
+					writeln!(output, "{}\t\t\t{}: {},", extensions_cfg(&[ext.provider(name)]), profile.function_pointer_name(name), function).expect("Failed to write");
+				} else {
+					writeln!(output, "\t\t\t{}: {}.ok_or(\"{}\")?,", profile.function_pointer_name(name), function, name).expect("Failed to write");
+				}
+			}
+			write!(output, "\t\t}})\n\t}}\n}}\n").expect("Failed to write");
+		}
+
+		// Print the Handle trait, with a marker type for each handle as the handles themselves are all u64
+		let object_type_enum = format!("{}ObjectType", profile.type_prefix);
+		let debug_report_enum = format!("{}DebugReportObjectTypeEXT", profile.type_prefix);
+		if let Some(object_types) = enums.iter().find(|e| e.name == object_type_enum) {
+
+			let core_commands: Vec<&String> = features.iter().flat_map(|f| f.contents.iter()).filter_map(|c| match *c { FeatureContent::Command(ref name) => Some(name), _ => None }).collect();
+			let debug_report_types = enums.iter().find(|e| e.name == debug_report_enum);
+			let allocation_callbacks = format!("{}AllocationCallbacks", profile.type_prefix);
+
+			write!(output, "{}", fluff_handle.replace("VkObjectType", &object_type_enum).replace("VulkanFunctionPointers", profile.function_pointers)).expect("Failed to write");
+			if debug_report_types.is_some() {
+				write!(output, "\n\t// The same object type for VK_EXT_debug_report, unknown for types it does not have\n\tconst DEBUG_REPORT_OBJECT_TYPE: {};\n", debug_report_enum).expect("Failed to write");
+			}
+			write!(output, "}}\n\npub mod handle {{\n\tuse super::*;\n").expect("Failed to write");
+
+			for handle in &handle_types {
+
+				let providers = type_providers(handle);
+				let unknown = format!("{}OBJECT_TYPE_UNKNOWN", profile.constant_prefix);
+
+				// An object type value added by an extension is only used if that extension also declares the handle
+				let object_type = match handle_object_types.get(handle) {
+					Some(value) if object_types.values.iter().any(|v| v.0 == *value) => value.clone(),
+					found => {
+						let extension_value = extensions.iter().filter(|ext| providers.contains(&ext.provider(handle))).flat_map(|ext| ext.types.iter()).find_map(|ext_type| match *ext_type {
+							ExtensionNewType::EnumExtension { ref name, ref extends, ref comment, .. } if *extends == object_type_enum && (Some(name) == found || comment == handle) => Some(name.clone()),
+							_ => None
+						});
+						extension_value.unwrap_or(unknown.clone())
+					}
+				};
+
+				let parent = match handle_parents.get(handle) {
+					Some(parent) if handle_types.contains(parent) => parent.clone(),
+					_ => handle.clone()
+				};
+
+				// The first vkDestroy* or vkFree* command whose last parameter (before pAllocator) is the handle
+				let destroy_prefixes = [format!("{}Destroy", profile.command_prefix), format!("{}Free", profile.command_prefix)];
+				let destroy_command = commands.keys().filter(|name| destroy_prefixes.iter().any(|p| name.starts_with(p))).find(|name| {
+					let params: Vec<&CommandParam> = command_params[*name].iter().filter(|p| p.type_name != allocation_callbacks).collect();
+					params.last().map(|p| p.type_name == *handle && !p.ptr_ptr).unwrap_or(false)
+				});
+
+				write!(output, "\n{}\t#[allow(non_camel_case_types)]\n\tpub struct {};\n\n{}\timpl Handle for {} {{\n", extensions_cfg(&providers), handle, extensions_cfg(&providers), handle).expect("Failed to write");
+				write!(output, "\t\tconst NAME: &'static str = \"{}\";\n\t\tconst OBJECT_TYPE: {} = {}::{};\n", handle, object_type_enum, object_type_enum, object_type).expect("Failed to write");
+				if let Some(debug_report_types) = debug_report_types {
+					let value = format!("{}_EXT", object_type.replacen("OBJECT_TYPE_", "DEBUG_REPORT_OBJECT_TYPE_", 1));
+					let value = if debug_report_types.values.iter().any(|v| v.0 == value) { value } else { format!("{}DEBUG_REPORT_OBJECT_TYPE_UNKNOWN_EXT", profile.constant_prefix) };
+					writeln!(output, "\t\tconst DEBUG_REPORT_OBJECT_TYPE: {} = {}::{};", debug_report_enum, debug_report_enum, value).expect("Failed to write");
+				}
+				writeln!(output, "\t\ttype Parent = {};", parent).expect("Failed to write");
+
+				match destroy_command {
+					Some(name) => {
+						let params = &command_params[name];
+
+						// (handle, pAllocator) or (owner, handle, pAllocator), anything else frees from a pool
+						let arguments: Option<Vec<&str>> = params.iter().enumerate().map(|(i, p)| {
+							if p.type_name == allocation_callbacks {
+								Some("::core::ptr::null()")
+							} else if p.ptr {
+								None
+							} else if i == params.len() - 1 || params[i + 1].type_name == allocation_callbacks {
+								Some("handle")
+							} else if i == 0 {
+								Some("owner")
+							} else {
+								None
+							}
+						}).collect();
+
+						writeln!(output, "\t\tconst DESTROY_COMMAND: Option<&'static str> = Some(\"{}\");", name).expect("Failed to write");
+						match arguments {
+							Some(arguments) => {
+								let call = if core_commands.contains(&name) {
+									format!("{}({})", name, arguments.join(", "))
+								} else {
+									format!("functions.{}.expect(\"{} is not loaded\")({})", profile.function_pointer_name(name), name, arguments.join(", "))
+								};
+								let unused = if core_commands.contains(&name) { "_functions" } else { "functions" };
+								let owner = if arguments.contains(&"owner") { "owner" } else { "_owner" };
+								write!(output, "\t\tunsafe fn destroy({}: &{}, {}: u64, handle: u64) -> bool {{\n\t\t\t{};\n\t\t\ttrue\n\t\t}}\n", unused, profile.function_pointers, owner, call).expect("Failed to write");
+							},
+							None => {
+								write!(output, "\t\tunsafe fn destroy(_functions: &{}, _owner: u64, _handle: u64) -> bool {{\n\t\t\tfalse\n\t\t}}\n", profile.function_pointers).expect("Failed to write");
+							}
+						}
+					},
+					None => {
+						write!(output, "\t\tconst DESTROY_COMMAND: Option<&'static str> = None;\n\t\tunsafe fn destroy(_functions: &{}, _owner: u64, _handle: u64) -> bool {{\n\t\t\tfalse\n\t\t}}\n", profile.function_pointers).expect("Failed to write");
+					}
+				}
+				writeln!(output, "\t}}").expect("Failed to write");
+			}
+			writeln!(output, "}}").expect("Failed to write");
+		}
+
+		// StructureType values, to find the structs in pNext chains
+		let structure_type_enum = format!("{}StructureType", profile.type_prefix);
+		let mut structure_types = BTreeMap::<String, i64>::new();
+		for e in enums.iter().filter(|e| e.name == structure_type_enum) {
+			for v in &e.values {
+				structure_types.insert(v.0.clone(), v.1 as i64);
+			}
+		}
+		for ext in &extensions {
+			for ext_enum in &ext.types {
+				if let ExtensionNewType::EnumExtension { ref name, offset, ref extends, ref dir, .. } = *ext_enum {
+					if *extends == structure_type_enum {
+						let value = (extension_base_number + (ext.number - 1) * extension_block_size + offset) as i64;
+						structure_types.insert(name.clone(), if dir == "-" { -value } else { value });
+					}
+				}
+			}
+		}
+
+		// The structs in pNext chains, for registries that have the base struct to walk them with
+		let base_structure = format!("{}BaseOutStructure", profile.type_prefix);
+		let chain_structs: Vec<&(String, String, bool)> = structs.iter().filter(|s| structure_types.contains_key(&layout_types[&s.0].structure_type)).collect();
+		let chains = layout_types.get(&base_structure).map(|t| t.members.iter().any(|m| m.name == "pNext")).unwrap_or(false);
+		if chains {
+
+			write!(output, "\n// A struct in a pNext chain, see chain\n#[derive(Copy, Clone)]\npub enum ChainStruct<'a> {{\n").expect("Failed to write");
+			for s in &chain_structs {
+				writeln!(output, "{}\t{}(&'a {}),", extensions_cfg(&type_providers(&s.0)), s.0, s.0).expect("Failed to write");
+			}
+			write!(output, "\n\t// A struct vkraw does not know, with its sType\n\tUnknown(i32)\n}}\n").expect("Failed to write");
+
+			// Structs with arrays have no Debug
+			write!(output, "\nimpl<'a> core::fmt::Debug for ChainStruct<'a> {{\n\tfn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{\n\t\tmatch *self {{\n").expect("Failed to write");
+			for s in &chain_structs {
+				if s.2 {
+					writeln!(output, "{}\t\t\tChainStruct::{}(_) => f.write_str(\"{} {{ .. }}\"),", extensions_cfg(&type_providers(&s.0)), s.0, s.0).expect("Failed to write");
+				} else {
+					writeln!(output, "{}\t\t\tChainStruct::{}(value) => core::fmt::Debug::fmt(value, f),", extensions_cfg(&type_providers(&s.0)), s.0).expect("Failed to write");
+				}
+			}
+			write!(output, "\t\t\tChainStruct::Unknown(sType) => write!(f, \"{{{{ sType: {{}}, .. }}}}\", sType)\n\t\t}}\n\t}}\n}}\n").expect("Failed to write");
+
+			write!(output, "\n// The structs of a pNext chain, see chain\n#[derive(Copy, Clone)]\npub struct Chain<'a> {{\n\tnext: *const c_void,\n\tmarker: core::marker::PhantomData<&'a c_void>\n}}\n").expect("Failed to write");
+			write!(output, "\nimpl<'a> Iterator for Chain<'a> {{\n\ttype Item = ChainStruct<'a>;\n\n\tfn next(&mut self) -> Option<ChainStruct<'a>> {{\n\t\tif self.next.is_null() {{\n\t\t\treturn None;\n\t\t}}\n\t\tlet current = self.next;\n\t\tunsafe {{\n").expect("Failed to write");
+			write!(output, "\t\t\tself.next = (*(current as *const {})).pNext as *const c_void;\n\n\t\t\t// sType is read as an i32, it can be a value vkraw does not know\n\t\t\tSome(match *(current as *const i32) {{\n", base_structure).expect("Failed to write");
+			for s in &chain_structs {
+				writeln!(output, "{}\t\t\t\t{} => ChainStruct::{}(&*(current as *const {})),", extensions_cfg(&type_providers(&s.0)), structure_types[&layout_types[&s.0].structure_type], s.0, s.0).expect("Failed to write");
+			}
+			write!(output, "\t\t\t\tsType => ChainStruct::Unknown(sType)\n\t\t\t}})\n\t\t}}\n\t}}\n}}\n").expect("Failed to write");
+			write!(output, "\nimpl<'a> core::fmt::Debug for Chain<'a> {{\n\tfn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {{\n\t\tf.debug_list().entries(*self).finish()\n\t}}\n}}\n").expect("Failed to write");
+			write!(output, "\n// The structs in a pNext chain, found from their sType, i.e. to print it with {{:?}}\n// The chain has to stay valid while they are used\npub unsafe fn chain<'a>(pNext: *const c_void) -> Chain<'a> {{\n\tChain {{ next: pNext, marker: core::marker::PhantomData }}\n}}\n").expect("Failed to write");
+		}
+
+		// The rest are frameworks for the Vulkan loader's interfaces
+		if !profile.frameworks {
+			return;
+		}
+
+		// Commands with the extensions that provide them (none for core commands), in registry order
+		let mut command_extensions = Vec::<(String, Vec<String>)>::new();
+
+		for feature_block in &features {
+
+			for feature_content in &feature_block.contents {
+
+				if let FeatureContent::Command(ref name) = *feature_content {
+					if commands.contains_key(name) && command_params.contains_key(name) && !command_extensions.iter().any(|c| c.0 == *name) {
+						command_extensions.push((name.clone(), Vec::new()));
+					}
+				}
+			}
+		}
+
+		for ext in &extensions {
+
+			if ext.supported != "disabled" {
+
+				for ext_cmd in &ext.types {
+
+					if let ExtensionNewType::Command(ref name) = *ext_cmd {
+						if commands.contains_key(name) && command_params.contains_key(name) {
+							match command_extensions.iter().position(|c| c.0 == *name) {
+								Some(i) => {
+									if !command_extensions[i].1.is_empty() && !command_extensions[i].1.contains(&ext.provider(name)) {
+										command_extensions[i].1.push(ext.provider(name));
+									}
+								},
+								None => command_extensions.push((name.clone(), vec![ext.provider(name)]))
+							}
+						}
+					}
+				}
+			}
+		}
+
+		// Commands a layer can intercept
+		let layer_commands: Vec<(String, DispatchLevel, Vec<String>)> = command_extensions.iter().filter_map(|c| {
+			dispatch_level(&c.0, &command_params[&c.0]).map(|level| (c.0.clone(), level, c.1.clone()))
+		}).collect();
+
+		// Print the layer framework
+		write!(output, "\n// Framework for writing layers, see vklayer for an example\n#[cfg(feature = \"layer\")]\npub mod layer {{\n\tuse super::*;\n{}", fluff_layer).expect("Failed to write");
+
+		for &(level, table, handle, get_proc_addr) in &[
+			(DispatchLevel::Instance, "InstanceDispatch", "instance: VkInstance", "vkGetInstanceProcAddr"),
+			(DispatchLevel::Device, "DeviceDispatch", "device: VkDevice", "vkGetDeviceProcAddr")] {
+
+			let handle_name = &handle[..handle.find(':').unwrap()];
+			let get_proc_addr_cmd = commands.get(get_proc_addr).expect("vk.xml has no vkGetInstanceProcAddr or vkGetDeviceProcAddr");
+			let get_proc_addr_type = format!("extern \"C\" fn({}){}", get_proc_addr_cmd.0, returns(&get_proc_addr_cmd.1));
+
+			write!(output, "\n\t// The next layer's commands for {}\n\tpub struct {} {{\n\t\tpub {},\n", if level == DispatchLevel::Instance { "an instance" } else { "a device" }, table, handle).expect("Failed to write");
+			for (name, _, exts) in layer_commands.iter().filter(|c| c.1 == level) {
+				let cmd = &commands[name];
+				writeln!(output, "{}\t\tpub {}: Option<extern \"C\" fn({}){}>,", extensions_cfg(exts), profile.function_pointer_name(name), cmd.0, returns(&cmd.1)).expect("Failed to write");
+			}
+			write!(output, "\t}}\n\n\timpl {} {{\n\t\tpub unsafe fn load({}, get_proc_addr: {}) -> {} {{\n\t\t\t{} {{\n\t\t\t\t{}: {},\n", table, handle, get_proc_addr_type, table, table, handle_name, handle_name).expect("Failed to write");
+			for (name, _, exts) in layer_commands.iter().filter(|c| c.1 == level) {
+				let cmd = &commands[name];
+				if name == get_proc_addr {
+					writeln!(output, "{}\t\t\t\t{}: Some(get_proc_addr),", extensions_cfg(exts), profile.function_pointer_name(name)).expect("Failed to write");
+				} else if command_names(name).len() == 1 {
+					writeln!(output, "{}\t\t\t\t{}: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern \"C\" fn({}){}>>(get_proc_addr({}, \"{}\\0\".as_ptr())),", extensions_cfg(exts), profile.function_pointer_name(name), cmd.0, returns(&cmd.1), handle_name, name).expect("Failed to write");
+				} else {
+					// The next layer may only know the command by an extension's name, or only by the core name
+					let names: Vec<String> = command_names(name).iter().map(|n| format!("\"{}\\0\"", n)).collect();
+					writeln!(output, "{}\t\t\t\t{}: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern \"C\" fn({}){}>>(first_proc_addr(&[{}], &|name| get_proc_addr({}, name))),", extensions_cfg(exts), profile.function_pointer_name(name), cmd.0, returns(&cmd.1), names.join(", "), handle_name).expect("Failed to write");
+				}
+			}
+			write!(output, "\t\t\t}}\n\t\t}}\n").expect("Failed to write");
+
+			// Calls to the next layer, for the Layer defaults and for overrides to forward to
+			for (name, _, exts) in layer_commands.iter().filter(|c| c.1 == level) {
+				let cmd = &commands[name];
+				let arguments: Vec<&str> = command_params[name].iter().map(|p| p.name.as_ref()).collect();
+				write!(output, "\n{}\t\tpub unsafe fn {}(&self, {}){} {{\n\t\t\tself.{}.expect(\"{} is not loaded\")({})\n\t\t}}\n", extensions_cfg(exts), name, cmd.0, returns(&cmd.1), profile.function_pointer_name(name), name, arguments.join(", ")).expect("Failed to write");
+			}
+			writeln!(output, "\t}}").expect("Failed to write");
+		}
+
+		// The trait layers implement, vkGetInstanceProcAddr and vkGetDeviceProcAddr are handled by the framework
+		write!(output, "\n\t// A layer, every command it does not override is passed on to the next layer\n\tpub trait Layer: Sized + Sync + 'static {{\n\n\t\t// The layer the loader's calls go to\n\t\tfn get() -> &'static Self;\n").expect("Failed to write");
+		for &(ref name, level, ref exts) in layer_commands.iter().filter(|c| c.0 != "vkGetInstanceProcAddr" && c.0 != "vkGetDeviceProcAddr") {
+			let cmd = &commands[name];
+			let arguments: Vec<&str> = command_params[name].iter().map(|p| p.name.as_ref()).collect();
+			let table = if level == DispatchLevel::Instance { "InstanceDispatch" } else { "DeviceDispatch" };
+			write!(output, "\n{}\t\tunsafe fn {}(&self, dispatch: &{}, {}){} {{\n\t\t\tdispatch.{}({})\n\t\t}}\n", extensions_cfg(exts), name, table, cmd.0, returns(&cmd.1), name, arguments.join(", ")).expect("Failed to write");
+		}
+		writeln!(output, "\t}}").expect("Failed to write");
+
+		// The layer's entry points, vkCreateInstance and vkCreateDevice also set up the dispatch tables and are in the framework
+		write!(output, "\n\tmod intercept {{\n\t\tuse super::*;\n").expect("Failed to write");
+		for &(ref name, level, ref exts) in layer_commands.iter().filter(|c| !["vkGetInstanceProcAddr", "vkGetDeviceProcAddr", "vkCreateInstance", "vkCreateDevice"].contains(&c.0.as_ref())) {
+			let cmd = &commands[name];
+			let params = &command_params[name];
+			let arguments: Vec<&str> = params.iter().map(|p| p.name.as_ref()).collect();
+			let (dispatch, tables) = if level == DispatchLevel::Instance { ("instance_dispatch", "INSTANCES") } else { ("device_dispatch", "DEVICES") };
+			write!(output, "\n{}\t\tpub unsafe extern \"C\" fn {}<L: Layer>({}){} {{\n", extensions_cfg(exts), name, cmd.0, returns(&cmd.1)).expect("Failed to write");
+			if name == "vkDestroyInstance" || name == "vkDestroyDevice" {
+				write!(output, "\t\t\tlet key = dispatch_key({});\n\t\t\tL::get().{}(&{}({}), {});\n\t\t\t{}.write().unwrap().remove(&key);\n", params[0].name, name, dispatch, params[0].name, arguments.join(", "), tables).expect("Failed to write");
+			} else {
+				writeln!(output, "\t\t\tL::get().{}(&{}({}), {})", name, dispatch, params[0].name, arguments.join(", ")).expect("Failed to write");
+			}
+			writeln!(output, "\t\t}}").expect("Failed to write");
+		}
+		writeln!(output, "\t}}").expect("Failed to write");
+
+		write!(output, "\n\t// The layer's entry point for a command, or null for commands it does not intercept\n\tfn intercept_address<L: Layer>(name: &[u8]) -> PFN_vkVoidFunction {{\n\t\tmatch name {{\n").expect("Failed to write");
+		write!(output, "\t\t\tb\"vkGetInstanceProcAddr\" => get_instance_proc_addr::<L> as PFN_vkVoidFunction,\n\t\t\tb\"vkGetDeviceProcAddr\" => get_device_proc_addr::<L> as PFN_vkVoidFunction,\n").expect("Failed to write");
+		write!(output, "\t\t\tb\"vkCreateInstance\" => create_instance::<L> as PFN_vkVoidFunction,\n\t\t\tb\"vkCreateDevice\" => create_device::<L> as PFN_vkVoidFunction,\n").expect("Failed to write");
+		for (name, _, exts) in layer_commands.iter().filter(|c| !["vkGetInstanceProcAddr", "vkGetDeviceProcAddr", "vkCreateInstance", "vkCreateDevice"].contains(&c.0.as_ref())) {
+			let names: Vec<String> = command_names(name).iter().map(|n| format!("b\"{}\"", n)).collect();
+			writeln!(output, "{}\t\t\t{} => intercept::{}::<L> as PFN_vkVoidFunction,", extensions_cfg(exts), names.join(" | "), name).expect("Failed to write");
+		}
+		write!(output, "\t\t\t_ => ptr::null()\n\t\t}}\n\t}}\n}}\n").expect("Failed to write");
+
+		// Print the driver framework
+		write!(output, "\n// Framework for writing drivers (ICDs), see vkmock for an example\n#[cfg(feature = \"icd\")]\n#[allow(unused_variables, dead_code)]\npub mod icd {{\n\tuse super::*;\n{}", fluff_icd).expect("Failed to write");
+
+		write!(output, "\n\t// VkResult from its name, i.e. for scripted results in a driver's configuration\n\tpub fn result_from_name(name: &str) -> Option<VkResult> {{\n\t\tmatch name {{\n").expect("Failed to write");
+		for e in enums.iter().filter(|e| e.name == "VkResult") {
+			for v in &e.values {
+				writeln!(output, "\t\t\t\"{}\" => Some(VkResult::{}),", v.0, v.0).expect("Failed to write");
+			}
+		}
+		for i in enum_extensions.get("VkResult").unwrap_or(&vec![]) {
+			let ext = &extensions[*i];
+			for ext_enum in &ext.types {
+				if let ExtensionNewType::EnumExtension { ref name, ref extends, .. } = *ext_enum {
+					if extends == "VkResult" {
+						write!(output, "#[cfg(feature = \"{}\")]\n\t\t\t\"{}\" => Some(VkResult::{}),\n", ext.name, name, name).expect("Failed to write");
+					}
+				}
+			}
+		}
+		write!(output, "\t\t\t_ => None\n\t\t}}\n\t}}\n").expect("Failed to write");
+
+		// The driver's entry points, the commands without their own implementation in the framework go by their parameters:
+		// handles they output are new fake ones, dispatchable ones passed to vkDestroy*/vkFree* are freed, two-call
+		// enumerations report ENUMERATED_HANDLE_COUNT handles or no values and everything else they output is left alone
+		write!(output, "\n\tmod commands {{\n\t\tuse super::*;\n").expect("Failed to write");
+		for (name, exts) in &command_extensions {
+
+			let cmd = &commands[name];
+			let params = &command_params[name];
+			let siblings: Vec<(String, usize)> = params.iter().map(|p| (p.name.clone(), if p.ptr_ptr { 2 } else if p.ptr { 1 } else { 0 })).collect();
+			let arguments: Vec<&str> = params.iter().map(|p| p.name.as_ref()).collect();
+
+			write!(output, "\n{}\t\tpub unsafe extern \"C\" fn {}<D: Driver>({}){} {{\n", extensions_cfg(exts), name, cmd.0, returns(&cmd.1)).expect("Failed to write");
+			if cmd.1 == "VkResult" {
+				write!(output, "\t\t\tlet scripted = D::get().result(\"{}\");\n\t\t\tif let Some(result) = scripted.filter(|result| {} < 0) {{\n\t\t\t\treturn result;\n\t\t\t}}\n", name, if newtype_enums { "result.0" } else { "(*result as i32)" }).expect("Failed to write");
+			}
+
+			let mut result = match cmd.1.as_ref() {
+				"VkResult" => "VkResult::VK_SUCCESS".to_string(),
+				"VkBool32" => "VK_TRUE".to_string(),
+				"()" => String::new(),
+				_ => "::core::mem::zeroed()".to_string()
+			};
+
+			if ICD_COMMANDS.contains(&name.as_ref()) {
+				let call = format!("{}::<D>({})", to_snake_case(&name[2..]), arguments.join(", "));
+				if cmd.1 == "()" {
+					writeln!(output, "\t\t\t{};", call).expect("Failed to write");
+				} else {
+					result = call;
+				}
+			} else {
+
+				// (uint32_t* pCount, T* pValues) with len="pCount"
+				let enumeration = params.len() >= 2 && {
+					let count = &params[params.len() - 2];
+					let values = &params[params.len() - 1];
+					count.ptr && !count.ptr_ptr && !count.is_const && count.type_name == "u32" &&
+						values.ptr && !values.ptr_ptr && !values.is_const && values.len == count.name && values.type_name != "c_void"
+				};
+				let outputs = if enumeration { &params[..params.len() - 2] } else { &params[..] };
+
+				let parent = match params.first() {
+					Some(p) if DISPATCHABLE_HANDLES.contains(&p.type_name.as_ref()) && !p.ptr => p.name.clone(),
+					_ => "VK_NULL_HANDLE".to_string()
+				};
+				let freed = if name.starts_with("vkDestroy") || name.starts_with("vkFree") {
+					format!("Vk{}", name.trim_start_matches("vkDestroy").trim_start_matches("vkFree").trim_end_matches('s'))
+				} else {
+					String::new()
+				};
+
+				for (i, p) in outputs.iter().enumerate() {
+					let dispatchable = DISPATCHABLE_HANDLES.contains(&p.type_name.as_ref());
+					let count = if p.len.is_empty() { None } else { len_count(&p.len, "", &siblings) };
+
+					if p.ptr && !p.ptr_ptr && !p.is_const && handle_types.contains(&p.type_name) {
+						let value = if dispatchable { format!("new_child({})", parent) } else { "new_handle()".to_string() };
+						match count {
+							Some(count) => write!(output, "\t\t\tfor i in 0..{} {{\n\t\t\t\t*{}.add(i) = {};\n\t\t\t}}\n", count, p.name, value).expect("Failed to write"),
+							None => write!(output, "\t\t\tif !{}.is_null() {{\n\t\t\t\t*{} = {};\n\t\t\t}}\n", p.name, p.name, value).expect("Failed to write")
+						}
+					} else if p.ptr && !p.ptr_ptr && !p.is_const && p.type_name == "VkBool32" && p.len.is_empty() {
+						write!(output, "\t\t\tif !{}.is_null() {{\n\t\t\t\t*{} = VK_TRUE;\n\t\t\t}}\n", p.name, p.name).expect("Failed to write");
+					} else if p.type_name == freed && dispatchable {
+						let owner = if i == 0 { "VK_NULL_HANDLE" } else { &parent };
+						if !p.ptr {
+							writeln!(output, "\t\t\tfree_dispatchable({}, {});", owner, p.name).expect("Failed to write");
+						} else if let Some(count) = count {
+							write!(output, "\t\t\tfor i in 0..{} {{\n\t\t\t\tfree_dispatchable({}, *{}.add(i));\n\t\t\t}}\n", count, owner, p.name).expect("Failed to write");
+						}
+					}
+				}
+
+				if enumeration {
+					let count = &params[params.len() - 2];
+					let values = &params[params.len() - 1];
+					let enumerated = if handle_types.contains(&values.type_name) && !DISPATCHABLE_HANDLES.contains(&values.type_name.as_ref()) {
+						let owner = outputs.iter().rev().find(|p| !p.ptr && handle_types.contains(&p.type_name)).map(|p| p.name.as_ref()).unwrap_or("VK_NULL_HANDLE");
+						format!("enumerate(&enumerated_handles(\"{}\", {}), {}, {})", name, owner, count.name, values.name)
+					} else {
+						format!("enumerate::<{}>(&[], {}, {})", values.type_name, count.name, values.name)
+					};
+					if cmd.1 == "VkResult" {
+						result = enumerated;
+					} else {
+						writeln!(output, "\t\t\t{};", enumerated).expect("Failed to write");
+					}
+				}
+			}
+
+			// The command does what it usually does, then a scripted success (i.e. VK_SUBOPTIMAL_KHR) replaces its result
+			if cmd.1 == "VkResult" {
+				write!(output, "\t\t\tlet result = {};\n\t\t\tscripted.unwrap_or(result)\n\t\t}}\n", result).expect("Failed to write");
+			} else if cmd.1 == "()" {
+				writeln!(output, "\t\t}}").expect("Failed to write");
+			} else {
+				write!(output, "\t\t\t{}\n\t\t}}\n", result).expect("Failed to write");
+			}
+		}
+		writeln!(output, "\t}}").expect("Failed to write");
+
+		write!(output, "\n\t// The driver's entry point for a command, or null for commands it does not have\n\tfn command_address<D: Driver>(name: &[u8]) -> PFN_vkVoidFunction {{\n\t\tmatch name {{\n").expect("Failed to write");
+		for (name, exts) in &command_extensions {
+			let names: Vec<String> = command_names(name).iter().map(|n| format!("b\"{}\"", n)).collect();
+			writeln!(output, "{}\t\t\t{} => commands::{}::<D> as PFN_vkVoidFunction,", extensions_cfg(exts), names.join(" | "), name).expect("Failed to write");
+		}
+		write!(output, "\t\t\t_ => ptr::null()\n\t\t}}\n\t}}\n}}\n").expect("Failed to write");
+
+		// Print the call tracing wrappers
+		if traced {
+
+			let trace_types = TraceTypes {
+				layout_types: &layout_types,
+				type_layouts: &type_layouts,
+				handle_types: &handle_types,
+				structs: structs.iter().map(|s| s.0.clone()).collect()
+			};
+
+			write!(output, "\n// Wrappers for every command that log the call, see TraceSink\n#[cfg(feature = \"traced\")]\npub mod traced {{\n\tuse super::*;\n{}", fluff_traced).expect("Failed to write");
+
+			for s in &structs {
+
+				let layout_type = &layout_types[&s.0];
+				let names: Vec<String> = type_providers(&s.0);
+				let siblings: Vec<(String, usize)> = layout_type.members.iter().map(|m| (m.name.clone(), if m.ptr_ptr { 2 } else if m.ptr { 1 } else { 0 })).collect();
+
+				write!(output, "\n{}\tpub unsafe fn trace_{}(out: &mut String, value: &{}) {{\n\t\tout.push_str(\"{} {{ \");\n", extensions_cfg(&names), s.0, s.0, s.0).expect("Failed to write");
+				for (i, m) in layout_type.members.iter().enumerate() {
+					let field = TraceField {
+						name: &m.name,
+						type_name: &m.type_name,
+						depth: siblings[i].1,
+						is_const: true,
+						array_sizes: &m.array_sizes,
+						char_array: m.char_array,
+						len: &m.len,
+						valid: !m.noautovalidity
+					};
+					let statement = trace_types.statement(&format!("value.{}", m.name), &field, "value.", &siblings);
+					write!(output, "\t\tout.push_str(\"{}{}: \");\n\t\t{};\n", if i > 0 { ", " } else { "" }, m.name, statement).expect("Failed to write");
+				}
+				write!(output, "\t\tout.push_str(\" }}\");\n\t}}\n").expect("Failed to write");
+			}
+
+			write!(output, "\n\t// Formats a pNext chain, the structs in it are found by chain\n\tpub unsafe fn trace_chain(out: &mut String, pNext: *const c_void) {{\n\t\tlet next = match chain(pNext).next() {{\n\t\t\tSome(next) => next,\n\t\t\tNone => {{\n\t\t\t\tout.push_str(\"null\");\n\t\t\t\treturn;\n\t\t\t}}\n\t\t}};\n\t\tout.push('&');\n\t\tmatch next {{\n").expect("Failed to write");
+			for s in &chain_structs {
+				writeln!(output, "{}\t\t\tChainStruct::{}(value) => trace_{}(out, value),", extensions_cfg(&type_providers(&s.0)), s.0, s.0).expect("Failed to write");
+			}
+			write!(output, "\t\t\tChainStruct::Unknown(sType) => {{\n\t\t\t\tlet _ = write!(out, \"{{{{ sType: {{}}, .. }}}}\", sType);\n\t\t\t}}\n\t\t}}\n\t}}\n").expect("Failed to write");
+
+			// Parameters that are written by the command (non-const pointers) are formatted after the call, the rest before it
+			for (name, exts) in &command_extensions {
+
+				let cmd = &commands[name];
+				let params = &command_params[name];
+				let siblings: Vec<(String, usize)> = params.iter().map(|p| (p.name.clone(), if p.ptr_ptr { 2 } else if p.ptr { 1 } else { 0 })).collect();
+				let arguments: Vec<&str> = params.iter().map(|p| p.name.as_ref()).collect();
+
+				if exts.is_empty() {
+					write!(output, "\n\tpub unsafe fn {}({}){} {{\n", name, cmd.0, returns(&cmd.1)).expect("Failed to write");
+				} else {
+					write!(output, "\n{}\tpub unsafe fn {}(functions: &{}, {}){} {{\n", extensions_cfg(exts), name, profile.function_pointers, cmd.0, returns(&cmd.1)).expect("Failed to write");
+				}
+
+				// Parameters the command writes to are only formatted if it succeeded
+				let written = if cmd.1 != "VkResult" { "" } else if newtype_enums { "if trace_result.0 >= 0" } else { "if trace_result as i32 >= 0" };
+				let mut before = String::new();
+				let mut after = String::new();
+				for (i, p) in params.iter().enumerate() {
+					let array_sizes = if p.array_size.is_empty() { vec![] } else { vec![p.array_size.clone()] };
+					let field = TraceField {
+						name: &p.name,
+						type_name: &p.type_name,
+						depth: siblings[i].1,
+						is_const: p.is_const,
+						array_sizes: &array_sizes,
+						char_array: false,
+						len: &p.len,
+						valid: true
+					};
+					let statement = trace_types.statement(&p.name, &field, "", &siblings);
+					before.write_fmt(format_args!("\t\tlet mut trace_{} = String::new();\n", p.name)).expect("Could not format string");
+					if siblings[i].1 > 0 && !p.is_const {
+						after.write_fmt(format_args!("\t\t{}{{\n\t\t\tlet out = &mut trace_{};\n\t\t\t{};\n\t\t}}{}\n", if written.is_empty() { String::new() } else { format!("{} ", written) }, p.name, statement, if written.is_empty() { String::new() } else { format!(" else {{\n\t\t\ttrace_{}.push('?');\n\t\t}}", p.name) })).expect("Could not format string");
+					} else {
+						before.write_fmt(format_args!("\t\t{{\n\t\t\tlet out = &mut trace_{};\n\t\t\t{};\n\t\t}}\n", p.name, statement)).expect("Could not format string");
+					}
+				}
+
+				let call = if exts.is_empty() { format!("super::{}", name) } else { format!("functions.{}.expect(\"{} is not loaded\")", profile.function_pointer_name(name), name) };
+				let logged: Vec<String> = params.iter().map(|p| format!("{}: {{}}", p.name)).collect();
+				let logged_values: Vec<String> = params.iter().map(|p| format!("trace_{}", p.name)).collect();
+				let (binding, result, returned) = if cmd.1 == "()" { ("", "\"void\"", "") } else { ("let trace_result = ", "&format!(\"{:?}\", trace_result)", "\t\ttrace_result\n") };
+
+				write!(output, "{}\t\tlet trace_start = Instant::now();\n\t\t{}{}({});\n\t\tlet trace_duration = trace_start.elapsed();\n{}", before, binding, call, arguments.join(", "), after).expect("Failed to write");
+				write!(output, "\t\tlog(\"{}\", &format!(\"{}\"{}{}), {}, trace_duration);\n{}\t}}\n", name, logged.join(", "), if logged_values.is_empty() { "" } else { ", " }, logged_values.join(", "), result, returned).expect("Failed to write");
+			}
+
+			writeln!(output, "}}").expect("Failed to write");
+		}
+
+		// Print the implicit valid usage checks
+		if validate {
+
+			let validate_types = ValidateTypes {
+				layout_types: &layout_types,
+				handle_types: &handle_types,
+				enums: enums.iter().map(|e| e.name.clone()).collect(),
+				structs: structs.iter().filter(|s| layout_types.get(&s.0).map(|t| !t.union).unwrap_or(false)).map(|s| s.0.clone()).collect(),
+				type_prefix: profile.type_prefix,
+				constant_prefix: profile.constant_prefix,
+				newtype_enums,
+				chain_structs: chain_structs.iter().filter(|_| chains).map(|s| (s.0.clone(), extensions_cfg(&type_providers(&s.0)))).collect()
+			};
+
+			write!(output, "\n// Wrappers for every command that check the implicit valid usage from the registry before calling it, see ValidationError\n#[cfg(feature = \"validate\")]\npub mod validate {{\n\tuse super::*;\n{}", fluff_validate.replace("VkStructureType", &format!("{}StructureType", profile.type_prefix))).expect("Failed to write");
+
+			// The values of each Rust enum, as validate reads them
+			for e in enums.iter().filter(|_| !newtype_enums) {
+				write!(output, "\n\t#[allow(non_snake_case, dead_code)]\n\tfn known_{}(value: i32) -> bool {{\n\t\tmatch value {{\n", e.name).expect("Failed to write");
+				for v in &enum_values(e) {
+					writeln!(output, "{}\t\t\t{} => true,", v.2, v.1).expect("Failed to write");
+				}
+				write!(output, "\t\t\t_ => false\n\t\t}}\n\t}}\n").expect("Failed to write");
+			}
+
+			// A check for each struct, path is the path to the struct (with -> or . after it)
+			for s in structs.iter().filter(|s| validate_types.structs.contains(&s.0)) {
+
+				let layout_type = &layout_types[&s.0];
+				let fields: Vec<ValidateField> = layout_type.members.iter().map(|m| ValidateField {
+					name: &m.name,
+					type_name: &m.type_name,
+					depth: if m.ptr_ptr { 2 } else if m.ptr { 1 } else { 0 },
+					is_const: m.is_const,
+					array: !m.array_sizes.is_empty() || m.bitfield,
+					len: &m.len,
+					optional: &m.optional,
+					noautovalidity: m.noautovalidity
+				}).collect();
+				let checks = validate_types.checks(&fields, "value.", "path", &layout_type.structure_type);
+				let unused = if checks.is_empty() { "_" } else { "" };
+
+				write!(output, "\n{}\tpub unsafe fn check_{}({}value: &{}, {}command: &'static str, {}path: &str) -> Result<(), ValidationError> {{\n", extensions_cfg(&type_providers(&s.0)), s.0, unused, s.0, unused, unused).expect("Failed to write");
+				for check in &checks {
+					writeln!(output, "\t\t{}", check).expect("Failed to write");
+				}
+				write!(output, "\t\tOk(())\n\t}}\n").expect("Failed to write");
+			}
+
+			for (name, exts) in &command_extensions {
+
+				let cmd = &commands[name];
+				let params = &command_params[name];
+				let fields: Vec<ValidateField> = params.iter().map(|p| ValidateField {
+					name: &p.name,
+					type_name: &p.type_name,
+					depth: if p.ptr_ptr { 2 } else if p.ptr { 1 } else { 0 },
+					is_const: p.is_const,
+					array: !p.array_size.is_empty(),
+					len: &p.len,
+					optional: &p.optional,
+					noautovalidity: p.noautovalidity
+				}).collect();
+				let checks = validate_types.checks(&fields, "", "\"\"", &String::new());
+				let arguments: Vec<&str> = params.iter().map(|p| p.name.as_ref()).collect();
+
+				if exts.is_empty() {
+					write!(output, "\n\tpub unsafe fn {}({}) -> Result<{}, ValidationError> {{\n", name, cmd.0, cmd.1).expect("Failed to write");
+				} else {
+					write!(output, "\n{}\tpub unsafe fn {}(functions: &{}, {}) -> Result<{}, ValidationError> {{\n", extensions_cfg(exts), name, profile.function_pointers, cmd.0, cmd.1).expect("Failed to write");
+				}
+				if !checks.is_empty() {
+					writeln!(output, "\t\tlet command = \"{}\";", name).expect("Failed to write");
+				}
+				for check in &checks {
+					writeln!(output, "\t\t{}", check).expect("Failed to write");
+				}
+				let call = if exts.is_empty() { format!("super::{}", name) } else { format!("functions.{}.expect(\"{} is not loaded\")", profile.function_pointer_name(name), name) };
+				if cmd.1 == "()" {
+					write!(output, "\t\t{}({});\n\t\tOk(())\n\t}}\n", call, arguments.join(", ")).expect("Failed to write");
+				} else {
+					write!(output, "\t\tOk({}({}))\n\t}}\n", call, arguments.join(", ")).expect("Failed to write");
+				}
+			}
+
+			writeln!(output, "}}").expect("Failed to write");
+		}
+
+		// Print the serde impls
+		if serde {
+
+			let serde_types = SerdeTypes {
+				type_layouts: &type_layouts,
+				structs: structs.iter().filter(|s| layout_types.get(&s.0).map(|t| !t.union).unwrap_or(false)).map(|s| s.0.clone()).collect()
+			};
+
+			write!(output, "\n// Serialize and Deserialize for the structs, enums and bitflags\n#[cfg(feature = \"serde\")]\nmod serde_impls {{\n\tuse super::*;\n{}", fluff_serde).expect("Failed to write");
+			if newtype_enums {
+				write!(output, "{}", fluff_serde_newtype).expect("Failed to write");
+			}
+
+			// Enums are written by name, with --newtype-enums values vkraw does not know as numbers
+			for e in &enums {
+				if newtype_enums {
+					write!(output, "\n\timpl Serialize for {} {{\n\t\tfn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{\n\t\t\tmatch self.name() {{\n\t\t\t\tSome(name) => serializer.serialize_str(name),\n\t\t\t\tNone => serializer.serialize_i32(self.0)\n\t\t\t}}\n\t\t}}\n\t}}\n", e.name).expect("Failed to write");
+					write!(output, "\n\timpl<'de> Deserialize<'de> for {} {{\n\t\tfn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{\n\t\t\tlet name = match EnumValue::deserialize(deserializer)? {{\n\t\t\t\tEnumValue::Name(name) => name,\n\t\t\t\tEnumValue::Number(value) => return Ok({}(value))\n\t\t\t}};\n\t\t\tmatch name.as_str() {{\n", e.name, e.name).expect("Failed to write");
+				} else {
+					write!(output, "\n\timpl Serialize for {} {{\n\t\tfn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{\n\t\t\tserializer.collect_str(&format_args!(\"{{:?}}\", self))\n\t\t}}\n\t}}\n", e.name).expect("Failed to write");
+					write!(output, "\n\timpl<'de> Deserialize<'de> for {} {{\n\t\tfn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{\n\t\t\tlet name = String::deserialize(deserializer)?;\n\t\t\tmatch name.as_str() {{\n", e.name).expect("Failed to write");
+				}
+				for v in &enum_values(e) {
+					writeln!(output, "{}\t\t\t\t\"{}\" => Ok({}::{}),", v.2, v.0, e.name, v.0).expect("Failed to write");
+				}
+				write!(output, "\t\t\t\t_ => Err(D::Error::custom(format_args!(\"unknown {} value {{}}\", name)))\n\t\t\t}}\n\t\t}}\n\t}}\n", e.name).expect("Failed to write");
+			}
+
+			// Bitflags are written as their bits, bits vkraw does not know are kept
+			for b in &bitflags {
+				write!(output, "\n\timpl Serialize for {} {{\n\t\tfn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{\n\t\t\tserializer.serialize_u{}(self.bits())\n\t\t}}\n\t}}\n", b.name, profile.flags_bits).expect("Failed to write");
+				write!(output, "\n\timpl<'de> Deserialize<'de> for {} {{\n\t\tfn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{\n\t\t\tOk(unsafe {{ {}::from_bits_unchecked(u{}::deserialize(deserializer)?) }})\n\t\t}}\n\t}}\n", b.name, b.name, profile.flags_bits).expect("Failed to write");
+			}
+
+			// Structs are written member by member, pointers are followed when serializing and null after deserializing
+			for s in structs.iter().filter(|s| serde_types.structs.contains(&s.0)) {
+
+				let layout_type = &layout_types[&s.0];
+				let cfg = extensions_cfg(&type_providers(&s.0));
+				let siblings: Vec<(String, usize)> = layout_type.members.iter().map(|m| (m.name.clone(), if m.ptr_ptr { 2 } else if m.ptr { 1 } else { 0 })).collect();
+				let serialized: Vec<(&String, String)> = layout_type.members.iter().filter_map(|m| serde_types.serialize(m, &siblings).map(|e| (&m.name, e))).collect();
+
+				write!(output, "\n{}\timpl Serialize for {} {{\n\t\tfn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{\n\t\t\tlet {}state = serializer.serialize_struct(\"{}\", {})?;\n", cfg, s.0, if serialized.is_empty() { "" } else { "mut " }, s.0, serialized.len()).expect("Failed to write");
+				for (name, expr) in &serialized {
+					writeln!(output, "\t\t\tstate.serialize_field(\"{}\", {})?;", name, expr).expect("Failed to write");
+				}
+				write!(output, "\t\t\tstate.end()\n\t\t}}\n\t}}\n").expect("Failed to write");
+
+				let deserialized: Vec<(&String, String)> = layout_type.members.iter().filter_map(|m| serde_types.deserialize(m).map(|e| (&m.name, e))).collect();
+				let names: Vec<String> = deserialized.iter().map(|d| format!("\"{}\"", d.0)).collect();
+
+				write!(output, "\n{}\timpl<'de> Deserialize<'de> for {} {{\n\t\tfn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{\n\t\t\tstruct Members;\n\t\t\timpl<'de> Visitor<'de> for Members {{\n\t\t\t\ttype Value = {};\n", cfg, s.0, s.0).expect("Failed to write");
+				write!(output, "\t\t\t\tfn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {{\n\t\t\t\t\tf.write_str(\"struct {}\")\n\t\t\t\t}}\n\t\t\t\tfn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<{}, A::Error> {{\n", s.0, s.0).expect("Failed to write");
+				for (name, _) in &deserialized {
+					writeln!(output, "\t\t\t\t\tlet mut member_{} = None;", name).expect("Failed to write");
+				}
+				write!(output, "\t\t\t\t\twhile let Some(key) = map.next_key::<String>()? {{\n\t\t\t\t\t\tmatch key.as_str() {{\n").expect("Failed to write");
+				for (name, expr) in &deserialized {
+					writeln!(output, "\t\t\t\t\t\t\t\"{}\" => member_{} = Some({}),", name, name, expr).expect("Failed to write");
+				}
+				write!(output, "\t\t\t\t\t\t\t_ => {{\n\t\t\t\t\t\t\t\tmap.next_value::<IgnoredAny>()?;\n\t\t\t\t\t\t\t}}\n\t\t\t\t\t\t}}\n\t\t\t\t\t}}\n\t\t\t\t\tOk({} {{\n", s.0).expect("Failed to write");
+
+				// sType may be left out, it is the struct's
+				let values: Vec<String> = layout_type.members.iter().map(|m| {
+					if m.name == "sType" && !layout_type.structure_type.is_empty() {
+						format!("member_sType.unwrap_or({}StructureType::{})", profile.type_prefix, layout_type.structure_type)
+					} else if deserialized.iter().any(|d| *d.0 == m.name) {
+						format!("member_{0}.ok_or_else(|| A::Error::missing_field(\"{0}\"))?", m.name)
+					} else if m.ptr && m.is_const {
+						"::core::ptr::null()".to_string()
+					} else if m.ptr {
+						"::core::ptr::null_mut()".to_string()
+					} else {
+						"unsafe { ::core::mem::zeroed() }".to_string()
+					}
+				}).collect();
+				for (i, m) in layout_type.members.iter().enumerate() {
+					writeln!(output, "\t\t\t\t\t\t{}: {}{}", m.name, values[i], if i + 1 < values.len() { "," } else { "" }).expect("Failed to write");
+				}
+				write!(output, "\t\t\t\t\t}})\n\t\t\t\t}}\n\t\t\t}}\n\t\t\tdeserializer.deserialize_struct(\"{}\", &[{}], Members)\n\t\t}}\n\t}}\n", s.0, names.join(", ")).expect("Failed to write");
+			}
+
+			writeln!(output, "}}").expect("Failed to write");
+		}
 	}
 }
@@ -0,0 +1,188 @@
+
+// Merging of registry overlays (xml fragments with private types, enums, commands and extensions) into vk.xml
+
+use quick_xml::reader::Reader;
+use quick_xml::events::Event;
+use std::str;
+
+use std::collections::BTreeMap;
+
+use crate::check::line_column;
+
+// The parts of a registry that are merged
+const MERGED_ELEMENTS: [&str; 4] = ["types", "enums", "commands", "extensions"];
+
+// A child of <registry>: name, start of its start tag, end of its start tag, start of its end tag, end of its end tag
+struct Block {
+	name: String,
+	start: usize,
+	contents_start: usize,
+	contents_end: usize,
+	end: usize
+}
+
+// Top level blocks and the things defined in them (kind and name to position)
+struct Layout {
+	blocks: Vec<Block>,
+	definitions: BTreeMap<(&'static str, String), usize>
+}
+
+fn layout(contents: &str) -> Result<Layout, String> {
+
+	let mut reader = Reader::from_str(contents);
+	reader.trim_text(true);
+
+	let mut buf = Vec::new();
+
+	// Open elements, innermost last, with the position of their start tags and the end of them
+	let mut elements = Vec::<(String, usize, usize)>::new();
+
+	let mut blocks = Vec::<Block>::new();
+	let mut definitions = BTreeMap::<(&'static str, String), usize>::new();
+
+	loop {
+		let before = reader.buffer_position();
+		let event = reader.read_event(&mut buf);
+		let after = reader.buffer_position();
+		let empty = matches!(event, Ok(Event::Empty(_)));
+		let position = before + contents[before..].find('<').unwrap_or(0);
+
+		match event {
+			Ok(Event::Start(ref e)) | Ok(Event::Empty(ref e)) => {
+
+				let element = str::from_utf8(e.name()).unwrap_or("").to_string();
+				let path: Vec<&str> = elements.iter().map(|p| p.0.as_ref()).collect();
+
+				let mut name = String::new();
+				let mut number = String::new();
+				let mut is_definition = false;
+				for tmp in e.attributes().flatten() {
+					match tmp.key {
+						b"name" => name = str::from_utf8(tmp.value).unwrap_or("").to_string(),
+						b"number" => number = str::from_utf8(tmp.value).unwrap_or("").to_string(),
+						b"extends" | b"value" | b"bitpos" | b"offset" => is_definition = true,
+						_ => ()
+					}
+				}
+
+				let kind = match (path.as_slice(), element.as_ref()) {
+					(["registry", "types"], "type") => Some("type"),
+					(["registry"], "enums") => Some("enums"),
+					(["registry", "enums"], "enum") => Some("enum value"),
+					([.., "require"], "enum") if is_definition => Some("enum value"),
+					(["registry", "commands"], "command") => Some("command"),
+					(["registry", "extensions"], "extension") => Some("extension"),
+					_ => None
+				};
+				if let Some(kind) = kind {
+					if !name.is_empty() {
+						definitions.entry((kind, name)).or_insert(position);
+					}
+				}
+				if element == "extension" && !number.is_empty() {
+					definitions.entry(("extension number", number)).or_insert(position);
+				}
+
+				if empty {
+					if path.len() == 1 {
+						blocks.push(Block { name: element, start: position, contents_start: after, contents_end: after, end: after });
+					}
+				} else {
+					elements.push((element, position, after));
+				}
+			},
+			Ok(Event::Text(ref e)) => {
+
+				// <type>...<name>blah</name></type> and <command><proto>...<name>blah</name></proto></command>
+				let path: Vec<&str> = elements.iter().map(|p| p.0.as_ref()).collect();
+				let kind = match path.as_slice() {
+					["registry", "types", "type", "name"] => Some(("type", elements[2].1)),
+					["registry", "commands", "command", "proto", "name"] => Some(("command", elements[2].1)),
+					_ => None
+				};
+				if let Some((kind, position)) = kind {
+					let text = e.unescape_and_decode(&reader).unwrap_or_default();
+					definitions.entry((kind, text)).or_insert(position);
+				}
+			},
+			Ok(Event::End(_)) => {
+				if let Some((element, start, contents_start)) = elements.pop() {
+					if elements.len() == 1 {
+						blocks.push(Block { name: element, start, contents_start, contents_end: position, end: after });
+					}
+				}
+			},
+			Ok(Event::Eof) => break,
+			Err(e) => {
+				let (line, column) = line_column(contents, reader.buffer_position());
+				return Err(format!("{}:{}: xml error {:?}", line, column, e));
+			},
+			_ => ()
+		}
+
+		buf.clear();
+	}
+
+	Ok(Layout { blocks, definitions })
+}
+
+// Merges an overlay into a registry, or returns the conflicts as "line:column: message" (positions in the overlay)
+pub fn merge(registry: &str, overlay: &str) -> Result<String, Vec<String>> {
+
+	let registry_layout = layout(registry).map_err(|e| vec![format!("in the registry: {}", e)])?;
+	let overlay_layout = layout(overlay).map_err(|e| vec![e])?;
+
+	let mut conflicts = Vec::<(usize, String)>::new();
+	for (definition, position) in &overlay_layout.definitions {
+		if registry_layout.definitions.contains_key(definition) {
+			conflicts.push((*position, format!("{} {} is already defined", definition.0, definition.1)));
+		}
+	}
+
+	// Where the overlay's blocks go: the contents of <types>, <commands> and <extensions> are appended to the
+	// registry's, whole <enums> blocks go after the registry's last one
+	let mut insertions = Vec::<(usize, &str)>::new();
+	for block in &overlay_layout.blocks {
+
+		if !MERGED_ELEMENTS.contains(&block.name.as_ref()) {
+			if block.name != "comment" {
+				conflicts.push((block.start, format!("<{}> can not be merged, only {} are", block.name, MERGED_ELEMENTS.join(", "))));
+			}
+			continue;
+		}
+
+		match registry_layout.blocks.iter().rfind(|b| b.name == block.name) {
+			Some(target) => {
+				if block.name == "enums" {
+					insertions.push((target.end, &overlay[block.start..block.end]));
+				} else {
+					insertions.push((target.contents_end, &overlay[block.contents_start..block.contents_end]));
+				}
+			},
+			None => conflicts.push((block.start, format!("the registry has no <{}> to merge into", block.name)))
+		}
+	}
+
+	if !conflicts.is_empty() {
+		conflicts.sort_by_key(|c| c.0);
+		return Err(conflicts.iter().map(|c| {
+			let (line, column) = line_column(overlay, c.0);
+			format!("{}:{}: {}", line, column, c.1)
+		}).collect());
+	}
+
+	// Stable sort keeps the overlay's order for insertions at the same position
+	insertions.sort_by_key(|insertion| insertion.0);
+
+	let mut merged = String::with_capacity(registry.len() + overlay.len());
+	let mut copied = 0;
+	for (at, text) in insertions {
+		merged.push_str(&registry[copied..at]);
+		merged.push('\n');
+		merged.push_str(text);
+		copied = at;
+	}
+	merged.push_str(&registry[copied..]);
+
+	Ok(merged)
+}
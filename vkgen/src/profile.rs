@@ -0,0 +1,254 @@
+// Conventions of a registry that are not written in its xml, so the same generator can read vk.xml and OpenXR's xr.xml
+// (which share the registry schema) and produce vkraw and xrraw
+
+pub struct Profile {
+	// Name given to --profile
+	pub name: &'static str,
+
+	// Prefixes of the registry's types, commands and constants, i.e. "Vk", "vk" and "VK_"
+	pub type_prefix: &'static str,
+	pub command_prefix: &'static str,
+	pub constant_prefix: &'static str,
+
+	// Library the core commands are linked from
+	pub library: &'static str,
+
+	// Struct the extension commands are loaded into
+	pub function_pointers: &'static str,
+
+	// Whether the instance level GetInstanceProcAddr returns the command (Vulkan) or writes it to its last parameter (OpenXR)
+	pub get_proc_addr_returns: bool,
+
+	// Extension enum values are base + (extension number - 1) * block size + offset
+	// See https://github.com/KhronosGroup/Vulkan-LoaderAndValidationLayers/blob/master/scripts/generator.py
+	pub extension_base_number: u32,
+	pub extension_block_size: u32,
+
+	// Width of the bitmask types
+	pub flags_bits: u32,
+
+	// Types declared in types_fluff that structs are built from, with their size (and alignment) for the layout checks
+	pub base_types: &'static [(&'static str, usize)],
+
+	// API constants that are not usize
+	pub constant_types: &'static [(&'static str, &'static str)],
+
+	// Features whose <require> blocks inside extensions are skipped
+	// TODO: hack
+	pub skipped_features: &'static [&'static str],
+
+	// Version functions, after the crate attributes
+	pub version_fluff: &'static str,
+
+	// Base types, function pointer types and hand written impls, after the defines
+	pub types_fluff: &'static str,
+
+	// Whether the layer, driver and tracing frameworks are generated, they follow the Vulkan loader's interfaces
+	pub frameworks: bool
+}
+
+impl Profile {
+
+	// Member of the function pointers struct for a command, i.e. CreateSwapchainKHR for vkCreateSwapchainKHR
+	pub fn function_pointer_name(&self, command: &str) -> String {
+		command.trim_start_matches(self.command_prefix).to_string()
+	}
+
+	pub fn constant_type(&self, name: &str) -> String {
+		self.constant_types.iter().find(|c| c.0 == name).map(|c| c.1).unwrap_or("usize").to_string()
+	}
+}
+
+pub const VULKAN: Profile = Profile {
+	name: "vulkan",
+	type_prefix: "Vk",
+	command_prefix: "vk",
+	constant_prefix: "VK_",
+	library: "vulkan",
+	function_pointers: "VulkanFunctionPointers",
+	get_proc_addr_returns: true,
+	extension_base_number: 1000000000,
+	extension_block_size: 1000,
+	flags_bits: 32,
+	base_types: &[("VkBool32", 4), ("VkSampleMask", 4), ("VkDeviceSize", 8), ("VkDeviceAddress", 8), ("VkFlags", 4), ("VkFlags64", 8)],
+	constant_types: &[("VK_WHOLE_SIZE", "u64"), ("VK_LOD_CLAMP_NONE", "f32"), ("VK_TRUE", "u32"), ("VK_FALSE", "u32")],
+	skipped_features: &["VK_VERSION_1_1"],
+	version_fluff: r#"
+pub const VK_VERSION_1_0: u32 = 1;
+
+pub const fn VK_MAKE_VERSION(major: u32, minor: u32, patch: u32) -> u32 {
+	((major) << 22) | ((minor) << 12) | (patch)
+}
+
+pub const VK_API_VERSION_1_0: u32 = VK_MAKE_VERSION(1, 0, 0);
+
+pub const fn VK_VERSION_MAJOR(version: u32) -> u32 {
+	version >> 22
+}
+
+pub const fn VK_VERSION_MINOR(version: u32) -> u32 {
+	(version >> 22) & 0x3ff
+}
+
+pub const fn VK_VERSION_PATCH(version: u32) -> u32 {
+	version & 0x3ff
+}
+"#,
+	types_fluff: r#"
+
+#[allow(non_camel_case_types)]
+pub type VkDeviceSize = u64;
+#[allow(non_camel_case_types)]
+pub type VkSampleMask = u32;
+#[allow(non_camel_case_types)]
+pub type VkDeviceAddress = u64;
+#[allow(non_camel_case_types)]
+pub type VkFlags = u32;
+#[allow(non_camel_case_types)]
+pub type VkFlags64 = u64;
+
+#[allow(non_camel_case_types)]
+pub type PFN_vkAllocationFunction = *const c_void;
+#[allow(non_camel_case_types)]
+pub type PFN_vkReallocationFunction = *const c_void;
+#[allow(non_camel_case_types)]
+pub type PFN_vkFreeFunction = *const c_void;
+#[allow(non_camel_case_types)]
+pub type PFN_vkInternalAllocationNotification = *const c_void;
+#[allow(non_camel_case_types)]
+pub type PFN_vkInternalFreeNotification = *const c_void;
+#[allow(non_camel_case_types)]
+pub type PFN_vkDebugReportCallbackEXT = *const c_void;
+#[allow(non_camel_case_types)]
+pub type PFN_vkDebugUtilsMessengerCallbackEXT = *const c_void;
+#[allow(non_camel_case_types)]
+pub type PFN_vkVoidFunction = *const c_void;
+
+// Rust assumes bool is u8, vulkan assumes it is u32
+pub type VkBool32 = u32;
+
+// Unions of Copy types, so they are Copy themselves
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub union VkClearColorValue {
+	pub float32: [f32; 4],
+	pub int32: [i32; 4],
+	pub uint32: [u32; 4]
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub union VkClearValue {
+	pub colour: VkClearColorValue,
+	pub depthStencil: VkClearDepthStencilValue
+}
+
+impl core::fmt::Debug for VkClearColorValue {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		unsafe { write!(f, "VkClearColorValue {{float32: {:?}; or int32: {:?}; or uint32: {:?}}}", self.float32, self.int32, self.uint32) }
+	}
+}
+
+impl core::fmt::Debug for VkClearValue {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		unsafe { write!(f, "VkClearValue {{colour: {:?}; or depthStencil: {:?}}}", self.colour, self.depthStencil) }
+	}
+}
+
+// TODO
+// Cannot implement Debug for [u8; x > 32]
+impl core::fmt::Debug for VkPhysicalDeviceProperties {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "not implemented")
+	}
+}
+
+impl core::fmt::Debug for VkLayerProperties {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "not implemented")
+	}
+}
+
+impl core::fmt::Debug for VkExtensionProperties {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "not implemented")
+	}
+}
+
+impl core::fmt::Debug for VkPhysicalDeviceMemoryProperties {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "not implemented")
+	}
+}"#,
+	frameworks: true
+};
+
+pub const OPENXR: Profile = Profile {
+	name: "openxr",
+	type_prefix: "Xr",
+	command_prefix: "xr",
+	constant_prefix: "XR_",
+	library: "openxr_loader",
+	function_pointers: "OpenXrFunctionPointers",
+	get_proc_addr_returns: false,
+	extension_base_number: 1000000000,
+	extension_block_size: 1000,
+	flags_bits: 64,
+	base_types: &[("XrBool32", 4), ("XrFlags64", 8), ("XrTime", 8), ("XrDuration", 8), ("XrVersion", 8), ("XrSystemId", 8), ("XrPath", 8)],
+	constant_types: &[("XR_TRUE", "u32"), ("XR_FALSE", "u32"), ("XR_NO_DURATION", "i64"), ("XR_INFINITE_DURATION", "i64"), ("XR_MIN_HAPTIC_DURATION", "i64"), ("XR_FREQUENCY_UNSPECIFIED", "f32")],
+	skipped_features: &[],
+	version_fluff: r#"
+pub const XR_VERSION_1_0: u32 = 1;
+
+pub const fn XR_MAKE_VERSION(major: u64, minor: u64, patch: u64) -> u64 {
+	((major & 0xffff) << 48) | ((minor & 0xffff) << 32) | (patch & 0xffffffff)
+}
+
+pub const XR_API_VERSION_1_0: u64 = XR_MAKE_VERSION(1, 0, 0);
+
+pub const fn XR_VERSION_MAJOR(version: u64) -> u64 {
+	(version >> 48) & 0xffff
+}
+
+pub const fn XR_VERSION_MINOR(version: u64) -> u64 {
+	(version >> 32) & 0xffff
+}
+
+pub const fn XR_VERSION_PATCH(version: u64) -> u64 {
+	version & 0xffffffff
+}
+"#,
+	types_fluff: r#"
+
+// Rust assumes bool is u8, OpenXR assumes it is u32
+pub type XrBool32 = u32;
+pub type XrFlags64 = u64;
+pub type XrTime = i64;
+pub type XrDuration = i64;
+pub type XrVersion = u64;
+
+// Atoms
+pub type XrSystemId = u64;
+pub type XrPath = u64;
+
+#[allow(non_camel_case_types)]
+pub type PFN_xrVoidFunction = *const c_void;
+#[allow(non_camel_case_types)]
+pub type PFN_xrDebugUtilsMessengerCallbackEXT = *const c_void;
+
+// TODO
+// Cannot implement Debug for [u8; x > 32]
+impl core::fmt::Debug for XrApplicationInfo {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "not implemented")
+	}
+}
+"#,
+	frameworks: false
+};
+
+pub const PROFILES: [&Profile; 2] = [&VULKAN, &OPENXR];
+
+pub fn from_name(name: &str) -> Option<&'static Profile> {
+	PROFILES.iter().find(|p| p.name == name).cloned()
+}
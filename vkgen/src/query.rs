@@ -0,0 +1,265 @@
+// What the registry says about a name (vkgen query), to answer questions without grepping vk.xml
+// Works on the same model as --emit json, so overlays are merged and aliases resolved like they are for the bindings
+
+use crate::json::Value;
+
+fn strings(value: &Value) -> Vec<&str> {
+	value.as_array().iter().filter_map(|v| v.as_str()).collect()
+}
+
+fn name(value: &Value) -> &str {
+	value.get("name").as_str().unwrap_or("")
+}
+
+// How a member or parameter is declared in the bindings, with its len= and optional= attributes
+fn declaration(value: &Value) -> String {
+
+	let mut type_name = value.get("type").as_str().unwrap_or("").to_string();
+	for _ in 0..value.get("pointer_depth").as_number().unwrap_or(0) {
+		type_name = format!("{}{}", if let Value::Bool(true) = *value.get("const") { "*const " } else { "*mut " }, type_name);
+	}
+	let mut sizes: Vec<&str> = strings(value.get("array_sizes"));
+	sizes.extend(value.get("array_size").as_str());
+	for size in sizes.iter().rev() {
+		type_name = format!("[{}; {}]", type_name, size);
+	}
+
+	let mut attributes = vec![];
+	if let Some(len) = value.get("len").as_str() {
+		attributes.push(format!("len={}", len));
+	}
+	if let Some(optional) = value.get("optional").as_str() {
+		attributes.push(format!("optional={}", optional));
+	}
+	if attributes.is_empty() {
+		format!("{}: {}", name(value), type_name)
+	} else {
+		format!("{}: {} ({})", name(value), type_name, attributes.join(", "))
+	}
+}
+
+// The core versions and extensions that provide a type, command or enum
+// kind is the list of a feature it would be in: "types", "commands" or "enums"
+fn providers(registry: &Value, item: &Value, item_name: &str, kind: &str) -> Vec<String> {
+	let mut providers: Vec<String> = registry.get("features").as_array().iter()
+		.filter(|f| strings(f.get(kind)).contains(&item_name))
+		.map(|f| format!("{} ({})", f.get("feature").as_str().unwrap_or(""), f.get("comment").as_str().unwrap_or("")))
+		.collect();
+	for extension in strings(item.get("extensions")) {
+		if !providers.iter().any(|p| p == extension) {
+			providers.push(extension.to_string());
+		}
+	}
+	providers
+}
+
+// The commands with a parameter of one of the types, and the structs with a member of one
+fn users(registry: &Value, type_names: &[&str], lines: &mut Vec<String>) {
+
+	let commands: Vec<&str> = registry.get("commands").as_array().iter()
+		.filter(|c| c.get("params").as_array().iter().any(|p| type_names.contains(&p.get("type").as_str().unwrap_or(""))))
+		.map(name)
+		.collect();
+	let structs: Vec<&str> = registry.get("structs").as_array().iter()
+		.filter(|s| s.get("alias_of").as_str().is_none() && s.get("members").as_array().iter().any(|m| type_names.contains(&m.get("type").as_str().unwrap_or(""))))
+		.map(name)
+		.collect();
+
+	list(lines, "used by commands", &commands);
+	list(lines, "member of", &structs);
+}
+
+fn list<S: AsRef<str>>(lines: &mut Vec<String>, label: &str, values: &[S]) {
+	if !values.is_empty() {
+		let values: Vec<&str> = values.iter().map(|v| v.as_ref()).collect();
+		lines.push(format!("{}: {}", label, values.join(", ")));
+	}
+}
+
+fn query_constant(registry: &Value, constant: &Value, lines: &mut Vec<String>) {
+	match constant.get("alias_of").as_str() {
+		Some(alias_of) => lines.push(format!("constant {}, alias of {}", name(constant), alias_of)),
+		None => lines.push(format!("constant {} = {}", name(constant), crate::json::to_string(constant.get("value")).trim_end()))
+	}
+	let extensions: Vec<&str> = registry.get("extensions").as_array().iter().filter(|e| strings(e.get("constants")).contains(&name(constant))).map(name).collect();
+	let mut providers = providers(registry, &Value::Null, name(constant), "enums");
+	providers.extend(extensions.iter().map(|e| e.to_string()));
+	list(lines, "provided by", &providers);
+}
+
+fn query_handle(registry: &Value, handle: &Value, lines: &mut Vec<String>) {
+	lines.push(format!("handle {}", name(handle)));
+	if let Some(parent) = handle.get("parent").as_str() {
+		lines.push(format!("parent: {}", parent));
+	}
+	list(lines, "provided by", &providers(registry, handle, name(handle), "types"));
+	users(registry, &[name(handle)], lines);
+}
+
+fn query_bitmask_type(registry: &Value, bitmask_type: &Value, lines: &mut Vec<String>) {
+	lines.push(format!("bitmask type {}", name(bitmask_type)));
+	if let Some(bits) = bitmask_type.get("bits").as_str() {
+		lines.push(format!("bits: {}", bits));
+	}
+	list(lines, "provided by", &providers(registry, &Value::Null, name(bitmask_type), "types"));
+	users(registry, &[name(bitmask_type)], lines);
+}
+
+// Enums and bitmasks (the FlagBits types)
+fn query_enum(registry: &Value, kind: &str, e: &Value, lines: &mut Vec<String>) {
+	lines.push(format!("{} {}", kind, name(e)));
+	for value in e.get("values").as_array() {
+		let number = value.get("value").as_number().map(|v| format!(" = {}", v)).unwrap_or_default();
+		match (value.get("alias_of").as_str(), value.get("extension").as_str()) {
+			(Some(alias_of), _) => lines.push(format!("\t{}, alias of {}", name(value), alias_of)),
+			(None, Some(extension)) => lines.push(format!("\t{}{} ({})", name(value), number, extension)),
+			(None, None) => lines.push(format!("\t{}{}", name(value), number))
+		}
+	}
+	list(lines, "provided by", &providers(registry, &Value::Null, name(e), "types"));
+	users(registry, &[name(e)], lines);
+}
+
+fn query_enum_value(registry: &Value, kind: &str, e: &Value, value: &Value, lines: &mut Vec<String>) {
+	let number = value.get("value").as_number().map(|v| format!(" = {}", v)).unwrap_or_default();
+	lines.push(format!("{} value {}{} of {}", kind, name(value), number, name(e)));
+	if let Some(alias_of) = value.get("alias_of").as_str() {
+		lines.push(format!("alias of {}", alias_of));
+	}
+	match value.get("extension").as_str() {
+		Some(extension) => lines.push(format!("provided by: {}", extension)),
+		None => list(lines, "provided by", &providers(registry, &Value::Null, name(e), "types"))
+	}
+	let aliases: Vec<&str> = e.get("values").as_array().iter().filter(|v| v.get("alias_of").as_str() == Some(name(value))).map(name).collect();
+	list(lines, "aliases", &aliases);
+}
+
+fn query_struct(registry: &Value, s: &Value, lines: &mut Vec<String>) {
+
+	let structs = registry.get("structs").as_array();
+	let kind = if let Value::Bool(true) = *s.get("union") { "union" } else { "struct" };
+	lines.push(format!("{} {}", kind, name(s)));
+
+	// An alias has the members of the struct it stands for, and shares its relations
+	let target = s.get("alias_of").as_str().unwrap_or(name(s));
+	if target != name(s) {
+		lines.push(format!("alias of {}", target));
+	}
+	for member in s.get("members").as_array() {
+		lines.push(format!("\t{}", declaration(member)));
+	}
+	if let Some(structure_type) = s.get("structure_type").as_str() {
+		lines.push(format!("sType: {}", structure_type));
+	}
+	list(lines, "provided by", &providers(registry, s, name(s), "types"));
+
+	let mut names = vec![target];
+	names.extend(structs.iter().filter(|a| a.get("alias_of").as_str() == Some(target)).map(name));
+	let aliases: Vec<&str> = names.iter().cloned().filter(|n| *n != name(s)).collect();
+	list(lines, "aliases", &aliases);
+
+	list(lines, "extends", &strings(s.get("extends")));
+	let extended_by: Vec<&str> = structs.iter()
+		.filter(|e| e.get("alias_of").as_str().is_none() && strings(e.get("extends")).iter().any(|x| names.contains(x)))
+		.map(name)
+		.collect();
+	list(lines, "extended by", &extended_by);
+	users(registry, &names, lines);
+}
+
+fn query_command(registry: &Value, command: &Value, lines: &mut Vec<String>) {
+	let params: Vec<String> = command.get("params").as_array().iter().map(declaration).collect();
+	lines.push(format!("command {}", name(command)));
+	if let Some(alias_of) = command.get("alias_of").as_str() {
+		lines.push(format!("alias of {}", alias_of));
+	}
+	for param in &params {
+		lines.push(format!("\t{}", param));
+	}
+	lines.push(format!("returns: {}", command.get("return_type").as_str().unwrap_or("")));
+	list(lines, "provided by", &providers(registry, command, name(command), "commands"));
+
+	let target = command.get("alias_of").as_str().unwrap_or(name(command));
+	let aliases: Vec<&str> = registry.get("commands").as_array().iter()
+		.map(|c| (name(c), c.get("alias_of").as_str()))
+		.filter(|c| c.0 != name(command) && (c.0 == target || c.1 == Some(target)))
+		.map(|c| c.0)
+		.collect();
+	list(lines, "aliases", &aliases);
+}
+
+fn query_extension(extension: &Value, lines: &mut Vec<String>) {
+	lines.push(format!("extension {} (number {}, {})", name(extension), extension.get("number").as_number().unwrap_or(0), extension.get("type").as_str().unwrap_or("no type")));
+	for &(label, key) in &[("supported", "supported"), ("platform", "platform"), ("depends", "depends"), ("promoted to", "promoted_to"), ("deprecated by", "deprecated_by"), ("obsoleted by", "obsoleted_by")] {
+		if let Some(value) = extension.get(key).as_str() {
+			lines.push(format!("{}: {}", label, value));
+		}
+	}
+	list(lines, "requires", &strings(extension.get("requires")));
+	list(lines, "commands", &strings(extension.get("commands")));
+	list(lines, "types", &strings(extension.get("types")));
+	list(lines, "enums", &strings(extension.get("enums")));
+	list(lines, "constants", &strings(extension.get("constants")));
+}
+
+// Everything called name, each as a block of lines (a name can be several things, e.g. an extension and its name constant)
+pub fn query(registry: &Value, query_name: &str) -> Vec<Vec<String>> {
+
+	let mut blocks = vec![];
+	let named = |section: &str| -> Vec<&Value> {
+		registry.get(section).as_array().iter().filter(|v| name(v) == query_name).collect()
+	};
+
+	for constant in named("constants") {
+		let mut lines = vec![];
+		query_constant(registry, constant, &mut lines);
+		blocks.push(lines);
+	}
+	// Extension constants (the _SPEC_VERSION and _EXTENSION_NAME ones) are only listed by their extension
+	if named("constants").is_empty() {
+		let extensions: Vec<&str> = registry.get("extensions").as_array().iter().filter(|e| strings(e.get("constants")).contains(&query_name)).map(name).collect();
+		if !extensions.is_empty() {
+			blocks.push(vec![format!("constant {}", query_name), format!("provided by: {}", extensions.join(", "))]);
+		}
+	}
+	for handle in named("handles") {
+		let mut lines = vec![];
+		query_handle(registry, handle, &mut lines);
+		blocks.push(lines);
+	}
+	for bitmask_type in named("bitmask_types") {
+		let mut lines = vec![];
+		query_bitmask_type(registry, bitmask_type, &mut lines);
+		blocks.push(lines);
+	}
+	for &(section, kind) in &[("enums", "enum"), ("bitmasks", "bitmask")] {
+		for e in registry.get(section).as_array() {
+			if name(e) == query_name {
+				let mut lines = vec![];
+				query_enum(registry, kind, e, &mut lines);
+				blocks.push(lines);
+			}
+			for value in e.get("values").as_array().iter().filter(|v| name(v) == query_name) {
+				let mut lines = vec![];
+				query_enum_value(registry, kind, e, value, &mut lines);
+				blocks.push(lines);
+			}
+		}
+	}
+	for s in named("structs") {
+		let mut lines = vec![];
+		query_struct(registry, s, &mut lines);
+		blocks.push(lines);
+	}
+	for command in named("commands") {
+		let mut lines = vec![];
+		query_command(registry, command, &mut lines);
+		blocks.push(lines);
+	}
+	for extension in named("extensions") {
+		let mut lines = vec![];
+		query_extension(extension, &mut lines);
+		blocks.push(lines);
+	}
+	blocks
+}
@@ -0,0 +1,90 @@
+// Builds the bindings for the fixture registry with every feature and runs tests/fixtures/bindings_test.rs on them,
+// once as they are and once with --newtype-enums. Then builds them without std, with every feature that does not need it.
+// Needs the crates the bindings use, so cargo has to be able to fetch them
+
+#[cfg(test)]
+mod tests {
+
+	use std::path::Path;
+	use std::process::Command;
+
+	// Makes a crate of the bindings in dir, returns the features to build it with
+	fn generate_crate(dir: &Path, newtype_enums: bool) -> Vec<String> {
+
+		std::fs::create_dir_all(dir.join("src")).expect("Could not create the crate's directory");
+		std::fs::create_dir_all(dir.join("tests")).expect("Could not create the crate's directory");
+		let fixtures = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+		std::fs::copy(fixtures.join("bindings.toml"), dir.join("Cargo.toml")).expect("Could not copy Cargo.toml");
+		std::fs::copy(fixtures.join("bindings_build.rs"), dir.join("build.rs")).expect("Could not copy build.rs");
+		std::fs::copy(fixtures.join("bindings_test.rs"), dir.join("tests/bindings.rs")).expect("Could not copy the tests");
+
+		let mut command = Command::new(env!("CARGO_BIN_EXE_vkgen"));
+		command.current_dir(env!("CARGO_MANIFEST_DIR"))
+			.arg("tests/fixtures/vk.xml")
+			.arg("-o")
+			.arg(dir.join("src/lib.rs"))
+			.arg("--cargo-toml")
+			.arg(dir.join("Cargo.toml"))
+			.args(["--traced", "--validate", "--serde"]);
+		if newtype_enums {
+			command.arg("--newtype-enums");
+		}
+		let output = command.output().expect("Failed to run vkgen");
+		assert!(output.status.success(), "vkgen failed:\n{}", String::from_utf8_lossy(&output.stdout));
+
+		// Every feature, except the extensions for platforms whose crates the test does not have (i.e. xcb)
+		let cargo_toml = std::fs::read_to_string(dir.join("Cargo.toml")).expect("Could not read Cargo.toml");
+		let generated = &cargo_toml[cargo_toml.find("# BEGIN vkgen features").unwrap()..cargo_toml.find("# END vkgen features").unwrap()];
+		let mut features: Vec<String> = ["std", "layer", "traced", "validate", "serde", "icd"].iter().map(|f| f.to_string()).collect();
+		for line in generated.lines().skip(1).filter(|l| !l.starts_with('#')) {
+			let (name, requires) = line.split_once(" = ").expect("Not a feature");
+			if requires.trim_matches(&['[', ']'][..]).split(", ").filter(|r| !r.is_empty()).all(|r| r.trim_matches('"').starts_with("VK_")) {
+				features.push(name.to_string());
+			}
+		}
+		if newtype_enums {
+			features.push("newtype".to_string());
+		}
+		features
+	}
+
+	// Runs cargo on the crate in dir with the features, the crates share a target directory so the dependencies are only built once
+	fn cargo(root: &Path, dir: &Path, args: &[&str], features: &[String]) -> std::process::Output {
+
+		let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+		Command::new(cargo)
+			.current_dir(dir)
+			.env_remove("CARGO_TARGET_DIR")
+			.args(args)
+			.arg("--target-dir")
+			.arg(root.join("target"))
+			.arg("--no-default-features")
+			.arg("--features")
+			.arg(features.join(" "))
+			.output()
+			.expect("Failed to run cargo")
+	}
+
+	#[test]
+	fn bindings_test() {
+
+		let root = Path::new(env!("CARGO_TARGET_TMPDIR")).join("bindings");
+		for &(name, newtype_enums) in &[("enums", false), ("newtype_enums", true)] {
+
+			let dir = root.join(name);
+			let _ = std::fs::remove_dir_all(&dir);
+			let features = generate_crate(&dir, newtype_enums);
+
+			let output = cargo(&root, &dir, &["test"], &features);
+			assert!(output.status.success(), "The {} bindings failed their tests:\n{}\n{}", name, String::from_utf8_lossy(&output.stdout), String::from_utf8_lossy(&output.stderr));
+		}
+
+		// The tests need std, so without it the library is only built
+		let dir = root.join("no_std");
+		let _ = std::fs::remove_dir_all(&dir);
+		let std_features = ["std", "layer", "traced", "validate", "serde", "icd"];
+		let features: Vec<String> = generate_crate(&dir, false).into_iter().filter(|f| !std_features.contains(&f.as_str())).collect();
+		let output = cargo(&root, &dir, &["build", "--lib"], &features);
+		assert!(output.status.success(), "The bindings failed to build without std:\n{}\n{}", String::from_utf8_lossy(&output.stdout), String::from_utf8_lossy(&output.stderr));
+	}
+}
@@ -0,0 +1,50 @@
+
+#[cfg(test)]
+mod tests {
+
+	use std::process::Command;
+
+	fn check(xml_filename: &str) -> (bool, String) {
+
+		let output = Command::new(env!("CARGO_BIN_EXE_vkgen"))
+			.current_dir(env!("CARGO_MANIFEST_DIR"))
+			.arg("check")
+			.arg(xml_filename)
+			.output()
+			.expect("Failed to run vkgen");
+
+		(output.status.success(), String::from_utf8(output.stdout).expect("vkgen output is not utf8"))
+	}
+
+	#[test]
+	fn check_fixture_test() {
+
+		let (success, output) = check("tests/fixtures/vk.xml");
+		assert!(success, "Problems found in the golden test fixture:\n{}", output);
+	}
+
+	#[test]
+	fn check_problems_test() {
+
+		let (success, output) = check("tests/fixtures/vk_broken.xml");
+		assert!(!success);
+
+		let expected = [
+			"tests/fixtures/vk_broken.xml:13:21: unknown type VkFlagz (used in a member, parameter or return type)",
+			"tests/fixtures/vk_broken.xml:15:9: unknown struct VkDeviceCreateInfo (structextends of VkVendorFeaturesEXT)",
+			"tests/fixtures/vk_broken.xml:18:9: dangling type alias VkVendorFeatureEXT (alias of VkVendorFeaturesKHR)",
+			"tests/fixtures/vk_broken.xml:23:9: value 1 of VK_STRUCTURE_TYPE_VENDOR_INFO in VkStructureType collides with VK_STRUCTURE_TYPE_INSTANCE_CREATE_INFO",
+			"tests/fixtures/vk_broken.xml:24:9: VK_STRUCTURE_TYPE_TYPO in VkStructureType has no valid value or bitpos",
+			"tests/fixtures/vk_broken.xml:25:9: dangling enum alias VK_STRUCTURE_TYPE_APPLICATION_INF (alias of VK_STRUCTURE_TYPE_INFO)",
+			"tests/fixtures/vk_broken.xml:31:9: dangling command alias vkVendorCommandEXTT (alias of vkVendorCommandKHR)",
+			"tests/fixtures/vk_broken.xml:41:9: extension number 1000 of VK_EXT_vendor_two is already used by VK_EXT_vendor_one",
+			"tests/fixtures/vk_broken.xml:41:9: unknown extension or version VK_EXT_vendor_thre (dependency of VK_EXT_vendor_two)",
+			"tests/fixtures/vk_broken.xml:42:13: invalid depends of VK_EXT_vendor_two: expression ends early",
+			"tests/fixtures/vk_broken.xml:43:17: unknown enum VkStructureTyp (extended by VK_STRUCTURE_TYPE_VENDOR_TWO_EXT)",
+			"tests/fixtures/vk_broken.xml:48:9: unknown enum value VK_FORMAT_R8_UNROM (described in formats)",
+			"12 problems found"
+		];
+		let lines: Vec<&str> = output.lines().collect();
+		assert_eq!(lines, expected);
+	}
+}
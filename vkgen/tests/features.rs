@@ -0,0 +1,58 @@
+
+#[cfg(test)]
+mod tests {
+
+	use std::process::Command;
+
+	// Runs vkgen --cargo-toml on the fixture with an overlay, returns the lines it wrote between the markers
+	fn features(overlay_filename: &str, toml_filename: &str) -> Vec<String> {
+
+		let toml_path = format!("{}/{}", env!("CARGO_TARGET_TMPDIR"), toml_filename);
+		std::fs::write(&toml_path, "[features]\nstd = []\n# BEGIN vkgen features\n# END vkgen features\n").expect("Could not write Cargo.toml");
+
+		let output = Command::new(env!("CARGO_BIN_EXE_vkgen"))
+			.current_dir(env!("CARGO_MANIFEST_DIR"))
+			.arg("tests/fixtures/vk.xml")
+			.arg("--overlay")
+			.arg(overlay_filename)
+			.arg("-o")
+			.arg(format!("{}/{}.rs", env!("CARGO_TARGET_TMPDIR"), toml_filename))
+			.arg("--cargo-toml")
+			.arg(&toml_path)
+			.output()
+			.expect("Failed to run vkgen");
+		assert!(output.status.success(), "vkgen failed:\n{}", String::from_utf8_lossy(&output.stdout));
+
+		let cargo_toml = std::fs::read_to_string(&toml_path).expect("Could not read Cargo.toml");
+		let begin = cargo_toml.find("# BEGIN vkgen features\n").unwrap() + "# BEGIN vkgen features\n".len();
+		let end = cargo_toml.find("# END vkgen features").unwrap();
+		cargo_toml[begin..end].lines().map(|l| l.to_string()).collect()
+	}
+
+	#[test]
+	fn features_test() {
+
+		let features = features("tests/fixtures/features_overlay.xml", "features.toml");
+		let expected = [
+			"VK_KHR_swapchain = [\"VK_KHR_surface\"]",
+			"VK_KHR_xcb_surface = [\"VK_KHR_surface\", \"xcb\"]",
+			// Disabled extensions have no feature, and nothing refers to one
+			"# VK_VENDOR_needs_disabled needs VK_NV_extension_1, which has no feature",
+			"VK_VENDOR_needs_disabled = [\"VK_KHR_swapchain\"]",
+			"# VK_VENDOR_needs_unknown needs VK_VENDOR_not_there, which has no feature",
+			"VK_VENDOR_needs_unknown = [\"VK_KHR_surface\"]",
+			// Cargo has no or, the first alternative that can be turned on is
+			"# VK_VENDOR_needs_either needs VK_NV_extension_1,VK_KHR_swapchain,VK_EXT_debug_report, the feature turns on VK_KHR_swapchain",
+			"VK_VENDOR_needs_either = [\"VK_KHR_swapchain\"]",
+			// A core version always meets it
+			"VK_VENDOR_needs_nothing = []",
+			"VK_KHR_shader_float16_int8 = []",
+		];
+		for line in expected.iter() {
+			assert!(features.iter().any(|l| l == line), "Missing from the features: {}\n{}", line, features.join("\n"));
+		}
+		assert!(!features.iter().any(|l| l.starts_with("VK_NV_extension_1")));
+		assert!(!features.iter().any(|l| !l.starts_with('#') && l.contains("VK_NV_extension_1")));
+		assert!(!features.iter().any(|l| !l.starts_with('#') && l.contains("VK_VENDOR_not_there")));
+	}
+}
@@ -0,0 +1,8 @@
+// The bindings link to libvulkan, the tests are the Vulkan implementation instead so an empty library is enough
+
+fn main() {
+	let out_dir = std::env::var("OUT_DIR").unwrap();
+	let library = if cfg!(windows) { "vulkan.lib" } else { "libvulkan.a" };
+	std::fs::write(format!("{}/{}", out_dir, library), "!<arch>\n").expect("Could not write the empty library");
+	println!("cargo:rustc-link-search=native={}", out_dir);
+}
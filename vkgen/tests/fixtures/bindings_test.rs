@@ -0,0 +1,343 @@
+// Runs the bindings vkgen generates for vk.xml, built with every feature (see tests/bindings.rs)
+// There is no Vulkan implementation, the commands the bindings call are exported here and go to a driver written with the icd module
+
+#![allow(non_snake_case)]
+
+extern crate bindings;
+extern crate serde_json;
+
+use bindings::*;
+use bindings::icd::{Driver, PhysicalDevice};
+use bindings::validate::{Problem, ValidationError};
+use std::ptr;
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+struct Fixture {
+	physical_devices: Vec<PhysicalDevice>
+}
+
+static FIXTURE: OnceLock<Fixture> = OnceLock::new();
+
+// The UUID has a 0 early on, which would end it if it was traced as a string
+const UUID: [u8; 16] = [1, 0, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+impl Driver for Fixture {
+
+	fn get() -> &'static Fixture {
+		FIXTURE.get_or_init(|| {
+			let mut physical_device = PhysicalDevice::new("Fixture GPU");
+			physical_device.properties.deviceType = VkPhysicalDeviceType::VK_PHYSICAL_DEVICE_TYPE_INTEGRATED_GPU;
+			physical_device.properties.pipelineCacheUUID = UUID;
+			physical_device.properties.limits.maxComputeWorkGroupCount = [64, 32, 1];
+			physical_device.properties.limits.pointSizeRange = [1.0, 64.5];
+			physical_device.properties.limits.framebufferColorSampleCounts = VkSampleCountFlags::VK_SAMPLE_COUNT_1_BIT | VkSampleCountFlags::VK_SAMPLE_COUNT_4_BIT;
+			Fixture { physical_devices: vec![physical_device] }
+		})
+	}
+
+	fn physical_devices(&self) -> &[PhysicalDevice] {
+		&self.physical_devices
+	}
+}
+
+// Exports a command of the library the bindings link to, implemented by the driver
+macro_rules! export {
+	($name:ident($($arg:ident: $t:ty),*) $(-> $result:ty)*) => {
+		#[no_mangle]
+		pub unsafe extern "C" fn $name($($arg: $t),*) $(-> $result)* {
+			let address = icd::get_instance_proc_addr::<Fixture>(VK_NULL_HANDLE, concat!(stringify!($name), "\0").as_ptr());
+			assert!(!address.is_null(), "The driver has no {}", stringify!($name));
+			::core::mem::transmute::<PFN_vkVoidFunction, unsafe extern "C" fn($($t),*) $(-> $result)*>(address)($($arg),*)
+		}
+	}
+}
+
+export!(vkCreateInstance(pCreateInfo: *const VkInstanceCreateInfo, pAllocator: *const VkAllocationCallbacks, pInstance: *mut VkInstance) -> VkResult);
+export!(vkDestroyInstance(instance: VkInstance, pAllocator: *const VkAllocationCallbacks));
+export!(vkEnumeratePhysicalDevices(instance: VkInstance, pPhysicalDeviceCount: *mut u32, pPhysicalDevices: *mut VkPhysicalDevice) -> VkResult);
+export!(vkGetPhysicalDeviceProperties(physicalDevice: VkPhysicalDevice, pProperties: *mut VkPhysicalDeviceProperties));
+export!(vkGetPhysicalDeviceFeatures2(physicalDevice: VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures2));
+export!(vkCreateDevice(physicalDevice: VkPhysicalDevice, pCreateInfo: *const VkDeviceCreateInfo, pAllocator: *const VkAllocationCallbacks, pDevice: *mut VkDevice) -> VkResult);
+export!(vkDestroyDevice(device: VkDevice, pAllocator: *const VkAllocationCallbacks));
+export!(vkGetInstanceProcAddr(instance: VkInstance, pName: *const u8) -> PFN_vkVoidFunction);
+export!(vkGetDeviceProcAddr(device: VkDevice, pName: *const u8) -> PFN_vkVoidFunction);
+
+// Not in the driver, it checks that the enumeration wrapper set up each element before the second call
+#[no_mangle]
+pub unsafe extern "C" fn vkGetPhysicalDeviceQueueFamilyProperties2(_physicalDevice: VkPhysicalDevice, pQueueFamilyPropertyCount: *mut u32, pQueueFamilyProperties: *mut VkQueueFamilyProperties2) {
+	if pQueueFamilyProperties.is_null() {
+		*pQueueFamilyPropertyCount = 2;
+		return;
+	}
+	for i in 0..*pQueueFamilyPropertyCount as usize {
+		let properties = &mut *pQueueFamilyProperties.add(i);
+		assert!(properties.sType == VkStructureType::VK_STRUCTURE_TYPE_QUEUE_FAMILY_PROPERTIES_2);
+		assert!(properties.pNext.is_null());
+		properties.queueFamilyProperties.queueCount = i as u32 + 1;
+	}
+}
+
+// The traced calls, as "command(arguments) = result"
+static TRACE: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+struct Recorder;
+
+impl traced::TraceSink for Recorder {
+	fn call(&self, command: &str, arguments: &str, result: &str, _duration: Duration) {
+		TRACE.lock().unwrap().push(format!("{}({}) = {}", command, arguments, result));
+	}
+}
+
+fn traced_call(command: &str) -> String {
+	TRACE.lock().unwrap().iter().rev().find(|call| call.starts_with(&format!("{}(", command))).cloned().unwrap_or_else(|| panic!("{} was not traced", command))
+}
+
+// A struct the bindings do not know, for the end of a pNext chain
+#[repr(C)]
+struct UnknownStruct {
+	sType: i32,
+	pNext: *mut core::ffi::c_void
+}
+
+unsafe fn create_instance() -> VkInstance {
+	let mut application_info: VkApplicationInfo = ::core::mem::zeroed();
+	application_info.sType = VkStructureType::VK_STRUCTURE_TYPE_APPLICATION_INFO;
+	application_info.pApplicationName = "bindings test\0".as_ptr();
+	let mut create_info: VkInstanceCreateInfo = ::core::mem::zeroed();
+	create_info.sType = VkStructureType::VK_STRUCTURE_TYPE_INSTANCE_CREATE_INFO;
+	create_info.pApplicationInfo = &application_info;
+
+	let mut instance = VK_NULL_HANDLE;
+	assert_eq!(traced::vkCreateInstance(&create_info, ptr::null(), &mut instance), VkResult::VK_SUCCESS);
+	instance
+}
+
+unsafe fn physical_device(instance: VkInstance) -> VkPhysicalDevice {
+	let mut count = 1;
+	let mut physical_device = VK_NULL_HANDLE;
+	assert_eq!(validate::vkEnumeratePhysicalDevices(instance, &mut count, &mut physical_device), Ok(VkResult::VK_SUCCESS));
+	assert_eq!(count, 1);
+	physical_device
+}
+
+fn device_create_info(queue_create_info: &VkDeviceQueueCreateInfo) -> VkDeviceCreateInfo {
+	let mut create_info: VkDeviceCreateInfo = unsafe { ::core::mem::zeroed() };
+	create_info.sType = VkStructureType::VK_STRUCTURE_TYPE_DEVICE_CREATE_INFO;
+	create_info.queueCreateInfoCount = 1;
+	create_info.pQueueCreateInfos = queue_create_info;
+	create_info
+}
+
+// A layer that overrides a command without a result, which only compiles if the trait's returns nothing too
+#[allow(dead_code)]
+struct VoidLayer;
+
+#[allow(dead_code)]
+static VOID_LAYER: VoidLayer = VoidLayer;
+
+impl layer::Layer for VoidLayer {
+	fn get() -> &'static VoidLayer {
+		&VOID_LAYER
+	}
+
+	unsafe fn vkDestroyDevice(&self, dispatch: &layer::DeviceDispatch, device: VkDevice, pAllocator: *const VkAllocationCallbacks) {
+		dispatch.vkDestroyDevice(device, pAllocator)
+	}
+}
+
+#[test]
+fn trace_test() {
+	unsafe {
+		traced::set_sink(Box::new(Recorder));
+		let instance = create_instance();
+		assert!(traced_call("vkCreateInstance").contains("pApplicationName: \"bindings test\""));
+
+		let mut properties: VkPhysicalDeviceProperties = ::core::mem::zeroed();
+		traced::vkGetPhysicalDeviceProperties(physical_device(instance), &mut properties);
+		let call = traced_call("vkGetPhysicalDeviceProperties");
+		assert!(call.contains("deviceName: \"Fixture GPU\""), "{}", call);
+		assert!(call.contains("pipelineCacheUUID: [1, 0, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]"), "{}", call);
+		assert!(call.contains("deviceType: VK_PHYSICAL_DEVICE_TYPE_INTEGRATED_GPU"), "{}", call);
+		assert!(call.ends_with(" = void"), "{}", call);
+
+		// Commands without a result return nothing, wherever they are called from (the declaration, not the export above)
+		let _: unsafe extern "C" fn(VkInstance, *const VkAllocationCallbacks) = bindings::vkDestroyInstance;
+		let () = traced::vkDestroyInstance(instance, ptr::null());
+		assert!(traced_call("vkDestroyInstance").ends_with("pAllocator: null) = void"));
+	}
+}
+
+#[test]
+fn enumerate_test() {
+	unsafe {
+		let queue_families = enumerate::vkGetPhysicalDeviceQueueFamilyProperties2(VK_NULL_HANDLE);
+		assert_eq!(queue_families.iter().map(|f| f.queueFamilyProperties.queueCount).collect::<Vec<u32>>(), vec![1, 2]);
+	}
+}
+
+#[test]
+fn validate_test() {
+	unsafe {
+		let instance = create_instance();
+		let physical_device = physical_device(instance);
+
+		assert_eq!(validate::vkEnumeratePhysicalDevices(VK_NULL_HANDLE, &mut 0, ptr::null_mut()), Err(ValidationError {
+			command: "vkEnumeratePhysicalDevices",
+			parameter: "instance".to_string(),
+			problem: Problem::Null
+		}));
+
+		let priority = 1.0f32;
+		let mut queue_create_info: VkDeviceQueueCreateInfo = ::core::mem::zeroed();
+		queue_create_info.sType = VkStructureType::VK_STRUCTURE_TYPE_DEVICE_QUEUE_CREATE_INFO;
+		queue_create_info.queueCount = 1;
+		queue_create_info.pQueuePriorities = &priority;
+
+		let mut create_info = device_create_info(&queue_create_info);
+		create_info.sType = VkStructureType::VK_STRUCTURE_TYPE_INSTANCE_CREATE_INFO;
+		let mut device = VK_NULL_HANDLE;
+		let error = validate::vkCreateDevice(physical_device, &create_info, ptr::null(), &mut device).unwrap_err();
+		assert_eq!(error.to_string(), "vkCreateDevice: pCreateInfo->sType is VK_STRUCTURE_TYPE_INSTANCE_CREATE_INFO instead of VK_STRUCTURE_TYPE_DEVICE_CREATE_INFO");
+
+		// Arrays are checked element by element
+		let mut broken_queue_create_info = queue_create_info;
+		broken_queue_create_info.pQueuePriorities = ptr::null();
+		let create_info = device_create_info(&broken_queue_create_info);
+		let error = validate::vkCreateDevice(physical_device, &create_info, ptr::null(), &mut device).unwrap_err();
+		assert_eq!(error.to_string(), "vkCreateDevice: pCreateInfo->pQueueCreateInfos[0].pQueuePriorities is null");
+		assert_eq!(device, VK_NULL_HANDLE);
+
+		// The structs in pNext chains are found from their sType
+		let mut features: VkPhysicalDeviceFeatures2 = ::core::mem::zeroed();
+		features.sType = VkStructureType::VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FEATURES_2;
+		features.pNext = &broken_queue_create_info as *const VkDeviceQueueCreateInfo as *mut core::ffi::c_void;
+		let mut create_info = device_create_info(&queue_create_info);
+		create_info.pNext = &features as *const VkPhysicalDeviceFeatures2 as *const core::ffi::c_void;
+		let error = validate::vkCreateDevice(physical_device, &create_info, ptr::null(), &mut device).unwrap_err();
+		assert_eq!(error.parameter, "pCreateInfo->pNext->pNext->pQueuePriorities");
+
+		let mut create_info = device_create_info(&queue_create_info);
+		create_info.queueCreateInfoCount = 0;
+		let error = validate::vkCreateDevice(physical_device, &create_info, ptr::null(), &mut device).unwrap_err();
+		assert_eq!(error.problem, Problem::ZeroCount);
+
+		let create_info = device_create_info(&queue_create_info);
+		assert_eq!(validate::vkCreateDevice(physical_device, &create_info, ptr::null(), &mut device), Ok(VkResult::VK_SUCCESS));
+		assert!(device != VK_NULL_HANDLE);
+
+		// Extension commands go through the function pointers, which the driver has under both names of an alias
+		let functions = VulkanFunctionPointers::new(instance);
+		let _: Option<extern "C" fn(VkDevice, VkSwapchainKHR, *const VkAllocationCallbacks)> = functions.DestroySwapchainKHR;
+		assert!(functions.GetPhysicalDeviceFeatures2KHR.is_some());
+		let swapchain_fn = KhrSwapchainFn::load(device).expect("Could not load VK_KHR_swapchain");
+		let _: extern "C" fn(VkDevice, VkSwapchainKHR, *const VkAllocationCallbacks) = swapchain_fn.DestroySwapchainKHR;
+		let () = validate::vkDestroySwapchainKHR(&functions, device, VK_NULL_HANDLE, ptr::null()).unwrap();
+
+		let () = validate::vkDestroyDevice(device, ptr::null()).unwrap();
+		let () = validate::vkDestroyInstance(instance, ptr::null()).unwrap();
+	}
+}
+
+// Without --newtype-enums, enum values are read as integers before they are used
+#[cfg(not(feature = "newtype"))]
+#[test]
+fn raw_enum_test() {
+	let priority = 1.0f32;
+	let mut queue_create_info: VkDeviceQueueCreateInfo = unsafe { ::core::mem::zeroed() };
+	queue_create_info.sType = VkStructureType::VK_STRUCTURE_TYPE_DEVICE_QUEUE_CREATE_INFO;
+	queue_create_info.queueCount = 1;
+	queue_create_info.pQueuePriorities = &priority;
+	let mut create_info = device_create_info(&queue_create_info);
+	unsafe { ptr::addr_of_mut!(create_info.sType).cast::<i32>().write(1234) };
+	let mut device = VK_NULL_HANDLE;
+	let error = unsafe { validate::vkCreateDevice(1, &create_info, ptr::null(), &mut device) }.unwrap_err();
+	assert_eq!((error.parameter.as_str(), error.problem), ("pCreateInfo->sType", Problem::EnumValue(1234)));
+}
+
+#[test]
+fn chain_test() {
+	unsafe {
+		let mut unknown = UnknownStruct { sType: 1234, pNext: ptr::null_mut() };
+		let mut float16_int8: VkPhysicalDeviceFloat16Int8FeaturesKHR = ::core::mem::zeroed();
+		float16_int8.sType = VkStructureType::VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FLOAT16_INT8_FEATURES_KHR;
+		float16_int8.pNext = &mut unknown as *mut UnknownStruct as *mut core::ffi::c_void;
+		float16_int8.shaderInt8 = VK_TRUE;
+		let mut features: VkPhysicalDeviceFeatures2 = ::core::mem::zeroed();
+		features.sType = VkStructureType::VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FEATURES_2;
+		features.pNext = &mut float16_int8 as *mut VkPhysicalDeviceFloat16Int8FeaturesKHR as *mut core::ffi::c_void;
+
+		let mut structs = chain(features.pNext);
+		match structs.next() {
+			Some(ChainStruct::VkPhysicalDeviceFloat16Int8FeaturesKHR(value)) => assert_eq!(value.shaderInt8, VK_TRUE),
+			other => panic!("Expected VkPhysicalDeviceFloat16Int8FeaturesKHR, not {:?}", other)
+		}
+		match structs.next() {
+			Some(ChainStruct::Unknown(sType)) => assert_eq!(sType, 1234),
+			other => panic!("Expected the unknown struct, not {:?}", other)
+		}
+		assert!(structs.next().is_none());
+		assert_eq!(format!("{:?}", chain(float16_int8.pNext)), "[{ sType: 1234, .. }]");
+
+		// The driver leaves the chain alone, and the trace follows it
+		traced::set_sink(Box::new(Recorder));
+		let instance = create_instance();
+		traced::vkGetPhysicalDeviceFeatures2(physical_device(instance), &mut features);
+		let call = traced_call("vkGetPhysicalDeviceFeatures2");
+		assert!(call.contains("pNext: &VkPhysicalDeviceFloat16Int8FeaturesKHR { "), "{}", call);
+		assert!(call.contains("shaderInt8: 1"), "{}", call);
+		traced::vkDestroyInstance(instance, ptr::null());
+	}
+}
+
+#[test]
+fn serde_test() {
+	let properties = Fixture::get().physical_devices[0].properties;
+	let json = serde_json::to_string(&properties).unwrap();
+	assert!(json.contains("\"deviceName\":\"Fixture GPU\""), "{}", json);
+	assert!(json.contains("\"pipelineCacheUUID\":[1,0,2,3,4,5,6,7,8,9,10,11,12,13,14,15]"), "{}", json);
+	assert!(json.contains("\"deviceType\":\"VK_PHYSICAL_DEVICE_TYPE_INTEGRATED_GPU\""), "{}", json);
+	assert!(json.contains("\"framebufferColorSampleCounts\":5"), "{}", json);
+
+	let read: VkPhysicalDeviceProperties = serde_json::from_str(&json).unwrap();
+	assert_eq!(format!("{:?}", read), format!("{:?}", properties));
+	assert_eq!(read.pipelineCacheUUID, UUID);
+
+	// Values from extensions are known by name too
+	let format: VkFormat = serde_json::from_str("\"VK_FORMAT_A4R4G4B4_UNORM_PACK16_EXT\"").unwrap();
+	assert_eq!(format, VkFormat::VK_FORMAT_A4R4G4B4_UNORM_PACK16_EXT);
+	assert_eq!(serde_json::to_string(&format).unwrap(), "\"VK_FORMAT_A4R4G4B4_UNORM_PACK16_EXT\"");
+	assert!(serde_json::from_str::<VkFormat>("\"VK_FORMAT_NOTHING\"").is_err());
+}
+
+#[test]
+fn tables_test() {
+	assert_eq!(VkFormat::VK_FORMAT_D24_UNORM_S8_UINT.aspect_mask(), VkImageAspectFlags::VK_IMAGE_ASPECT_DEPTH_BIT | VkImageAspectFlags::VK_IMAGE_ASPECT_STENCIL_BIT);
+	assert_eq!(VkFormat::VK_FORMAT_ASTC_8x6_UNORM_BLOCK.block_extent(), (8, 6, 1));
+	assert_eq!(VkFormat::VK_FORMAT_A4R4G4B4_UNORM_PACK16_EXT.block_size(), 2);
+
+	assert_eq!(<handle::VkSurfaceKHR as Handle>::OBJECT_TYPE, VkObjectType::VK_OBJECT_TYPE_SURFACE_KHR);
+	assert_eq!(<handle::VkSurfaceKHR as Handle>::DEBUG_REPORT_OBJECT_TYPE, VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_SURFACE_KHR_EXT);
+	assert_eq!(<<handle::VkImage as Handle>::Parent as Handle>::NAME, "VkDevice");
+	assert_eq!(<handle::VkCommandBuffer as Handle>::DESTROY_COMMAND, Some("vkFreeCommandBuffers"));
+}
+
+// Only with --newtype-enums, values the registry does not have
+#[cfg(feature = "newtype")]
+#[test]
+fn newtype_test() {
+	assert_eq!(VkResult(-1234).known(), None);
+	assert_eq!(VkResult::VK_ERROR_SURFACE_LOST_KHR.known(), Some(known::VkResult::VK_ERROR_SURFACE_LOST_KHR));
+	assert_eq!(format!("{:?}", VkStructureType(1234)), "VkStructureType(1234)");
+
+	let priority = 1.0f32;
+	let mut queue_create_info: VkDeviceQueueCreateInfo = unsafe { ::core::mem::zeroed() };
+	queue_create_info.sType = VkStructureType::VK_STRUCTURE_TYPE_DEVICE_QUEUE_CREATE_INFO;
+	queue_create_info.queueCount = 1;
+	queue_create_info.pQueuePriorities = &priority;
+	let mut create_info = device_create_info(&queue_create_info);
+	create_info.sType = VkStructureType(1234);
+	let mut device = VK_NULL_HANDLE;
+	let error = unsafe { validate::vkCreateDevice(1, &create_info, ptr::null(), &mut device) }.unwrap_err();
+	assert_eq!(error.to_string(), "vkCreateDevice: pCreateInfo->sType is VkStructureType(1234) instead of VK_STRUCTURE_TYPE_DEVICE_CREATE_INFO");
+}
@@ -0,0 +1,4233 @@
+/*
+Copyright (c) 2015-2019 The Khronos Group Inc.
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+
+This file is a cut-down fixture used by the vkgen golden tests.
+*/
+
+#![allow(non_snake_case)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[macro_use]
+extern crate bitflags;
+
+use core::ffi::c_void;
+
+pub const VK_VERSION_1_0: u32 = 1;
+
+pub const fn VK_MAKE_VERSION(major: u32, minor: u32, patch: u32) -> u32 {
+	((major) << 22) | ((minor) << 12) | (patch)
+}
+
+pub const VK_API_VERSION_1_0: u32 = VK_MAKE_VERSION(1, 0, 0);
+
+pub const fn VK_VERSION_MAJOR(version: u32) -> u32 {
+	version >> 22
+}
+
+pub const fn VK_VERSION_MINOR(version: u32) -> u32 {
+	(version >> 22) & 0x3ff
+}
+
+pub const fn VK_VERSION_PATCH(version: u32) -> u32 {
+	version & 0x3ff
+}
+
+
+pub const VK_HEADER_VERSION: i32 = 131;
+pub const VK_NULL_HANDLE: u64 = 0;
+
+#[allow(non_camel_case_types)]
+pub type VkDeviceSize = u64;
+#[allow(non_camel_case_types)]
+pub type VkSampleMask = u32;
+#[allow(non_camel_case_types)]
+pub type VkDeviceAddress = u64;
+#[allow(non_camel_case_types)]
+pub type VkFlags = u32;
+#[allow(non_camel_case_types)]
+pub type VkFlags64 = u64;
+
+#[allow(non_camel_case_types)]
+pub type PFN_vkAllocationFunction = *const c_void;
+#[allow(non_camel_case_types)]
+pub type PFN_vkReallocationFunction = *const c_void;
+#[allow(non_camel_case_types)]
+pub type PFN_vkFreeFunction = *const c_void;
+#[allow(non_camel_case_types)]
+pub type PFN_vkInternalAllocationNotification = *const c_void;
+#[allow(non_camel_case_types)]
+pub type PFN_vkInternalFreeNotification = *const c_void;
+#[allow(non_camel_case_types)]
+pub type PFN_vkDebugReportCallbackEXT = *const c_void;
+#[allow(non_camel_case_types)]
+pub type PFN_vkDebugUtilsMessengerCallbackEXT = *const c_void;
+#[allow(non_camel_case_types)]
+pub type PFN_vkVoidFunction = *const c_void;
+
+// Rust assumes bool is u8, vulkan assumes it is u32
+pub type VkBool32 = u32;
+
+// Unions of Copy types, so they are Copy themselves
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub union VkClearColorValue {
+	pub float32: [f32; 4],
+	pub int32: [i32; 4],
+	pub uint32: [u32; 4]
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub union VkClearValue {
+	pub colour: VkClearColorValue,
+	pub depthStencil: VkClearDepthStencilValue
+}
+
+impl core::fmt::Debug for VkClearColorValue {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		unsafe { write!(f, "VkClearColorValue {{float32: {:?}; or int32: {:?}; or uint32: {:?}}}", self.float32, self.int32, self.uint32) }
+	}
+}
+
+impl core::fmt::Debug for VkClearValue {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		unsafe { write!(f, "VkClearValue {{colour: {:?}; or depthStencil: {:?}}}", self.colour, self.depthStencil) }
+	}
+}
+
+// TODO
+// Cannot implement Debug for [u8; x > 32]
+impl core::fmt::Debug for VkPhysicalDeviceProperties {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "not implemented")
+	}
+}
+
+impl core::fmt::Debug for VkLayerProperties {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "not implemented")
+	}
+}
+
+impl core::fmt::Debug for VkExtensionProperties {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "not implemented")
+	}
+}
+
+impl core::fmt::Debug for VkPhysicalDeviceMemoryProperties {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		write!(f, "not implemented")
+	}
+}
+pub const VK_MAX_PHYSICAL_DEVICE_NAME_SIZE: usize = 256;
+pub const VK_UUID_SIZE: usize = 16;
+pub const VK_LUID_SIZE: usize = 8;
+pub const VK_LUID_SIZE_KHR: usize = VK_LUID_SIZE;
+pub const VK_MAX_EXTENSION_NAME_SIZE: usize = 256;
+pub const VK_MAX_DESCRIPTION_SIZE: usize = 256;
+pub const VK_MAX_MEMORY_TYPES: usize = 32;
+pub const VK_MAX_DEVICE_GROUP_SIZE: usize = 32;
+pub const VK_MAX_MEMORY_HEAPS: usize = 16;
+pub const VK_LOD_CLAMP_NONE: f32 = 1000.0;
+pub const VK_REMAINING_MIP_LEVELS: usize = ::core::usize::MAX;
+pub const VK_WHOLE_SIZE: u64 = ::core::u64::MAX;
+pub const VK_QUEUE_FAMILY_IGNORED: usize = ::core::usize::MAX;
+pub const VK_TRUE: u32 = 1;
+pub const VK_FALSE: u32 = 0;
+pub const VK_KHR_SURFACE_SPEC_VERSION: u32 = 25;
+pub const VK_KHR_SURFACE_EXTENSION_NAME: &'static ::core::ffi::CStr = unsafe { ::core::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_surface\0") };
+pub const VK_KHR_SWAPCHAIN_SPEC_VERSION: u32 = 70;
+pub const VK_KHR_SWAPCHAIN_EXTENSION_NAME: &'static ::core::ffi::CStr = unsafe { ::core::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_swapchain\0") };
+pub const VK_KHR_XCB_SURFACE_SPEC_VERSION: u32 = 6;
+pub const VK_KHR_XCB_SURFACE_EXTENSION_NAME: &'static ::core::ffi::CStr = unsafe { ::core::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_xcb_surface\0") };
+pub const VK_EXT_DEBUG_REPORT_SPEC_VERSION: u32 = 9;
+pub const VK_EXT_DEBUG_REPORT_EXTENSION_NAME: &'static ::core::ffi::CStr = unsafe { ::core::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_debug_report\0") };
+pub const VK_KHR_GET_PHYSICAL_DEVICE_PROPERTIES_2_SPEC_VERSION: u32 = 1;
+pub const VK_KHR_GET_PHYSICAL_DEVICE_PROPERTIES_2_EXTENSION_NAME: &'static ::core::ffi::CStr = unsafe { ::core::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_get_physical_device_properties2\0") };
+pub const VK_KHR_SHADER_FLOAT16_INT8_SPEC_VERSION: u32 = 1;
+pub const VK_KHR_SHADER_FLOAT16_INT8_EXTENSION_NAME: &'static ::core::ffi::CStr = unsafe { ::core::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_KHR_shader_float16_int8\0") };
+pub const VK_EXT_4444_FORMATS_SPEC_VERSION: u32 = 1;
+pub const VK_EXT_4444_FORMATS_EXTENSION_NAME: &'static ::core::ffi::CStr = unsafe { ::core::ffi::CStr::from_bytes_with_nul_unchecked(b"VK_EXT_4444_formats\0") };
+#[allow(non_camel_case_types)]
+pub type void = u64;
+#[allow(non_camel_case_types)]
+pub type char = u64;
+#[allow(non_camel_case_types)]
+pub type float = u64;
+#[allow(non_camel_case_types)]
+pub type uint8_t = u64;
+#[allow(non_camel_case_types)]
+pub type uint32_t = u64;
+#[allow(non_camel_case_types)]
+pub type uint64_t = u64;
+#[allow(non_camel_case_types)]
+pub type int32_t = u64;
+#[allow(non_camel_case_types)]
+pub type size_t = u64;
+#[allow(non_camel_case_types)]
+#[cfg(feature="xcb")]
+pub type xcb_connection_t = xcb::ffi::xcb_connection_t;
+#[allow(non_camel_case_types)]
+#[cfg(feature="xcb")]
+pub type xcb_visualid_t = u32;
+#[allow(non_camel_case_types)]
+#[cfg(feature="xcb")]
+pub type xcb_window_t = u32;
+#[allow(non_camel_case_types)]
+pub type VkQueueFlags = VkQueueFlagBits;
+#[allow(non_camel_case_types)]
+pub type VkMemoryPropertyFlags = VkMemoryPropertyFlagBits;
+#[allow(non_camel_case_types)]
+pub type VkMemoryHeapFlags = VkMemoryHeapFlagBits;
+#[allow(non_camel_case_types)]
+pub type VkBufferUsageFlags = VkBufferUsageFlagBits;
+#[allow(non_camel_case_types)]
+pub type VkBufferCreateFlags = VkBufferCreateFlagBits;
+#[allow(non_camel_case_types)]
+pub type VkSampleCountFlags = VkSampleCountFlagBits;
+#[allow(non_camel_case_types)]
+pub type VkImageAspectFlags = VkImageAspectFlagBits;
+#[allow(non_camel_case_types)]
+pub type VkDeviceQueueCreateFlags = VkDeviceQueueCreateFlagBits;
+#[allow(non_camel_case_types)]
+pub type VkCommandPoolCreateFlags = VkCommandPoolCreateFlagBits;
+#[allow(non_camel_case_types)]
+pub type VkFenceCreateFlags = VkFenceCreateFlagBits;
+#[allow(non_camel_case_types)]
+pub type VkInstanceCreateFlags = u32;
+#[allow(non_camel_case_types)]
+pub type VkDeviceCreateFlags = u32;
+#[allow(non_camel_case_types)]
+pub type VkMemoryMapFlags = u32;
+#[allow(non_camel_case_types)]
+pub type VkSurfaceTransformFlagsKHR = VkSurfaceTransformFlagBitsKHR;
+#[allow(non_camel_case_types)]
+pub type VkCompositeAlphaFlagsKHR = VkCompositeAlphaFlagBitsKHR;
+#[allow(non_camel_case_types)]
+pub type VkImageUsageFlags = VkImageUsageFlagBits;
+#[allow(non_camel_case_types)]
+pub type VkSwapchainCreateFlagsKHR = VkSwapchainCreateFlagBitsKHR;
+#[allow(non_camel_case_types)]
+pub type VkXcbSurfaceCreateFlagsKHR = u32;
+#[allow(non_camel_case_types)]
+pub type VkDebugReportFlagsEXT = VkDebugReportFlagBitsEXT;
+#[allow(non_camel_case_types)]
+pub type VkInstance = u64;
+#[allow(non_camel_case_types)]
+pub type VkPhysicalDevice = u64;
+#[allow(non_camel_case_types)]
+pub type VkDevice = u64;
+#[allow(non_camel_case_types)]
+pub type VkQueue = u64;
+#[allow(non_camel_case_types)]
+pub type VkCommandBuffer = u64;
+#[allow(non_camel_case_types)]
+pub type VkDeviceMemory = u64;
+#[allow(non_camel_case_types)]
+pub type VkCommandPool = u64;
+#[allow(non_camel_case_types)]
+pub type VkBuffer = u64;
+#[allow(non_camel_case_types)]
+pub type VkImage = u64;
+#[allow(non_camel_case_types)]
+pub type VkFence = u64;
+#[allow(non_camel_case_types)]
+pub type VkSemaphore = u64;
+#[allow(non_camel_case_types)]
+pub type VkSurfaceKHR = u64;
+#[allow(non_camel_case_types)]
+pub type VkSwapchainKHR = u64;
+#[allow(non_camel_case_types)]
+pub type VkDebugReportCallbackEXT = u64;
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(C)]
+pub enum VkAttachmentLoadOp {
+	VK_ATTACHMENT_LOAD_OP_LOAD = 0,
+	VK_ATTACHMENT_LOAD_OP_CLEAR = 1,
+	VK_ATTACHMENT_LOAD_OP_DONT_CARE = 2,
+	VK_ATTACHMENT_LOAD_OP_MAX_ENUM = 0x7FFFFFFF
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(C)]
+pub enum VkFormat {
+	VK_FORMAT_UNDEFINED = 0,
+	VK_FORMAT_R8_UNORM = 9,
+	VK_FORMAT_R8G8B8A8_UNORM = 37,
+	VK_FORMAT_R8G8B8A8_SRGB = 43,
+	VK_FORMAT_B8G8R8A8_UNORM = 44,
+	VK_FORMAT_B8G8R8A8_SRGB = 50,
+	VK_FORMAT_R16G16B16A16_SFLOAT = 97,
+	VK_FORMAT_R32G32B32A32_SFLOAT = 109,
+	VK_FORMAT_D16_UNORM = 124,
+	VK_FORMAT_D32_SFLOAT = 126,
+	VK_FORMAT_S8_UINT = 127,
+	VK_FORMAT_D24_UNORM_S8_UINT = 129,
+	VK_FORMAT_D32_SFLOAT_S8_UINT = 130,
+	VK_FORMAT_BC1_RGB_UNORM_BLOCK = 131,
+	VK_FORMAT_ETC2_R8G8B8_UNORM_BLOCK = 147,
+	VK_FORMAT_ASTC_4x4_UNORM_BLOCK = 157,
+	VK_FORMAT_ASTC_8x6_UNORM_BLOCK = 163,
+
+	// VK_EXT_4444_formats
+#[cfg(feature = "VK_EXT_4444_formats")]
+	VK_FORMAT_A4R4G4B4_UNORM_PACK16_EXT = 1000340000,
+	VK_FORMAT_MAX_ENUM = 0x7FFFFFFF
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(C)]
+pub enum VkObjectType {
+	VK_OBJECT_TYPE_UNKNOWN = 0,
+	VK_OBJECT_TYPE_INSTANCE = 1,
+	VK_OBJECT_TYPE_PHYSICAL_DEVICE = 2,
+	VK_OBJECT_TYPE_DEVICE = 3,
+	VK_OBJECT_TYPE_QUEUE = 4,
+	VK_OBJECT_TYPE_SEMAPHORE = 5,
+	VK_OBJECT_TYPE_COMMAND_BUFFER = 6,
+	VK_OBJECT_TYPE_FENCE = 7,
+	VK_OBJECT_TYPE_DEVICE_MEMORY = 8,
+	VK_OBJECT_TYPE_BUFFER = 9,
+	VK_OBJECT_TYPE_IMAGE = 10,
+	VK_OBJECT_TYPE_COMMAND_POOL = 25,
+
+	// VK_KHR_surface
+#[cfg(feature = "VK_KHR_surface")]
+	VK_OBJECT_TYPE_SURFACE_KHR = 1000000000,
+
+	// VK_KHR_swapchain
+#[cfg(feature = "VK_KHR_swapchain")]
+	VK_OBJECT_TYPE_SWAPCHAIN_KHR = 1000001000,
+
+	// VK_EXT_debug_report
+#[cfg(feature = "VK_EXT_debug_report")]
+	VK_OBJECT_TYPE_DEBUG_REPORT_CALLBACK_EXT = 1000011000,
+	VK_OBJECT_TYPE_MAX_ENUM = 0x7FFFFFFF
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(C)]
+pub enum VkPhysicalDeviceType {
+	VK_PHYSICAL_DEVICE_TYPE_OTHER = 0,
+	VK_PHYSICAL_DEVICE_TYPE_INTEGRATED_GPU = 1,
+	VK_PHYSICAL_DEVICE_TYPE_DISCRETE_GPU = 2,
+	VK_PHYSICAL_DEVICE_TYPE_VIRTUAL_GPU = 3,
+	VK_PHYSICAL_DEVICE_TYPE_CPU = 4,
+	VK_PHYSICAL_DEVICE_TYPE_MAX_ENUM = 0x7FFFFFFF
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(C)]
+pub enum VkResult {
+	VK_SUCCESS = 0,
+	VK_NOT_READY = 1,
+	VK_TIMEOUT = 2,
+	VK_INCOMPLETE = 5,
+	VK_ERROR_OUT_OF_HOST_MEMORY = -1,
+	VK_ERROR_OUT_OF_DEVICE_MEMORY = -2,
+	VK_ERROR_INITIALIZATION_FAILED = -3,
+	VK_ERROR_DEVICE_LOST = -4,
+	VK_ERROR_MEMORY_MAP_FAILED = -5,
+	VK_ERROR_LAYER_NOT_PRESENT = -6,
+	VK_ERROR_EXTENSION_NOT_PRESENT = -7,
+	VK_ERROR_INCOMPATIBLE_DRIVER = -9,
+
+	// VK_KHR_surface
+#[cfg(feature = "VK_KHR_surface")]
+	VK_ERROR_SURFACE_LOST_KHR = -1000000000,
+#[cfg(feature = "VK_KHR_surface")]
+	VK_ERROR_NATIVE_WINDOW_IN_USE_KHR = -1000000001,
+
+	// VK_KHR_swapchain
+#[cfg(feature = "VK_KHR_swapchain")]
+	VK_SUBOPTIMAL_KHR = 1000001004,
+#[cfg(feature = "VK_KHR_swapchain")]
+	VK_ERROR_OUT_OF_DATE_KHR = -1000001004,
+
+	// VK_EXT_debug_report
+#[cfg(feature = "VK_EXT_debug_report")]
+	VK_ERROR_VALIDATION_FAILED_EXT = -1000011001,
+	VK_RESULT_MAX_ENUM = 0x7FFFFFFF
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(C)]
+pub enum VkSharingMode {
+	VK_SHARING_MODE_EXCLUSIVE = 0,
+	VK_SHARING_MODE_CONCURRENT = 1,
+	VK_SHARING_MODE_MAX_ENUM = 0x7FFFFFFF
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(C)]
+pub enum VkStructureType {
+	VK_STRUCTURE_TYPE_APPLICATION_INFO = 0,
+	VK_STRUCTURE_TYPE_INSTANCE_CREATE_INFO = 1,
+	VK_STRUCTURE_TYPE_DEVICE_QUEUE_CREATE_INFO = 2,
+	VK_STRUCTURE_TYPE_DEVICE_CREATE_INFO = 3,
+	VK_STRUCTURE_TYPE_MEMORY_ALLOCATE_INFO = 5,
+	VK_STRUCTURE_TYPE_FENCE_CREATE_INFO = 8,
+	VK_STRUCTURE_TYPE_BUFFER_CREATE_INFO = 12,
+	VK_STRUCTURE_TYPE_COMMAND_POOL_CREATE_INFO = 39,
+	VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FEATURES_2 = 1000059000,
+	VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_GROUP_PROPERTIES = 1000070000,
+	VK_STRUCTURE_TYPE_QUEUE_FAMILY_PROPERTIES_2 = 1000059005,
+
+	// VK_KHR_swapchain
+#[cfg(feature = "VK_KHR_swapchain")]
+	VK_STRUCTURE_TYPE_SWAPCHAIN_CREATE_INFO_KHR = 1000001000,
+#[cfg(feature = "VK_KHR_swapchain")]
+	VK_STRUCTURE_TYPE_PRESENT_INFO_KHR = 1000001001,
+
+	// VK_KHR_xcb_surface
+#[cfg(feature = "VK_KHR_xcb_surface")]
+	VK_STRUCTURE_TYPE_XCB_SURFACE_CREATE_INFO_KHR = 1000005000,
+
+	// VK_EXT_debug_report
+#[cfg(feature = "VK_EXT_debug_report")]
+	VK_STRUCTURE_TYPE_DEBUG_REPORT_CALLBACK_CREATE_INFO_EXT = 1000011000,
+
+	// VK_KHR_shader_float16_int8
+#[cfg(feature = "VK_KHR_shader_float16_int8")]
+	VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FLOAT16_INT8_FEATURES_KHR = 1000082000,
+	VK_STRUCTURE_TYPE_MAX_ENUM = 0x7FFFFFFF
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(C)]
+pub enum VkSystemAllocationScope {
+	VK_SYSTEM_ALLOCATION_SCOPE_COMMAND = 0,
+	VK_SYSTEM_ALLOCATION_SCOPE_OBJECT = 1,
+	VK_SYSTEM_ALLOCATION_SCOPE_CACHE = 2,
+	VK_SYSTEM_ALLOCATION_SCOPE_DEVICE = 3,
+	VK_SYSTEM_ALLOCATION_SCOPE_INSTANCE = 4,
+	VK_SYSTEM_ALLOCATION_SCOPE_MAX_ENUM = 0x7FFFFFFF
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(C)]
+pub enum VkInternalAllocationType {
+	VK_INTERNAL_ALLOCATION_TYPE_EXECUTABLE = 0,
+	VK_INTERNAL_ALLOCATION_TYPE_MAX_ENUM = 0x7FFFFFFF
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(C)]
+pub enum VkColorSpaceKHR {
+	VK_COLOR_SPACE_SRGB_NONLINEAR_KHR = 0,
+	VK_COLOR_SPACE_KHR_MAX_ENUM = 0x7FFFFFFF
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(C)]
+pub enum VkPresentModeKHR {
+	VK_PRESENT_MODE_IMMEDIATE_KHR = 0,
+	VK_PRESENT_MODE_MAILBOX_KHR = 1,
+	VK_PRESENT_MODE_FIFO_KHR = 2,
+	VK_PRESENT_MODE_FIFO_RELAXED_KHR = 3,
+	VK_PRESENT_MODE_KHR_MAX_ENUM = 0x7FFFFFFF
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, PartialEq, Debug)]
+#[repr(C)]
+pub enum VkDebugReportObjectTypeEXT {
+	VK_DEBUG_REPORT_OBJECT_TYPE_UNKNOWN_EXT = 0,
+	VK_DEBUG_REPORT_OBJECT_TYPE_INSTANCE_EXT = 1,
+	VK_DEBUG_REPORT_OBJECT_TYPE_PHYSICAL_DEVICE_EXT = 2,
+	VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_EXT = 3,
+	VK_DEBUG_REPORT_OBJECT_TYPE_QUEUE_EXT = 4,
+	VK_DEBUG_REPORT_OBJECT_TYPE_SEMAPHORE_EXT = 5,
+	VK_DEBUG_REPORT_OBJECT_TYPE_COMMAND_BUFFER_EXT = 6,
+	VK_DEBUG_REPORT_OBJECT_TYPE_FENCE_EXT = 7,
+	VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_MEMORY_EXT = 8,
+	VK_DEBUG_REPORT_OBJECT_TYPE_BUFFER_EXT = 9,
+	VK_DEBUG_REPORT_OBJECT_TYPE_IMAGE_EXT = 10,
+	VK_DEBUG_REPORT_OBJECT_TYPE_COMMAND_POOL_EXT = 25,
+	VK_DEBUG_REPORT_OBJECT_TYPE_SURFACE_KHR_EXT = 26,
+	VK_DEBUG_REPORT_OBJECT_TYPE_SWAPCHAIN_KHR_EXT = 27,
+	VK_DEBUG_REPORT_OBJECT_TYPE_DEBUG_REPORT_CALLBACK_EXT_EXT = 28,
+	VK_DEBUG_REPORT_OBJECT_TYPE_EXT_MAX_ENUM = 0x7FFFFFFF
+}
+
+bitflags! {
+#[repr(C)]
+	pub struct VkQueueFlagBits: u32 {
+		const _EMPTY = 0;
+		const VK_QUEUE_GRAPHICS_BIT = 0b00000000000000000000000000000001;
+		const VK_QUEUE_COMPUTE_BIT = 0b00000000000000000000000000000010;
+		const VK_QUEUE_TRANSFER_BIT = 0b00000000000000000000000000000100;
+		const VK_QUEUE_SPARSE_BINDING_BIT = 0b00000000000000000000000000001000;
+	}
+}
+
+bitflags! {
+#[repr(C)]
+	pub struct VkMemoryPropertyFlagBits: u32 {
+		const _EMPTY = 0;
+		const VK_MEMORY_PROPERTY_DEVICE_LOCAL_BIT = 0b00000000000000000000000000000001;
+		const VK_MEMORY_PROPERTY_HOST_VISIBLE_BIT = 0b00000000000000000000000000000010;
+		const VK_MEMORY_PROPERTY_HOST_COHERENT_BIT = 0b00000000000000000000000000000100;
+		const VK_MEMORY_PROPERTY_HOST_CACHED_BIT = 0b00000000000000000000000000001000;
+	}
+}
+
+bitflags! {
+#[repr(C)]
+	pub struct VkMemoryHeapFlagBits: u32 {
+		const _EMPTY = 0;
+		const VK_MEMORY_HEAP_DEVICE_LOCAL_BIT = 0b00000000000000000000000000000001;
+	}
+}
+
+bitflags! {
+#[repr(C)]
+	pub struct VkBufferUsageFlagBits: u32 {
+		const _EMPTY = 0;
+		const VK_BUFFER_USAGE_TRANSFER_SRC_BIT = 0b00000000000000000000000000000001;
+		const VK_BUFFER_USAGE_TRANSFER_DST_BIT = 0b00000000000000000000000000000010;
+		const VK_BUFFER_USAGE_UNIFORM_BUFFER_BIT = 0b00000000000000000000000000010000;
+		const VK_BUFFER_USAGE_VERTEX_BUFFER_BIT = 0b00000000000000000000000010000000;
+	}
+}
+
+bitflags! {
+#[repr(C)]
+	pub struct VkBufferCreateFlagBits: u32 {
+		const _EMPTY = 0;
+		const VK_BUFFER_CREATE_SPARSE_BINDING_BIT = 0b00000000000000000000000000000001;
+	}
+}
+
+bitflags! {
+#[repr(C)]
+	pub struct VkSampleCountFlagBits: u32 {
+		const _EMPTY = 0;
+		const VK_SAMPLE_COUNT_1_BIT = 0b00000000000000000000000000000001;
+		const VK_SAMPLE_COUNT_2_BIT = 0b00000000000000000000000000000010;
+		const VK_SAMPLE_COUNT_4_BIT = 0b00000000000000000000000000000100;
+	}
+}
+
+bitflags! {
+#[repr(C)]
+	pub struct VkImageAspectFlagBits: u32 {
+		const _EMPTY = 0;
+		const VK_IMAGE_ASPECT_COLOR_BIT = 0b00000000000000000000000000000001;
+		const VK_IMAGE_ASPECT_DEPTH_BIT = 0b00000000000000000000000000000010;
+		const VK_IMAGE_ASPECT_STENCIL_BIT = 0b00000000000000000000000000000100;
+		const VK_IMAGE_ASPECT_METADATA_BIT = 0b00000000000000000000000000001000;
+	}
+}
+
+bitflags! {
+#[repr(C)]
+	pub struct VkDeviceQueueCreateFlagBits: u32 {
+		const _EMPTY = 0;
+	}
+}
+
+bitflags! {
+#[repr(C)]
+	pub struct VkCommandPoolCreateFlagBits: u32 {
+		const _EMPTY = 0;
+		const VK_COMMAND_POOL_CREATE_TRANSIENT_BIT = 0b00000000000000000000000000000001;
+		const VK_COMMAND_POOL_CREATE_RESET_COMMAND_BUFFER_BIT = 0b00000000000000000000000000000010;
+	}
+}
+
+bitflags! {
+#[repr(C)]
+	pub struct VkFenceCreateFlagBits: u32 {
+		const _EMPTY = 0;
+		const VK_FENCE_CREATE_SIGNALED_BIT = 0b00000000000000000000000000000001;
+	}
+}
+
+bitflags! {
+#[repr(C)]
+	pub struct VkImageUsageFlagBits: u32 {
+		const _EMPTY = 0;
+		const VK_IMAGE_USAGE_TRANSFER_SRC_BIT = 0b00000000000000000000000000000001;
+		const VK_IMAGE_USAGE_TRANSFER_DST_BIT = 0b00000000000000000000000000000010;
+		const VK_IMAGE_USAGE_COLOR_ATTACHMENT_BIT = 0b00000000000000000000000000010000;
+	}
+}
+
+bitflags! {
+#[repr(C)]
+	pub struct VkSurfaceTransformFlagBitsKHR: u32 {
+		const _EMPTY = 0;
+		const VK_SURFACE_TRANSFORM_IDENTITY_BIT_KHR = 0b00000000000000000000000000000001;
+		const VK_SURFACE_TRANSFORM_ROTATE_90_BIT_KHR = 0b00000000000000000000000000000010;
+	}
+}
+
+bitflags! {
+#[repr(C)]
+	pub struct VkCompositeAlphaFlagBitsKHR: u32 {
+		const _EMPTY = 0;
+		const VK_COMPOSITE_ALPHA_OPAQUE_BIT_KHR = 0b00000000000000000000000000000001;
+		const VK_COMPOSITE_ALPHA_PRE_MULTIPLIED_BIT_KHR = 0b00000000000000000000000000000010;
+	}
+}
+
+bitflags! {
+#[repr(C)]
+	pub struct VkSwapchainCreateFlagBitsKHR: u32 {
+		const _EMPTY = 0;
+	}
+}
+
+bitflags! {
+#[repr(C)]
+	pub struct VkDebugReportFlagBitsEXT: u32 {
+		const _EMPTY = 0;
+		const VK_DEBUG_REPORT_INFORMATION_BIT_EXT = 0b00000000000000000000000000000001;
+		const VK_DEBUG_REPORT_WARNING_BIT_EXT = 0b00000000000000000000000000000010;
+		const VK_DEBUG_REPORT_PERFORMANCE_WARNING_BIT_EXT = 0b00000000000000000000000000000100;
+		const VK_DEBUG_REPORT_ERROR_BIT_EXT = 0b00000000000000000000000000001000;
+		const VK_DEBUG_REPORT_DEBUG_BIT_EXT = 0b00000000000000000000000000010000;
+	}
+}
+
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkBaseOutStructure {
+	pub sType:  VkStructureType,
+	pub pNext: *mut VkBaseOutStructure,
+
+}
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkBaseOutStructure>() == 16);
+	assert!(::core::mem::align_of::<VkBaseOutStructure>() == 8);
+	assert!(::core::mem::offset_of!(VkBaseOutStructure, sType) == 0);
+	assert!(::core::mem::offset_of!(VkBaseOutStructure, pNext) == 8);
+};
+#[cfg(any(all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkBaseOutStructure>() == 8);
+	assert!(::core::mem::align_of::<VkBaseOutStructure>() == 4);
+	assert!(::core::mem::offset_of!(VkBaseOutStructure, sType) == 0);
+	assert!(::core::mem::offset_of!(VkBaseOutStructure, pNext) == 4);
+};
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkBaseInStructure {
+	pub sType:  VkStructureType,
+	pub pNext: *const VkBaseInStructure,
+
+}
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkBaseInStructure>() == 16);
+	assert!(::core::mem::align_of::<VkBaseInStructure>() == 8);
+	assert!(::core::mem::offset_of!(VkBaseInStructure, sType) == 0);
+	assert!(::core::mem::offset_of!(VkBaseInStructure, pNext) == 8);
+};
+#[cfg(any(all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkBaseInStructure>() == 8);
+	assert!(::core::mem::align_of::<VkBaseInStructure>() == 4);
+	assert!(::core::mem::offset_of!(VkBaseInStructure, sType) == 0);
+	assert!(::core::mem::offset_of!(VkBaseInStructure, pNext) == 4);
+};
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkExtent2D {
+	pub width:  u32,
+	pub height:  u32,
+
+}
+#[cfg(any(target_pointer_width = "64", all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkExtent2D>() == 8);
+	assert!(::core::mem::align_of::<VkExtent2D>() == 4);
+	assert!(::core::mem::offset_of!(VkExtent2D, width) == 0);
+	assert!(::core::mem::offset_of!(VkExtent2D, height) == 4);
+};
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkExtent3D {
+	pub width:  u32,
+	pub height:  u32,
+	pub depth:  u32,
+
+}
+#[cfg(any(target_pointer_width = "64", all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkExtent3D>() == 12);
+	assert!(::core::mem::align_of::<VkExtent3D>() == 4);
+	assert!(::core::mem::offset_of!(VkExtent3D, width) == 0);
+	assert!(::core::mem::offset_of!(VkExtent3D, height) == 4);
+	assert!(::core::mem::offset_of!(VkExtent3D, depth) == 8);
+};
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VkPhysicalDeviceProperties {
+	pub apiVersion:  u32,
+	pub driverVersion:  u32,
+	pub vendorID:  u32,
+	pub deviceID:  u32,
+	pub deviceType:  VkPhysicalDeviceType,
+	pub deviceName: [ u8; VK_MAX_PHYSICAL_DEVICE_NAME_SIZE],
+	pub pipelineCacheUUID: [ u8; VK_UUID_SIZE],
+	pub limits:  VkPhysicalDeviceLimits,
+	pub sparseProperties:  VkPhysicalDeviceSparseProperties,
+
+}
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkPhysicalDeviceProperties>() == 368);
+	assert!(::core::mem::align_of::<VkPhysicalDeviceProperties>() == 8);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, apiVersion) == 0);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, driverVersion) == 4);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, vendorID) == 8);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, deviceID) == 12);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, deviceType) == 16);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, deviceName) == 20);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, pipelineCacheUUID) == 276);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, limits) == 296);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, sparseProperties) == 360);
+};
+#[cfg(all(target_pointer_width = "32", not(target_arch = "x86")))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkPhysicalDeviceProperties>() == 360);
+	assert!(::core::mem::align_of::<VkPhysicalDeviceProperties>() == 8);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, apiVersion) == 0);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, driverVersion) == 4);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, vendorID) == 8);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, deviceID) == 12);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, deviceType) == 16);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, deviceName) == 20);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, pipelineCacheUUID) == 276);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, limits) == 296);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, sparseProperties) == 352);
+};
+#[cfg(target_arch = "x86")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkPhysicalDeviceProperties>() == 356);
+	assert!(::core::mem::align_of::<VkPhysicalDeviceProperties>() == 4);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, apiVersion) == 0);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, driverVersion) == 4);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, vendorID) == 8);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, deviceID) == 12);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, deviceType) == 16);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, deviceName) == 20);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, pipelineCacheUUID) == 276);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, limits) == 292);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceProperties, sparseProperties) == 348);
+};
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VkExtensionProperties {
+	pub extensionName: [ u8; VK_MAX_EXTENSION_NAME_SIZE],
+	pub specVersion:  u32,
+
+}
+#[cfg(any(target_pointer_width = "64", all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkExtensionProperties>() == 260);
+	assert!(::core::mem::align_of::<VkExtensionProperties>() == 4);
+	assert!(::core::mem::offset_of!(VkExtensionProperties, extensionName) == 0);
+	assert!(::core::mem::offset_of!(VkExtensionProperties, specVersion) == 256);
+};
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VkLayerProperties {
+	pub layerName: [ u8; VK_MAX_EXTENSION_NAME_SIZE],
+	pub specVersion:  u32,
+	pub implementationVersion:  u32,
+	pub description: [ u8; VK_MAX_DESCRIPTION_SIZE],
+
+}
+#[cfg(any(target_pointer_width = "64", all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkLayerProperties>() == 520);
+	assert!(::core::mem::align_of::<VkLayerProperties>() == 4);
+	assert!(::core::mem::offset_of!(VkLayerProperties, layerName) == 0);
+	assert!(::core::mem::offset_of!(VkLayerProperties, specVersion) == 256);
+	assert!(::core::mem::offset_of!(VkLayerProperties, implementationVersion) == 260);
+	assert!(::core::mem::offset_of!(VkLayerProperties, description) == 264);
+};
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkApplicationInfo {
+	pub sType:  VkStructureType,
+	pub pNext: *const c_void,
+	pub pApplicationName: *const u8,
+	pub applicationVersion:  u32,
+	pub pEngineName: *const u8,
+	pub engineVersion:  u32,
+	pub apiVersion:  u32,
+
+}
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkApplicationInfo>() == 48);
+	assert!(::core::mem::align_of::<VkApplicationInfo>() == 8);
+	assert!(::core::mem::offset_of!(VkApplicationInfo, sType) == 0);
+	assert!(::core::mem::offset_of!(VkApplicationInfo, pNext) == 8);
+	assert!(::core::mem::offset_of!(VkApplicationInfo, pApplicationName) == 16);
+	assert!(::core::mem::offset_of!(VkApplicationInfo, applicationVersion) == 24);
+	assert!(::core::mem::offset_of!(VkApplicationInfo, pEngineName) == 32);
+	assert!(::core::mem::offset_of!(VkApplicationInfo, engineVersion) == 40);
+	assert!(::core::mem::offset_of!(VkApplicationInfo, apiVersion) == 44);
+};
+#[cfg(any(all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkApplicationInfo>() == 28);
+	assert!(::core::mem::align_of::<VkApplicationInfo>() == 4);
+	assert!(::core::mem::offset_of!(VkApplicationInfo, sType) == 0);
+	assert!(::core::mem::offset_of!(VkApplicationInfo, pNext) == 4);
+	assert!(::core::mem::offset_of!(VkApplicationInfo, pApplicationName) == 8);
+	assert!(::core::mem::offset_of!(VkApplicationInfo, applicationVersion) == 12);
+	assert!(::core::mem::offset_of!(VkApplicationInfo, pEngineName) == 16);
+	assert!(::core::mem::offset_of!(VkApplicationInfo, engineVersion) == 20);
+	assert!(::core::mem::offset_of!(VkApplicationInfo, apiVersion) == 24);
+};
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkAllocationCallbacks {
+	pub pUserData: *mut c_void,
+	pub pfnAllocation:  PFN_vkAllocationFunction,
+	pub pfnReallocation:  PFN_vkReallocationFunction,
+	pub pfnFree:  PFN_vkFreeFunction,
+	pub pfnInternalAllocation:  PFN_vkInternalAllocationNotification,
+	pub pfnInternalFree:  PFN_vkInternalFreeNotification,
+
+}
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkAllocationCallbacks>() == 48);
+	assert!(::core::mem::align_of::<VkAllocationCallbacks>() == 8);
+	assert!(::core::mem::offset_of!(VkAllocationCallbacks, pUserData) == 0);
+	assert!(::core::mem::offset_of!(VkAllocationCallbacks, pfnAllocation) == 8);
+	assert!(::core::mem::offset_of!(VkAllocationCallbacks, pfnReallocation) == 16);
+	assert!(::core::mem::offset_of!(VkAllocationCallbacks, pfnFree) == 24);
+	assert!(::core::mem::offset_of!(VkAllocationCallbacks, pfnInternalAllocation) == 32);
+	assert!(::core::mem::offset_of!(VkAllocationCallbacks, pfnInternalFree) == 40);
+};
+#[cfg(any(all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkAllocationCallbacks>() == 24);
+	assert!(::core::mem::align_of::<VkAllocationCallbacks>() == 4);
+	assert!(::core::mem::offset_of!(VkAllocationCallbacks, pUserData) == 0);
+	assert!(::core::mem::offset_of!(VkAllocationCallbacks, pfnAllocation) == 4);
+	assert!(::core::mem::offset_of!(VkAllocationCallbacks, pfnReallocation) == 8);
+	assert!(::core::mem::offset_of!(VkAllocationCallbacks, pfnFree) == 12);
+	assert!(::core::mem::offset_of!(VkAllocationCallbacks, pfnInternalAllocation) == 16);
+	assert!(::core::mem::offset_of!(VkAllocationCallbacks, pfnInternalFree) == 20);
+};
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkDeviceQueueCreateInfo {
+	pub sType:  VkStructureType,
+	pub pNext: *const c_void,
+	pub flags:  VkDeviceQueueCreateFlags,
+	pub queueFamilyIndex:  u32,
+	pub queueCount:  u32,
+	pub pQueuePriorities: *const f32,
+
+}
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkDeviceQueueCreateInfo>() == 40);
+	assert!(::core::mem::align_of::<VkDeviceQueueCreateInfo>() == 8);
+	assert!(::core::mem::offset_of!(VkDeviceQueueCreateInfo, sType) == 0);
+	assert!(::core::mem::offset_of!(VkDeviceQueueCreateInfo, pNext) == 8);
+	assert!(::core::mem::offset_of!(VkDeviceQueueCreateInfo, flags) == 16);
+	assert!(::core::mem::offset_of!(VkDeviceQueueCreateInfo, queueFamilyIndex) == 20);
+	assert!(::core::mem::offset_of!(VkDeviceQueueCreateInfo, queueCount) == 24);
+	assert!(::core::mem::offset_of!(VkDeviceQueueCreateInfo, pQueuePriorities) == 32);
+};
+#[cfg(any(all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkDeviceQueueCreateInfo>() == 24);
+	assert!(::core::mem::align_of::<VkDeviceQueueCreateInfo>() == 4);
+	assert!(::core::mem::offset_of!(VkDeviceQueueCreateInfo, sType) == 0);
+	assert!(::core::mem::offset_of!(VkDeviceQueueCreateInfo, pNext) == 4);
+	assert!(::core::mem::offset_of!(VkDeviceQueueCreateInfo, flags) == 8);
+	assert!(::core::mem::offset_of!(VkDeviceQueueCreateInfo, queueFamilyIndex) == 12);
+	assert!(::core::mem::offset_of!(VkDeviceQueueCreateInfo, queueCount) == 16);
+	assert!(::core::mem::offset_of!(VkDeviceQueueCreateInfo, pQueuePriorities) == 20);
+};
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkDeviceCreateInfo {
+	pub sType:  VkStructureType,
+	pub pNext: *const c_void,
+	pub flags:  VkDeviceCreateFlags,
+	pub queueCreateInfoCount:  u32,
+	pub pQueueCreateInfos: *const VkDeviceQueueCreateInfo,
+	pub enabledLayerCount:  u32,
+	pub ppEnabledLayerNames: *const*const u8,
+	pub enabledExtensionCount:  u32,
+	pub ppEnabledExtensionNames: *const*const u8,
+	pub pEnabledFeatures: *const VkPhysicalDeviceFeatures,
+
+}
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkDeviceCreateInfo>() == 72);
+	assert!(::core::mem::align_of::<VkDeviceCreateInfo>() == 8);
+	assert!(::core::mem::offset_of!(VkDeviceCreateInfo, sType) == 0);
+	assert!(::core::mem::offset_of!(VkDeviceCreateInfo, pNext) == 8);
+	assert!(::core::mem::offset_of!(VkDeviceCreateInfo, flags) == 16);
+	assert!(::core::mem::offset_of!(VkDeviceCreateInfo, queueCreateInfoCount) == 20);
+	assert!(::core::mem::offset_of!(VkDeviceCreateInfo, pQueueCreateInfos) == 24);
+	assert!(::core::mem::offset_of!(VkDeviceCreateInfo, enabledLayerCount) == 32);
+	assert!(::core::mem::offset_of!(VkDeviceCreateInfo, ppEnabledLayerNames) == 40);
+	assert!(::core::mem::offset_of!(VkDeviceCreateInfo, enabledExtensionCount) == 48);
+	assert!(::core::mem::offset_of!(VkDeviceCreateInfo, ppEnabledExtensionNames) == 56);
+	assert!(::core::mem::offset_of!(VkDeviceCreateInfo, pEnabledFeatures) == 64);
+};
+#[cfg(any(all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkDeviceCreateInfo>() == 40);
+	assert!(::core::mem::align_of::<VkDeviceCreateInfo>() == 4);
+	assert!(::core::mem::offset_of!(VkDeviceCreateInfo, sType) == 0);
+	assert!(::core::mem::offset_of!(VkDeviceCreateInfo, pNext) == 4);
+	assert!(::core::mem::offset_of!(VkDeviceCreateInfo, flags) == 8);
+	assert!(::core::mem::offset_of!(VkDeviceCreateInfo, queueCreateInfoCount) == 12);
+	assert!(::core::mem::offset_of!(VkDeviceCreateInfo, pQueueCreateInfos) == 16);
+	assert!(::core::mem::offset_of!(VkDeviceCreateInfo, enabledLayerCount) == 20);
+	assert!(::core::mem::offset_of!(VkDeviceCreateInfo, ppEnabledLayerNames) == 24);
+	assert!(::core::mem::offset_of!(VkDeviceCreateInfo, enabledExtensionCount) == 28);
+	assert!(::core::mem::offset_of!(VkDeviceCreateInfo, ppEnabledExtensionNames) == 32);
+	assert!(::core::mem::offset_of!(VkDeviceCreateInfo, pEnabledFeatures) == 36);
+};
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkInstanceCreateInfo {
+	pub sType:  VkStructureType,
+	pub pNext: *const c_void,
+	pub flags:  VkInstanceCreateFlags,
+	pub pApplicationInfo: *const VkApplicationInfo,
+	pub enabledLayerCount:  u32,
+	pub ppEnabledLayerNames: *const*const u8,
+	pub enabledExtensionCount:  u32,
+	pub ppEnabledExtensionNames: *const*const u8,
+
+}
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkInstanceCreateInfo>() == 64);
+	assert!(::core::mem::align_of::<VkInstanceCreateInfo>() == 8);
+	assert!(::core::mem::offset_of!(VkInstanceCreateInfo, sType) == 0);
+	assert!(::core::mem::offset_of!(VkInstanceCreateInfo, pNext) == 8);
+	assert!(::core::mem::offset_of!(VkInstanceCreateInfo, flags) == 16);
+	assert!(::core::mem::offset_of!(VkInstanceCreateInfo, pApplicationInfo) == 24);
+	assert!(::core::mem::offset_of!(VkInstanceCreateInfo, enabledLayerCount) == 32);
+	assert!(::core::mem::offset_of!(VkInstanceCreateInfo, ppEnabledLayerNames) == 40);
+	assert!(::core::mem::offset_of!(VkInstanceCreateInfo, enabledExtensionCount) == 48);
+	assert!(::core::mem::offset_of!(VkInstanceCreateInfo, ppEnabledExtensionNames) == 56);
+};
+#[cfg(any(all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkInstanceCreateInfo>() == 32);
+	assert!(::core::mem::align_of::<VkInstanceCreateInfo>() == 4);
+	assert!(::core::mem::offset_of!(VkInstanceCreateInfo, sType) == 0);
+	assert!(::core::mem::offset_of!(VkInstanceCreateInfo, pNext) == 4);
+	assert!(::core::mem::offset_of!(VkInstanceCreateInfo, flags) == 8);
+	assert!(::core::mem::offset_of!(VkInstanceCreateInfo, pApplicationInfo) == 12);
+	assert!(::core::mem::offset_of!(VkInstanceCreateInfo, enabledLayerCount) == 16);
+	assert!(::core::mem::offset_of!(VkInstanceCreateInfo, ppEnabledLayerNames) == 20);
+	assert!(::core::mem::offset_of!(VkInstanceCreateInfo, enabledExtensionCount) == 24);
+	assert!(::core::mem::offset_of!(VkInstanceCreateInfo, ppEnabledExtensionNames) == 28);
+};
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkQueueFamilyProperties {
+	pub queueFlags:  VkQueueFlags,
+	pub queueCount:  u32,
+	pub timestampValidBits:  u32,
+	pub minImageTransferGranularity:  VkExtent3D,
+
+}
+#[cfg(any(target_pointer_width = "64", all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkQueueFamilyProperties>() == 24);
+	assert!(::core::mem::align_of::<VkQueueFamilyProperties>() == 4);
+	assert!(::core::mem::offset_of!(VkQueueFamilyProperties, queueFlags) == 0);
+	assert!(::core::mem::offset_of!(VkQueueFamilyProperties, queueCount) == 4);
+	assert!(::core::mem::offset_of!(VkQueueFamilyProperties, timestampValidBits) == 8);
+	assert!(::core::mem::offset_of!(VkQueueFamilyProperties, minImageTransferGranularity) == 12);
+};
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VkPhysicalDeviceMemoryProperties {
+	pub memoryTypeCount:  u32,
+	pub memoryTypes: [ VkMemoryType; VK_MAX_MEMORY_TYPES],
+	pub memoryHeapCount:  u32,
+	pub memoryHeaps: [ VkMemoryHeap; VK_MAX_MEMORY_HEAPS],
+
+}
+#[cfg(any(target_pointer_width = "64", all(target_pointer_width = "32", not(target_arch = "x86"))))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkPhysicalDeviceMemoryProperties>() == 520);
+	assert!(::core::mem::align_of::<VkPhysicalDeviceMemoryProperties>() == 8);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceMemoryProperties, memoryTypeCount) == 0);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceMemoryProperties, memoryTypes) == 4);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceMemoryProperties, memoryHeapCount) == 260);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceMemoryProperties, memoryHeaps) == 264);
+};
+#[cfg(target_arch = "x86")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkPhysicalDeviceMemoryProperties>() == 456);
+	assert!(::core::mem::align_of::<VkPhysicalDeviceMemoryProperties>() == 4);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceMemoryProperties, memoryTypeCount) == 0);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceMemoryProperties, memoryTypes) == 4);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceMemoryProperties, memoryHeapCount) == 260);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceMemoryProperties, memoryHeaps) == 264);
+};
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkMemoryAllocateInfo {
+	pub sType:  VkStructureType,
+	pub pNext: *const c_void,
+	pub allocationSize:  VkDeviceSize,
+	pub memoryTypeIndex:  u32,
+
+}
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkMemoryAllocateInfo>() == 32);
+	assert!(::core::mem::align_of::<VkMemoryAllocateInfo>() == 8);
+	assert!(::core::mem::offset_of!(VkMemoryAllocateInfo, sType) == 0);
+	assert!(::core::mem::offset_of!(VkMemoryAllocateInfo, pNext) == 8);
+	assert!(::core::mem::offset_of!(VkMemoryAllocateInfo, allocationSize) == 16);
+	assert!(::core::mem::offset_of!(VkMemoryAllocateInfo, memoryTypeIndex) == 24);
+};
+#[cfg(all(target_pointer_width = "32", not(target_arch = "x86")))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkMemoryAllocateInfo>() == 24);
+	assert!(::core::mem::align_of::<VkMemoryAllocateInfo>() == 8);
+	assert!(::core::mem::offset_of!(VkMemoryAllocateInfo, sType) == 0);
+	assert!(::core::mem::offset_of!(VkMemoryAllocateInfo, pNext) == 4);
+	assert!(::core::mem::offset_of!(VkMemoryAllocateInfo, allocationSize) == 8);
+	assert!(::core::mem::offset_of!(VkMemoryAllocateInfo, memoryTypeIndex) == 16);
+};
+#[cfg(target_arch = "x86")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkMemoryAllocateInfo>() == 20);
+	assert!(::core::mem::align_of::<VkMemoryAllocateInfo>() == 4);
+	assert!(::core::mem::offset_of!(VkMemoryAllocateInfo, sType) == 0);
+	assert!(::core::mem::offset_of!(VkMemoryAllocateInfo, pNext) == 4);
+	assert!(::core::mem::offset_of!(VkMemoryAllocateInfo, allocationSize) == 8);
+	assert!(::core::mem::offset_of!(VkMemoryAllocateInfo, memoryTypeIndex) == 16);
+};
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkMemoryType {
+	pub propertyFlags:  VkMemoryPropertyFlags,
+	pub heapIndex:  u32,
+
+}
+#[cfg(any(target_pointer_width = "64", all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkMemoryType>() == 8);
+	assert!(::core::mem::align_of::<VkMemoryType>() == 4);
+	assert!(::core::mem::offset_of!(VkMemoryType, propertyFlags) == 0);
+	assert!(::core::mem::offset_of!(VkMemoryType, heapIndex) == 4);
+};
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkMemoryHeap {
+	pub size:  VkDeviceSize,
+	pub flags:  VkMemoryHeapFlags,
+
+}
+#[cfg(any(target_pointer_width = "64", all(target_pointer_width = "32", not(target_arch = "x86"))))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkMemoryHeap>() == 16);
+	assert!(::core::mem::align_of::<VkMemoryHeap>() == 8);
+	assert!(::core::mem::offset_of!(VkMemoryHeap, size) == 0);
+	assert!(::core::mem::offset_of!(VkMemoryHeap, flags) == 8);
+};
+#[cfg(target_arch = "x86")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkMemoryHeap>() == 12);
+	assert!(::core::mem::align_of::<VkMemoryHeap>() == 4);
+	assert!(::core::mem::offset_of!(VkMemoryHeap, size) == 0);
+	assert!(::core::mem::offset_of!(VkMemoryHeap, flags) == 8);
+};
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkBufferCreateInfo {
+	pub sType:  VkStructureType,
+	pub pNext: *const c_void,
+	pub flags:  VkBufferCreateFlags,
+	pub size:  VkDeviceSize,
+	pub usage:  VkBufferUsageFlags,
+	pub sharingMode:  VkSharingMode,
+	pub queueFamilyIndexCount:  u32,
+	pub pQueueFamilyIndices: *const u32,
+
+}
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkBufferCreateInfo>() == 56);
+	assert!(::core::mem::align_of::<VkBufferCreateInfo>() == 8);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, sType) == 0);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, pNext) == 8);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, flags) == 16);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, size) == 24);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, usage) == 32);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, sharingMode) == 36);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, queueFamilyIndexCount) == 40);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, pQueueFamilyIndices) == 48);
+};
+#[cfg(all(target_pointer_width = "32", not(target_arch = "x86")))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkBufferCreateInfo>() == 40);
+	assert!(::core::mem::align_of::<VkBufferCreateInfo>() == 8);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, sType) == 0);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, pNext) == 4);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, flags) == 8);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, size) == 16);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, usage) == 24);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, sharingMode) == 28);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, queueFamilyIndexCount) == 32);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, pQueueFamilyIndices) == 36);
+};
+#[cfg(target_arch = "x86")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkBufferCreateInfo>() == 36);
+	assert!(::core::mem::align_of::<VkBufferCreateInfo>() == 4);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, sType) == 0);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, pNext) == 4);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, flags) == 8);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, size) == 12);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, usage) == 20);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, sharingMode) == 24);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, queueFamilyIndexCount) == 28);
+	assert!(::core::mem::offset_of!(VkBufferCreateInfo, pQueueFamilyIndices) == 32);
+};
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkCommandPoolCreateInfo {
+	pub sType:  VkStructureType,
+	pub pNext: *const c_void,
+	pub flags:  VkCommandPoolCreateFlags,
+	pub queueFamilyIndex:  u32,
+
+}
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkCommandPoolCreateInfo>() == 24);
+	assert!(::core::mem::align_of::<VkCommandPoolCreateInfo>() == 8);
+	assert!(::core::mem::offset_of!(VkCommandPoolCreateInfo, sType) == 0);
+	assert!(::core::mem::offset_of!(VkCommandPoolCreateInfo, pNext) == 8);
+	assert!(::core::mem::offset_of!(VkCommandPoolCreateInfo, flags) == 16);
+	assert!(::core::mem::offset_of!(VkCommandPoolCreateInfo, queueFamilyIndex) == 20);
+};
+#[cfg(any(all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkCommandPoolCreateInfo>() == 16);
+	assert!(::core::mem::align_of::<VkCommandPoolCreateInfo>() == 4);
+	assert!(::core::mem::offset_of!(VkCommandPoolCreateInfo, sType) == 0);
+	assert!(::core::mem::offset_of!(VkCommandPoolCreateInfo, pNext) == 4);
+	assert!(::core::mem::offset_of!(VkCommandPoolCreateInfo, flags) == 8);
+	assert!(::core::mem::offset_of!(VkCommandPoolCreateInfo, queueFamilyIndex) == 12);
+};
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkFenceCreateInfo {
+	pub sType:  VkStructureType,
+	pub pNext: *const c_void,
+	pub flags:  VkFenceCreateFlags,
+
+}
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkFenceCreateInfo>() == 24);
+	assert!(::core::mem::align_of::<VkFenceCreateInfo>() == 8);
+	assert!(::core::mem::offset_of!(VkFenceCreateInfo, sType) == 0);
+	assert!(::core::mem::offset_of!(VkFenceCreateInfo, pNext) == 8);
+	assert!(::core::mem::offset_of!(VkFenceCreateInfo, flags) == 16);
+};
+#[cfg(any(all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkFenceCreateInfo>() == 12);
+	assert!(::core::mem::align_of::<VkFenceCreateInfo>() == 4);
+	assert!(::core::mem::offset_of!(VkFenceCreateInfo, sType) == 0);
+	assert!(::core::mem::offset_of!(VkFenceCreateInfo, pNext) == 4);
+	assert!(::core::mem::offset_of!(VkFenceCreateInfo, flags) == 8);
+};
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkPhysicalDeviceFeatures {
+	pub robustBufferAccess:  VkBool32,
+	pub fullDrawIndexUint32:  VkBool32,
+	pub geometryShader:  VkBool32,
+	pub shaderFloat64:  VkBool32,
+
+}
+#[cfg(any(target_pointer_width = "64", all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkPhysicalDeviceFeatures>() == 16);
+	assert!(::core::mem::align_of::<VkPhysicalDeviceFeatures>() == 4);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceFeatures, robustBufferAccess) == 0);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceFeatures, fullDrawIndexUint32) == 4);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceFeatures, geometryShader) == 8);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceFeatures, shaderFloat64) == 12);
+};
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VkPhysicalDeviceLimits {
+	pub maxImageDimension1D:  u32,
+	pub maxImageDimension2D:  u32,
+	pub maxComputeWorkGroupCount: [ u32; 3],
+	pub minMemoryMapAlignment:  usize,
+	pub minTexelBufferOffsetAlignment:  VkDeviceSize,
+	pub pointSizeRange: [ f32; 2],
+	pub framebufferColorSampleCounts:  VkSampleCountFlags,
+	pub strictLines:  VkBool32,
+	pub nonCoherentAtomSize:  VkDeviceSize,
+
+}
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkPhysicalDeviceLimits>() == 64);
+	assert!(::core::mem::align_of::<VkPhysicalDeviceLimits>() == 8);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, maxImageDimension1D) == 0);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, maxImageDimension2D) == 4);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, maxComputeWorkGroupCount) == 8);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, minMemoryMapAlignment) == 24);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, minTexelBufferOffsetAlignment) == 32);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, pointSizeRange) == 40);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, framebufferColorSampleCounts) == 48);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, strictLines) == 52);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, nonCoherentAtomSize) == 56);
+};
+#[cfg(all(target_pointer_width = "32", not(target_arch = "x86")))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkPhysicalDeviceLimits>() == 56);
+	assert!(::core::mem::align_of::<VkPhysicalDeviceLimits>() == 8);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, maxImageDimension1D) == 0);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, maxImageDimension2D) == 4);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, maxComputeWorkGroupCount) == 8);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, minMemoryMapAlignment) == 20);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, minTexelBufferOffsetAlignment) == 24);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, pointSizeRange) == 32);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, framebufferColorSampleCounts) == 40);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, strictLines) == 44);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, nonCoherentAtomSize) == 48);
+};
+#[cfg(target_arch = "x86")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkPhysicalDeviceLimits>() == 56);
+	assert!(::core::mem::align_of::<VkPhysicalDeviceLimits>() == 4);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, maxImageDimension1D) == 0);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, maxImageDimension2D) == 4);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, maxComputeWorkGroupCount) == 8);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, minMemoryMapAlignment) == 20);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, minTexelBufferOffsetAlignment) == 24);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, pointSizeRange) == 32);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, framebufferColorSampleCounts) == 40);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, strictLines) == 44);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceLimits, nonCoherentAtomSize) == 48);
+};
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkPhysicalDeviceSparseProperties {
+	pub residencyStandard2DBlockShape:  VkBool32,
+	pub residencyNonResidentStrict:  VkBool32,
+
+}
+#[cfg(any(target_pointer_width = "64", all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkPhysicalDeviceSparseProperties>() == 8);
+	assert!(::core::mem::align_of::<VkPhysicalDeviceSparseProperties>() == 4);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceSparseProperties, residencyStandard2DBlockShape) == 0);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceSparseProperties, residencyNonResidentStrict) == 4);
+};
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkClearDepthStencilValue {
+	pub depth:  f32,
+	pub stencil:  u32,
+
+}
+#[cfg(any(target_pointer_width = "64", all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkClearDepthStencilValue>() == 8);
+	assert!(::core::mem::align_of::<VkClearDepthStencilValue>() == 4);
+	assert!(::core::mem::offset_of!(VkClearDepthStencilValue, depth) == 0);
+	assert!(::core::mem::offset_of!(VkClearDepthStencilValue, stencil) == 4);
+};
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VkTransformMatrixNV {
+	pub matrix: [[ f32; 4]; 3],
+
+}
+#[cfg(any(target_pointer_width = "64", all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkTransformMatrixNV>() == 48);
+	assert!(::core::mem::align_of::<VkTransformMatrixNV>() == 4);
+	assert!(::core::mem::offset_of!(VkTransformMatrixNV, matrix) == 0);
+};
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkPhysicalDeviceFeatures2 {
+	pub sType:  VkStructureType,
+	pub pNext: *mut c_void,
+	pub features:  VkPhysicalDeviceFeatures,
+
+}
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkPhysicalDeviceFeatures2>() == 32);
+	assert!(::core::mem::align_of::<VkPhysicalDeviceFeatures2>() == 8);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceFeatures2, sType) == 0);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceFeatures2, pNext) == 8);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceFeatures2, features) == 16);
+};
+#[cfg(any(all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkPhysicalDeviceFeatures2>() == 24);
+	assert!(::core::mem::align_of::<VkPhysicalDeviceFeatures2>() == 4);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceFeatures2, sType) == 0);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceFeatures2, pNext) == 4);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceFeatures2, features) == 8);
+};
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct VkPhysicalDeviceGroupProperties {
+	pub sType:  VkStructureType,
+	pub pNext: *mut c_void,
+	pub physicalDeviceCount:  u32,
+	pub physicalDevices: [ VkPhysicalDevice; VK_MAX_DEVICE_GROUP_SIZE],
+	pub subsetAllocation:  VkBool32,
+
+}
+// The layout of VkPhysicalDeviceGroupProperties is not checked on all(target_pointer_width = "32", not(target_arch = "x86")) or target_arch = "x86", VkPhysicalDeviceGroupProperties.physicalDevices is a dispatchable handle, a pointer in C
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkPhysicalDeviceGroupProperties>() == 288);
+	assert!(::core::mem::align_of::<VkPhysicalDeviceGroupProperties>() == 8);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceGroupProperties, sType) == 0);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceGroupProperties, pNext) == 8);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceGroupProperties, physicalDeviceCount) == 16);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceGroupProperties, physicalDevices) == 24);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceGroupProperties, subsetAllocation) == 280);
+};
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkQueueFamilyProperties2 {
+	pub sType:  VkStructureType,
+	pub pNext: *mut c_void,
+	pub queueFamilyProperties:  VkQueueFamilyProperties,
+
+}
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkQueueFamilyProperties2>() == 40);
+	assert!(::core::mem::align_of::<VkQueueFamilyProperties2>() == 8);
+	assert!(::core::mem::offset_of!(VkQueueFamilyProperties2, sType) == 0);
+	assert!(::core::mem::offset_of!(VkQueueFamilyProperties2, pNext) == 8);
+	assert!(::core::mem::offset_of!(VkQueueFamilyProperties2, queueFamilyProperties) == 16);
+};
+#[cfg(any(all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkQueueFamilyProperties2>() == 32);
+	assert!(::core::mem::align_of::<VkQueueFamilyProperties2>() == 4);
+	assert!(::core::mem::offset_of!(VkQueueFamilyProperties2, sType) == 0);
+	assert!(::core::mem::offset_of!(VkQueueFamilyProperties2, pNext) == 4);
+	assert!(::core::mem::offset_of!(VkQueueFamilyProperties2, queueFamilyProperties) == 8);
+};
+
+// VK_KHR_surface
+#[cfg(feature = "VK_KHR_surface")]
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkSurfaceCapabilitiesKHR {
+	pub minImageCount:  u32,
+	pub maxImageCount:  u32,
+	pub currentExtent:  VkExtent2D,
+	pub supportedTransforms:  VkSurfaceTransformFlagsKHR,
+	pub supportedCompositeAlpha:  VkCompositeAlphaFlagsKHR,
+	pub supportedUsageFlags:  VkImageUsageFlags,
+
+}
+#[cfg(feature = "VK_KHR_surface")]
+#[cfg(any(target_pointer_width = "64", all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkSurfaceCapabilitiesKHR>() == 28);
+	assert!(::core::mem::align_of::<VkSurfaceCapabilitiesKHR>() == 4);
+	assert!(::core::mem::offset_of!(VkSurfaceCapabilitiesKHR, minImageCount) == 0);
+	assert!(::core::mem::offset_of!(VkSurfaceCapabilitiesKHR, maxImageCount) == 4);
+	assert!(::core::mem::offset_of!(VkSurfaceCapabilitiesKHR, currentExtent) == 8);
+	assert!(::core::mem::offset_of!(VkSurfaceCapabilitiesKHR, supportedTransforms) == 16);
+	assert!(::core::mem::offset_of!(VkSurfaceCapabilitiesKHR, supportedCompositeAlpha) == 20);
+	assert!(::core::mem::offset_of!(VkSurfaceCapabilitiesKHR, supportedUsageFlags) == 24);
+};
+
+// VK_KHR_surface, VK_KHR_xcb_surface
+#[cfg(any(feature = "VK_KHR_surface", feature = "VK_KHR_xcb_surface"))]
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkSurfaceFormatKHR {
+	pub format:  VkFormat,
+	pub colorSpace:  VkColorSpaceKHR,
+
+}
+#[cfg(any(feature = "VK_KHR_surface", feature = "VK_KHR_xcb_surface"))]
+#[cfg(any(target_pointer_width = "64", all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkSurfaceFormatKHR>() == 8);
+	assert!(::core::mem::align_of::<VkSurfaceFormatKHR>() == 4);
+	assert!(::core::mem::offset_of!(VkSurfaceFormatKHR, format) == 0);
+	assert!(::core::mem::offset_of!(VkSurfaceFormatKHR, colorSpace) == 4);
+};
+
+// VK_KHR_swapchain
+#[cfg(feature = "VK_KHR_swapchain")]
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkSwapchainCreateInfoKHR {
+	pub sType:  VkStructureType,
+	pub pNext: *const c_void,
+	pub flags:  VkSwapchainCreateFlagsKHR,
+	pub surface:  VkSurfaceKHR,
+	pub minImageCount:  u32,
+	pub imageFormat:  VkFormat,
+	pub imageColorSpace:  VkColorSpaceKHR,
+	pub imageExtent:  VkExtent2D,
+	pub imageSharingMode:  VkSharingMode,
+	pub presentMode:  VkPresentModeKHR,
+	pub clipped:  VkBool32,
+	pub oldSwapchain:  VkSwapchainKHR,
+
+}
+#[cfg(feature = "VK_KHR_swapchain")]
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkSwapchainCreateInfoKHR>() == 72);
+	assert!(::core::mem::align_of::<VkSwapchainCreateInfoKHR>() == 8);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, sType) == 0);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, pNext) == 8);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, flags) == 16);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, surface) == 24);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, minImageCount) == 32);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, imageFormat) == 36);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, imageColorSpace) == 40);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, imageExtent) == 44);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, imageSharingMode) == 52);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, presentMode) == 56);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, clipped) == 60);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, oldSwapchain) == 64);
+};
+#[cfg(feature = "VK_KHR_swapchain")]
+#[cfg(all(target_pointer_width = "32", not(target_arch = "x86")))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkSwapchainCreateInfoKHR>() == 64);
+	assert!(::core::mem::align_of::<VkSwapchainCreateInfoKHR>() == 8);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, sType) == 0);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, pNext) == 4);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, flags) == 8);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, surface) == 16);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, minImageCount) == 24);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, imageFormat) == 28);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, imageColorSpace) == 32);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, imageExtent) == 36);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, imageSharingMode) == 44);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, presentMode) == 48);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, clipped) == 52);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, oldSwapchain) == 56);
+};
+#[cfg(feature = "VK_KHR_swapchain")]
+#[cfg(target_arch = "x86")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkSwapchainCreateInfoKHR>() == 60);
+	assert!(::core::mem::align_of::<VkSwapchainCreateInfoKHR>() == 4);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, sType) == 0);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, pNext) == 4);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, flags) == 8);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, surface) == 12);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, minImageCount) == 20);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, imageFormat) == 24);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, imageColorSpace) == 28);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, imageExtent) == 32);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, imageSharingMode) == 40);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, presentMode) == 44);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, clipped) == 48);
+	assert!(::core::mem::offset_of!(VkSwapchainCreateInfoKHR, oldSwapchain) == 52);
+};
+
+// VK_KHR_xcb_surface
+#[cfg(feature = "VK_KHR_xcb_surface")]
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkXcbSurfaceCreateInfoKHR {
+	pub sType:  VkStructureType,
+	pub pNext: *const c_void,
+	pub flags:  VkXcbSurfaceCreateFlagsKHR,
+	pub connection: *mut xcb_connection_t,
+	pub window:  xcb_window_t,
+
+}
+#[cfg(feature = "VK_KHR_xcb_surface")]
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkXcbSurfaceCreateInfoKHR>() == 40);
+	assert!(::core::mem::align_of::<VkXcbSurfaceCreateInfoKHR>() == 8);
+	assert!(::core::mem::offset_of!(VkXcbSurfaceCreateInfoKHR, sType) == 0);
+	assert!(::core::mem::offset_of!(VkXcbSurfaceCreateInfoKHR, pNext) == 8);
+	assert!(::core::mem::offset_of!(VkXcbSurfaceCreateInfoKHR, flags) == 16);
+	assert!(::core::mem::offset_of!(VkXcbSurfaceCreateInfoKHR, connection) == 24);
+	assert!(::core::mem::offset_of!(VkXcbSurfaceCreateInfoKHR, window) == 32);
+};
+#[cfg(feature = "VK_KHR_xcb_surface")]
+#[cfg(any(all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkXcbSurfaceCreateInfoKHR>() == 20);
+	assert!(::core::mem::align_of::<VkXcbSurfaceCreateInfoKHR>() == 4);
+	assert!(::core::mem::offset_of!(VkXcbSurfaceCreateInfoKHR, sType) == 0);
+	assert!(::core::mem::offset_of!(VkXcbSurfaceCreateInfoKHR, pNext) == 4);
+	assert!(::core::mem::offset_of!(VkXcbSurfaceCreateInfoKHR, flags) == 8);
+	assert!(::core::mem::offset_of!(VkXcbSurfaceCreateInfoKHR, connection) == 12);
+	assert!(::core::mem::offset_of!(VkXcbSurfaceCreateInfoKHR, window) == 16);
+};
+
+// VK_EXT_debug_report
+#[cfg(feature = "VK_EXT_debug_report")]
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkDebugReportCallbackCreateInfoEXT {
+	pub sType:  VkStructureType,
+	pub pNext: *const c_void,
+	pub flags:  VkDebugReportFlagsEXT,
+	pub pfnCallback:  PFN_vkDebugReportCallbackEXT,
+	pub pUserData: *mut c_void,
+
+}
+#[cfg(feature = "VK_EXT_debug_report")]
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkDebugReportCallbackCreateInfoEXT>() == 40);
+	assert!(::core::mem::align_of::<VkDebugReportCallbackCreateInfoEXT>() == 8);
+	assert!(::core::mem::offset_of!(VkDebugReportCallbackCreateInfoEXT, sType) == 0);
+	assert!(::core::mem::offset_of!(VkDebugReportCallbackCreateInfoEXT, pNext) == 8);
+	assert!(::core::mem::offset_of!(VkDebugReportCallbackCreateInfoEXT, flags) == 16);
+	assert!(::core::mem::offset_of!(VkDebugReportCallbackCreateInfoEXT, pfnCallback) == 24);
+	assert!(::core::mem::offset_of!(VkDebugReportCallbackCreateInfoEXT, pUserData) == 32);
+};
+#[cfg(feature = "VK_EXT_debug_report")]
+#[cfg(any(all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkDebugReportCallbackCreateInfoEXT>() == 20);
+	assert!(::core::mem::align_of::<VkDebugReportCallbackCreateInfoEXT>() == 4);
+	assert!(::core::mem::offset_of!(VkDebugReportCallbackCreateInfoEXT, sType) == 0);
+	assert!(::core::mem::offset_of!(VkDebugReportCallbackCreateInfoEXT, pNext) == 4);
+	assert!(::core::mem::offset_of!(VkDebugReportCallbackCreateInfoEXT, flags) == 8);
+	assert!(::core::mem::offset_of!(VkDebugReportCallbackCreateInfoEXT, pfnCallback) == 12);
+	assert!(::core::mem::offset_of!(VkDebugReportCallbackCreateInfoEXT, pUserData) == 16);
+};
+
+// VK_KHR_shader_float16_int8
+#[cfg(feature = "VK_KHR_shader_float16_int8")]
+#[derive(Debug, Copy, Clone)]
+#[repr(C)]
+pub struct VkPhysicalDeviceFloat16Int8FeaturesKHR {
+	pub sType:  VkStructureType,
+	pub pNext: *mut c_void,
+	pub shaderFloat16:  VkBool32,
+	pub shaderInt8:  VkBool32,
+
+}
+#[cfg(feature = "VK_KHR_shader_float16_int8")]
+#[cfg(target_pointer_width = "64")]
+const _: () = {
+	assert!(::core::mem::size_of::<VkPhysicalDeviceFloat16Int8FeaturesKHR>() == 24);
+	assert!(::core::mem::align_of::<VkPhysicalDeviceFloat16Int8FeaturesKHR>() == 8);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceFloat16Int8FeaturesKHR, sType) == 0);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceFloat16Int8FeaturesKHR, pNext) == 8);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceFloat16Int8FeaturesKHR, shaderFloat16) == 16);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceFloat16Int8FeaturesKHR, shaderInt8) == 20);
+};
+#[cfg(feature = "VK_KHR_shader_float16_int8")]
+#[cfg(any(all(target_pointer_width = "32", not(target_arch = "x86")), target_arch = "x86"))]
+const _: () = {
+	assert!(::core::mem::size_of::<VkPhysicalDeviceFloat16Int8FeaturesKHR>() == 16);
+	assert!(::core::mem::align_of::<VkPhysicalDeviceFloat16Int8FeaturesKHR>() == 4);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceFloat16Int8FeaturesKHR, sType) == 0);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceFloat16Int8FeaturesKHR, pNext) == 4);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceFloat16Int8FeaturesKHR, shaderFloat16) == 8);
+	assert!(::core::mem::offset_of!(VkPhysicalDeviceFloat16Int8FeaturesKHR, shaderInt8) == 12);
+};
+
+// A component of a format, bits is 0 for compressed formats
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct FormatComponent {
+	// R, G, B, A, D or S
+	pub name: &'static str,
+	pub bits: u32,
+
+	// UNORM, SRGB, SFLOAT, ...
+	pub numeric_format: &'static str
+}
+
+impl VkFormat {
+
+	// Bytes per texel block, 0 for formats without a description
+	pub fn block_size(self) -> u32 {
+		match self {
+			VkFormat::VK_FORMAT_R8_UNORM => 1,
+			VkFormat::VK_FORMAT_R8G8B8A8_UNORM => 4,
+			VkFormat::VK_FORMAT_R8G8B8A8_SRGB => 4,
+			VkFormat::VK_FORMAT_B8G8R8A8_UNORM => 4,
+			VkFormat::VK_FORMAT_B8G8R8A8_SRGB => 4,
+			VkFormat::VK_FORMAT_R16G16B16A16_SFLOAT => 8,
+			VkFormat::VK_FORMAT_R32G32B32A32_SFLOAT => 16,
+			VkFormat::VK_FORMAT_D16_UNORM => 2,
+			VkFormat::VK_FORMAT_D32_SFLOAT => 4,
+			VkFormat::VK_FORMAT_S8_UINT => 1,
+			VkFormat::VK_FORMAT_D24_UNORM_S8_UINT => 4,
+			VkFormat::VK_FORMAT_D32_SFLOAT_S8_UINT => 5,
+			VkFormat::VK_FORMAT_BC1_RGB_UNORM_BLOCK => 8,
+			VkFormat::VK_FORMAT_ETC2_R8G8B8_UNORM_BLOCK => 8,
+			VkFormat::VK_FORMAT_ASTC_4x4_UNORM_BLOCK => 16,
+			VkFormat::VK_FORMAT_ASTC_8x6_UNORM_BLOCK => 16,
+#[cfg(feature = "VK_EXT_4444_formats")]
+			VkFormat::VK_FORMAT_A4R4G4B4_UNORM_PACK16_EXT => 2,
+			_ => 0
+		}
+	}
+
+	// Width, height and depth of a texel block
+	pub fn block_extent(self) -> (u32, u32, u32) {
+		match self {
+			VkFormat::VK_FORMAT_BC1_RGB_UNORM_BLOCK => (4, 4, 1),
+			VkFormat::VK_FORMAT_ETC2_R8G8B8_UNORM_BLOCK => (4, 4, 1),
+			VkFormat::VK_FORMAT_ASTC_4x4_UNORM_BLOCK => (4, 4, 1),
+			VkFormat::VK_FORMAT_ASTC_8x6_UNORM_BLOCK => (8, 6, 1),
+			_ => (1, 1, 1)
+		}
+	}
+
+	// Block compressed (BC, ETC2, ASTC, ...)
+	pub fn is_compressed(self) -> bool {
+		match self {
+			VkFormat::VK_FORMAT_BC1_RGB_UNORM_BLOCK => true,
+			VkFormat::VK_FORMAT_ETC2_R8G8B8_UNORM_BLOCK => true,
+			VkFormat::VK_FORMAT_ASTC_4x4_UNORM_BLOCK => true,
+			VkFormat::VK_FORMAT_ASTC_8x6_UNORM_BLOCK => true,
+			_ => false
+		}
+	}
+
+	// Has a depth component
+	pub fn is_depth(self) -> bool {
+		match self {
+			VkFormat::VK_FORMAT_D16_UNORM => true,
+			VkFormat::VK_FORMAT_D32_SFLOAT => true,
+			VkFormat::VK_FORMAT_D24_UNORM_S8_UINT => true,
+			VkFormat::VK_FORMAT_D32_SFLOAT_S8_UINT => true,
+			_ => false
+		}
+	}
+
+	// Has a stencil component
+	pub fn has_stencil(self) -> bool {
+		match self {
+			VkFormat::VK_FORMAT_S8_UINT => true,
+			VkFormat::VK_FORMAT_D24_UNORM_S8_UINT => true,
+			VkFormat::VK_FORMAT_D32_SFLOAT_S8_UINT => true,
+			_ => false
+		}
+	}
+
+	// Aspects of an image view of the whole format
+	pub fn aspect_mask(self) -> VkImageAspectFlags {
+		match self {
+			VkFormat::VK_FORMAT_D16_UNORM => VkImageAspectFlags::VK_IMAGE_ASPECT_DEPTH_BIT,
+			VkFormat::VK_FORMAT_D32_SFLOAT => VkImageAspectFlags::VK_IMAGE_ASPECT_DEPTH_BIT,
+			VkFormat::VK_FORMAT_S8_UINT => VkImageAspectFlags::VK_IMAGE_ASPECT_STENCIL_BIT,
+			VkFormat::VK_FORMAT_D24_UNORM_S8_UINT => VkImageAspectFlags::VK_IMAGE_ASPECT_DEPTH_BIT | VkImageAspectFlags::VK_IMAGE_ASPECT_STENCIL_BIT,
+			VkFormat::VK_FORMAT_D32_SFLOAT_S8_UINT => VkImageAspectFlags::VK_IMAGE_ASPECT_DEPTH_BIT | VkImageAspectFlags::VK_IMAGE_ASPECT_STENCIL_BIT,
+			_ => VkImageAspectFlags::VK_IMAGE_ASPECT_COLOR_BIT
+		}
+	}
+
+	// Components in memory order
+	pub fn components(self) -> &'static [FormatComponent] {
+		match self {
+			VkFormat::VK_FORMAT_R8_UNORM => &[FormatComponent { name: "R", bits: 8, numeric_format: "UNORM" }],
+			VkFormat::VK_FORMAT_R8G8B8A8_UNORM => &[FormatComponent { name: "R", bits: 8, numeric_format: "UNORM" }, FormatComponent { name: "G", bits: 8, numeric_format: "UNORM" }, FormatComponent { name: "B", bits: 8, numeric_format: "UNORM" }, FormatComponent { name: "A", bits: 8, numeric_format: "UNORM" }],
+			VkFormat::VK_FORMAT_R8G8B8A8_SRGB => &[FormatComponent { name: "R", bits: 8, numeric_format: "SRGB" }, FormatComponent { name: "G", bits: 8, numeric_format: "SRGB" }, FormatComponent { name: "B", bits: 8, numeric_format: "SRGB" }, FormatComponent { name: "A", bits: 8, numeric_format: "UNORM" }],
+			VkFormat::VK_FORMAT_B8G8R8A8_UNORM => &[FormatComponent { name: "B", bits: 8, numeric_format: "UNORM" }, FormatComponent { name: "G", bits: 8, numeric_format: "UNORM" }, FormatComponent { name: "R", bits: 8, numeric_format: "UNORM" }, FormatComponent { name: "A", bits: 8, numeric_format: "UNORM" }],
+			VkFormat::VK_FORMAT_B8G8R8A8_SRGB => &[FormatComponent { name: "B", bits: 8, numeric_format: "SRGB" }, FormatComponent { name: "G", bits: 8, numeric_format: "SRGB" }, FormatComponent { name: "R", bits: 8, numeric_format: "SRGB" }, FormatComponent { name: "A", bits: 8, numeric_format: "UNORM" }],
+			VkFormat::VK_FORMAT_R16G16B16A16_SFLOAT => &[FormatComponent { name: "R", bits: 16, numeric_format: "SFLOAT" }, FormatComponent { name: "G", bits: 16, numeric_format: "SFLOAT" }, FormatComponent { name: "B", bits: 16, numeric_format: "SFLOAT" }, FormatComponent { name: "A", bits: 16, numeric_format: "SFLOAT" }],
+			VkFormat::VK_FORMAT_R32G32B32A32_SFLOAT => &[FormatComponent { name: "R", bits: 32, numeric_format: "SFLOAT" }, FormatComponent { name: "G", bits: 32, numeric_format: "SFLOAT" }, FormatComponent { name: "B", bits: 32, numeric_format: "SFLOAT" }, FormatComponent { name: "A", bits: 32, numeric_format: "SFLOAT" }],
+			VkFormat::VK_FORMAT_D16_UNORM => &[FormatComponent { name: "D", bits: 16, numeric_format: "UNORM" }],
+			VkFormat::VK_FORMAT_D32_SFLOAT => &[FormatComponent { name: "D", bits: 32, numeric_format: "SFLOAT" }],
+			VkFormat::VK_FORMAT_S8_UINT => &[FormatComponent { name: "S", bits: 8, numeric_format: "UINT" }],
+			VkFormat::VK_FORMAT_D24_UNORM_S8_UINT => &[FormatComponent { name: "D", bits: 24, numeric_format: "UNORM" }, FormatComponent { name: "S", bits: 8, numeric_format: "UINT" }],
+			VkFormat::VK_FORMAT_D32_SFLOAT_S8_UINT => &[FormatComponent { name: "D", bits: 32, numeric_format: "SFLOAT" }, FormatComponent { name: "S", bits: 8, numeric_format: "UINT" }],
+			VkFormat::VK_FORMAT_BC1_RGB_UNORM_BLOCK => &[FormatComponent { name: "R", bits: 0, numeric_format: "UNORM" }, FormatComponent { name: "G", bits: 0, numeric_format: "UNORM" }, FormatComponent { name: "B", bits: 0, numeric_format: "UNORM" }],
+			VkFormat::VK_FORMAT_ETC2_R8G8B8_UNORM_BLOCK => &[FormatComponent { name: "R", bits: 8, numeric_format: "UNORM" }, FormatComponent { name: "G", bits: 8, numeric_format: "UNORM" }, FormatComponent { name: "B", bits: 8, numeric_format: "UNORM" }],
+			VkFormat::VK_FORMAT_ASTC_4x4_UNORM_BLOCK => &[FormatComponent { name: "R", bits: 0, numeric_format: "UNORM" }, FormatComponent { name: "G", bits: 0, numeric_format: "UNORM" }, FormatComponent { name: "B", bits: 0, numeric_format: "UNORM" }, FormatComponent { name: "A", bits: 0, numeric_format: "UNORM" }],
+			VkFormat::VK_FORMAT_ASTC_8x6_UNORM_BLOCK => &[FormatComponent { name: "R", bits: 0, numeric_format: "UNORM" }, FormatComponent { name: "G", bits: 0, numeric_format: "UNORM" }, FormatComponent { name: "B", bits: 0, numeric_format: "UNORM" }, FormatComponent { name: "A", bits: 0, numeric_format: "UNORM" }],
+#[cfg(feature = "VK_EXT_4444_formats")]
+			VkFormat::VK_FORMAT_A4R4G4B4_UNORM_PACK16_EXT => &[FormatComponent { name: "A", bits: 4, numeric_format: "UNORM" }, FormatComponent { name: "R", bits: 4, numeric_format: "UNORM" }, FormatComponent { name: "G", bits: 4, numeric_format: "UNORM" }, FormatComponent { name: "B", bits: 4, numeric_format: "UNORM" }],
+			_ => &[]
+		}
+	}
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum ExtensionType {
+	Instance,
+	Device
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct ExtensionInfo {
+	pub name: &'static str,
+	pub number: u32,
+	pub extension_type: ExtensionType,
+	pub spec_version: u32,
+	pub requires: &'static [&'static str],
+
+	// Boolean expression of what it depends on, '+' is and, ',' is or
+	pub depends: Option<&'static str>,
+	pub promoted_to: Option<&'static str>,
+	pub deprecated_by: Option<&'static str>,
+	pub obsoleted_by: Option<&'static str>
+}
+
+pub fn extension_info(name: &str) -> Option<&'static ExtensionInfo> {
+	EXTENSIONS.iter().find(|e| e.name == name)
+}
+
+pub static EXTENSIONS: &'static [ExtensionInfo] = &[
+	ExtensionInfo { name: "VK_KHR_surface", number: 1, extension_type: ExtensionType::Instance, spec_version: 25, requires: &[], depends: None, promoted_to: None, deprecated_by: None, obsoleted_by: None },
+	ExtensionInfo { name: "VK_KHR_swapchain", number: 2, extension_type: ExtensionType::Device, spec_version: 70, requires: &["VK_KHR_surface"], depends: None, promoted_to: None, deprecated_by: None, obsoleted_by: None },
+	ExtensionInfo { name: "VK_KHR_xcb_surface", number: 6, extension_type: ExtensionType::Instance, spec_version: 6, requires: &["VK_KHR_surface"], depends: None, promoted_to: None, deprecated_by: None, obsoleted_by: None },
+	ExtensionInfo { name: "VK_EXT_debug_report", number: 12, extension_type: ExtensionType::Instance, spec_version: 9, requires: &[], depends: None, promoted_to: None, deprecated_by: Some("VK_EXT_debug_utils"), obsoleted_by: None },
+	ExtensionInfo { name: "VK_KHR_get_physical_device_properties2", number: 60, extension_type: ExtensionType::Instance, spec_version: 1, requires: &[], depends: None, promoted_to: Some("VK_VERSION_1_1"), deprecated_by: None, obsoleted_by: None },
+	ExtensionInfo { name: "VK_KHR_shader_float16_int8", number: 83, extension_type: ExtensionType::Device, spec_version: 1, requires: &[], depends: Some("VK_KHR_get_physical_device_properties2,VK_VERSION_1_1"), promoted_to: None, deprecated_by: None, obsoleted_by: None },
+	ExtensionInfo { name: "VK_EXT_4444_formats", number: 341, extension_type: ExtensionType::Device, spec_version: 1, requires: &[], depends: Some("VK_KHR_get_physical_device_properties2,VK_VERSION_1_1"), promoted_to: None, deprecated_by: None, obsoleted_by: None },
+];
+
+#[link(name = "vulkan")]
+extern "C" {
+
+	// Device initialization
+	pub fn vkCreateInstance(pCreateInfo: *const VkInstanceCreateInfo, pAllocator: *const VkAllocationCallbacks, pInstance: *mut VkInstance, ) -> VkResult;
+	pub fn vkDestroyInstance(instance:  VkInstance, pAllocator: *const VkAllocationCallbacks, );
+	pub fn vkEnumeratePhysicalDevices(instance:  VkInstance, pPhysicalDeviceCount: *mut u32, pPhysicalDevices: *mut VkPhysicalDevice, ) -> VkResult;
+	pub fn vkGetPhysicalDeviceFeatures(physicalDevice:  VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures, );
+	pub fn vkGetPhysicalDeviceProperties(physicalDevice:  VkPhysicalDevice, pProperties: *mut VkPhysicalDeviceProperties, );
+	pub fn vkGetPhysicalDeviceQueueFamilyProperties(physicalDevice:  VkPhysicalDevice, pQueueFamilyPropertyCount: *mut u32, pQueueFamilyProperties: *mut VkQueueFamilyProperties, );
+	pub fn vkGetPhysicalDeviceMemoryProperties(physicalDevice:  VkPhysicalDevice, pMemoryProperties: *mut VkPhysicalDeviceMemoryProperties, );
+	pub fn vkGetInstanceProcAddr(instance:  VkInstance, pName: *const u8, ) -> PFN_vkVoidFunction;
+	pub fn vkGetDeviceProcAddr(device:  VkDevice, pName: *const u8, ) -> PFN_vkVoidFunction;
+
+	// Device commands
+	pub fn vkCreateDevice(physicalDevice:  VkPhysicalDevice, pCreateInfo: *const VkDeviceCreateInfo, pAllocator: *const VkAllocationCallbacks, pDevice: *mut VkDevice, ) -> VkResult;
+	pub fn vkDestroyDevice(device:  VkDevice, pAllocator: *const VkAllocationCallbacks, );
+
+	// Extension discovery commands
+	pub fn vkEnumerateInstanceExtensionProperties(pLayerName: *const u8, pPropertyCount: *mut u32, pProperties: *mut VkExtensionProperties, ) -> VkResult;
+	pub fn vkEnumerateDeviceExtensionProperties(physicalDevice:  VkPhysicalDevice, pLayerName: *const u8, pPropertyCount: *mut u32, pProperties: *mut VkExtensionProperties, ) -> VkResult;
+
+	// Layer discovery commands
+	pub fn vkEnumerateInstanceLayerProperties(pPropertyCount: *mut u32, pProperties: *mut VkLayerProperties, ) -> VkResult;
+
+	// Queue commands
+	pub fn vkGetDeviceQueue(device:  VkDevice, queueFamilyIndex:  u32, queueIndex:  u32, pQueue: *mut VkQueue, );
+	pub fn vkQueueWaitIdle(queue:  VkQueue, ) -> VkResult;
+
+	// Memory commands
+	pub fn vkAllocateMemory(device:  VkDevice, pAllocateInfo: *const VkMemoryAllocateInfo, pAllocator: *const VkAllocationCallbacks, pMemory: *mut VkDeviceMemory, ) -> VkResult;
+	pub fn vkFreeMemory(device:  VkDevice, memory:  VkDeviceMemory, pAllocator: *const VkAllocationCallbacks, );
+	pub fn vkMapMemory(device:  VkDevice, memory:  VkDeviceMemory, offset:  VkDeviceSize, size:  VkDeviceSize, flags:  VkMemoryMapFlags, ppData: *mut*mut c_void, ) -> VkResult;
+
+	// Buffer commands
+	pub fn vkCreateBuffer(device:  VkDevice, pCreateInfo: *const VkBufferCreateInfo, pAllocator: *const VkAllocationCallbacks, pBuffer: *mut VkBuffer, ) -> VkResult;
+	pub fn vkDestroyBuffer(device:  VkDevice, buffer:  VkBuffer, pAllocator: *const VkAllocationCallbacks, );
+
+	// Image commands
+	pub fn vkDestroyImage(device:  VkDevice, image:  VkImage, pAllocator: *const VkAllocationCallbacks, );
+
+	// Fence commands
+	pub fn vkCreateFence(device:  VkDevice, pCreateInfo: *const VkFenceCreateInfo, pAllocator: *const VkAllocationCallbacks, pFence: *mut VkFence, ) -> VkResult;
+	pub fn vkDestroyFence(device:  VkDevice, fence:  VkFence, pAllocator: *const VkAllocationCallbacks, );
+	pub fn vkWaitForFences(device:  VkDevice, fenceCount:  u32, pFences: *const VkFence, waitAll:  VkBool32, timeout:  u64, ) -> VkResult;
+
+	// Queue semaphore commands
+	pub fn vkDestroySemaphore(device:  VkDevice, semaphore:  VkSemaphore, pAllocator: *const VkAllocationCallbacks, );
+
+	// Command pool commands
+	pub fn vkCreateCommandPool(device:  VkDevice, pCreateInfo: *const VkCommandPoolCreateInfo, pAllocator: *const VkAllocationCallbacks, pCommandPool: *mut VkCommandPool, ) -> VkResult;
+	pub fn vkDestroyCommandPool(device:  VkDevice, commandPool:  VkCommandPool, pAllocator: *const VkAllocationCallbacks, );
+
+	// Command buffer commands
+	pub fn vkFreeCommandBuffers(device:  VkDevice, commandPool:  VkCommandPool, commandBufferCount:  u32, pCommandBuffers: *const VkCommandBuffer, );
+
+	// Device Initialization
+	pub fn vkEnumerateInstanceVersion(pApiVersion: *mut u32, ) -> VkResult;
+
+	// Promoted from VK_KHR_get_physical_device_properties2
+	pub fn vkGetPhysicalDeviceFeatures2(physicalDevice:  VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures2, );
+	pub fn vkGetPhysicalDeviceQueueFamilyProperties2(physicalDevice:  VkPhysicalDevice, pQueueFamilyPropertyCount: *mut u32, pQueueFamilyProperties: *mut VkQueueFamilyProperties2, );
+}
+
+#[cfg(feature = "std")]
+pub mod enumerate {
+	use super::*;
+
+	pub unsafe fn vkEnumeratePhysicalDevices(instance:  VkInstance) -> Result<Vec<VkPhysicalDevice>, VkResult> {
+		loop {
+			let mut count = 0;
+			let res = super::vkEnumeratePhysicalDevices(instance, &mut count, ::core::ptr::null_mut());
+			if res != VkResult::VK_SUCCESS {
+				return Err(res);
+			}
+			let mut values = Vec::<VkPhysicalDevice>::with_capacity(count as usize);
+			let res = super::vkEnumeratePhysicalDevices(instance, &mut count, values.as_mut_ptr());
+			if res == VkResult::VK_SUCCESS {
+				values.set_len(count as usize);
+				return Ok(values);
+			} else if res != VkResult::VK_INCOMPLETE {
+				return Err(res);
+			}
+		}
+	}
+
+	pub unsafe fn vkGetPhysicalDeviceQueueFamilyProperties(physicalDevice:  VkPhysicalDevice) -> Vec<VkQueueFamilyProperties> {
+		let mut count = 0;
+		super::vkGetPhysicalDeviceQueueFamilyProperties(physicalDevice, &mut count, ::core::ptr::null_mut());
+		let mut values = Vec::<VkQueueFamilyProperties>::with_capacity(count as usize);
+		super::vkGetPhysicalDeviceQueueFamilyProperties(physicalDevice, &mut count, values.as_mut_ptr());
+		values.set_len(count as usize);
+		values
+	}
+
+	pub unsafe fn vkEnumerateInstanceExtensionProperties(pLayerName: *const u8) -> Result<Vec<VkExtensionProperties>, VkResult> {
+		loop {
+			let mut count = 0;
+			let res = super::vkEnumerateInstanceExtensionProperties(pLayerName, &mut count, ::core::ptr::null_mut());
+			if res != VkResult::VK_SUCCESS {
+				return Err(res);
+			}
+			let mut values = Vec::<VkExtensionProperties>::with_capacity(count as usize);
+			let res = super::vkEnumerateInstanceExtensionProperties(pLayerName, &mut count, values.as_mut_ptr());
+			if res == VkResult::VK_SUCCESS {
+				values.set_len(count as usize);
+				return Ok(values);
+			} else if res != VkResult::VK_INCOMPLETE {
+				return Err(res);
+			}
+		}
+	}
+
+	pub unsafe fn vkEnumerateDeviceExtensionProperties(physicalDevice:  VkPhysicalDevice, pLayerName: *const u8) -> Result<Vec<VkExtensionProperties>, VkResult> {
+		loop {
+			let mut count = 0;
+			let res = super::vkEnumerateDeviceExtensionProperties(physicalDevice, pLayerName, &mut count, ::core::ptr::null_mut());
+			if res != VkResult::VK_SUCCESS {
+				return Err(res);
+			}
+			let mut values = Vec::<VkExtensionProperties>::with_capacity(count as usize);
+			let res = super::vkEnumerateDeviceExtensionProperties(physicalDevice, pLayerName, &mut count, values.as_mut_ptr());
+			if res == VkResult::VK_SUCCESS {
+				values.set_len(count as usize);
+				return Ok(values);
+			} else if res != VkResult::VK_INCOMPLETE {
+				return Err(res);
+			}
+		}
+	}
+
+	pub unsafe fn vkEnumerateInstanceLayerProperties() -> Result<Vec<VkLayerProperties>, VkResult> {
+		loop {
+			let mut count = 0;
+			let res = super::vkEnumerateInstanceLayerProperties(&mut count, ::core::ptr::null_mut());
+			if res != VkResult::VK_SUCCESS {
+				return Err(res);
+			}
+			let mut values = Vec::<VkLayerProperties>::with_capacity(count as usize);
+			let res = super::vkEnumerateInstanceLayerProperties(&mut count, values.as_mut_ptr());
+			if res == VkResult::VK_SUCCESS {
+				values.set_len(count as usize);
+				return Ok(values);
+			} else if res != VkResult::VK_INCOMPLETE {
+				return Err(res);
+			}
+		}
+	}
+
+	pub unsafe fn vkGetPhysicalDeviceQueueFamilyProperties2(physicalDevice:  VkPhysicalDevice) -> Vec<VkQueueFamilyProperties2> {
+		let mut count = 0;
+		super::vkGetPhysicalDeviceQueueFamilyProperties2(physicalDevice, &mut count, ::core::ptr::null_mut());
+		let mut values = Vec::<VkQueueFamilyProperties2>::with_capacity(count as usize);
+		for i in 0..count as usize {
+			let value = values.as_mut_ptr().add(i);
+			::core::ptr::addr_of_mut!((*value).sType).write(VkStructureType::VK_STRUCTURE_TYPE_QUEUE_FAMILY_PROPERTIES_2);
+			::core::ptr::addr_of_mut!((*value).pNext).write(::core::ptr::null_mut());
+		}
+		super::vkGetPhysicalDeviceQueueFamilyProperties2(physicalDevice, &mut count, values.as_mut_ptr());
+		values.set_len(count as usize);
+		values
+	}
+}
+
+// The first of a command's names (its own, then the ones it is promoted from or to) that get_proc_addr finds
+#[allow(dead_code)]
+fn first_proc_addr(names: &[&str], get_proc_addr: &dyn Fn(*const u8) -> *const c_void) -> *const c_void {
+	names.iter().map(|name| get_proc_addr(name.as_ptr())).find(|function| !function.is_null()).unwrap_or(::core::ptr::null())
+}
+
+	// Extensions
+	pub struct VulkanFunctionPointers {
+
+		// VK_KHR_surface
+
+#[cfg(feature = "VK_KHR_surface")]
+		pub DestroySurfaceKHR: Option<extern "C" fn(instance:  VkInstance, surface:  VkSurfaceKHR, pAllocator: *const VkAllocationCallbacks, )>,
+
+#[cfg(feature = "VK_KHR_surface")]
+		pub GetPhysicalDeviceSurfaceSupportKHR: Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, queueFamilyIndex:  u32, surface:  VkSurfaceKHR, pSupported: *mut VkBool32, ) -> VkResult>,
+
+#[cfg(feature = "VK_KHR_surface")]
+		pub GetPhysicalDeviceSurfaceCapabilitiesKHR: Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pSurfaceCapabilities: *mut VkSurfaceCapabilitiesKHR, ) -> VkResult>,
+
+#[cfg(feature = "VK_KHR_surface")]
+		pub GetPhysicalDeviceSurfaceFormatsKHR: Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pSurfaceFormatCount: *mut u32, pSurfaceFormats: *mut VkSurfaceFormatKHR, ) -> VkResult>,
+
+#[cfg(feature = "VK_KHR_surface")]
+		pub GetPhysicalDeviceSurfacePresentModesKHR: Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pPresentModeCount: *mut u32, pPresentModes: *mut VkPresentModeKHR, ) -> VkResult>,
+
+		// VK_KHR_swapchain
+
+#[cfg(feature = "VK_KHR_swapchain")]
+		pub CreateSwapchainKHR: Option<extern "C" fn(device:  VkDevice, pCreateInfo: *const VkSwapchainCreateInfoKHR, pAllocator: *const VkAllocationCallbacks, pSwapchain: *mut VkSwapchainKHR, ) -> VkResult>,
+
+#[cfg(feature = "VK_KHR_swapchain")]
+		pub DestroySwapchainKHR: Option<extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, pAllocator: *const VkAllocationCallbacks, )>,
+
+#[cfg(feature = "VK_KHR_swapchain")]
+		pub GetSwapchainImagesKHR: Option<extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, pSwapchainImageCount: *mut u32, pSwapchainImages: *mut VkImage, ) -> VkResult>,
+
+#[cfg(feature = "VK_KHR_swapchain")]
+		pub AcquireNextImageKHR: Option<extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, timeout:  u64, semaphore:  VkSemaphore, fence:  VkFence, pImageIndex: *mut u32, ) -> VkResult>,
+
+#[cfg(all(feature = "VK_KHR_swapchain", feature = "VK_KHR_get_physical_device_properties2", any(feature = "VK_EXT_debug_report", feature = "VK_KHR_surface")))]
+		pub GetSwapchainStatusKHR: Option<extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, ) -> VkResult>,
+
+		// VK_KHR_xcb_surface
+
+#[cfg(feature = "VK_KHR_xcb_surface")]
+		pub CreateXcbSurfaceKHR: Option<extern "C" fn(instance:  VkInstance, pCreateInfo: *const VkXcbSurfaceCreateInfoKHR, pAllocator: *const VkAllocationCallbacks, pSurface: *mut VkSurfaceKHR, ) -> VkResult>,
+
+#[cfg(feature = "VK_KHR_xcb_surface")]
+		pub GetPhysicalDeviceXcbPresentationSupportKHR: Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, queueFamilyIndex:  u32, connection: *mut xcb_connection_t, visual_id:  xcb_visualid_t, ) -> VkBool32>,
+
+		// VK_EXT_debug_report
+
+#[cfg(feature = "VK_EXT_debug_report")]
+		pub CreateDebugReportCallbackEXT: Option<extern "C" fn(instance:  VkInstance, pCreateInfo: *const VkDebugReportCallbackCreateInfoEXT, pAllocator: *const VkAllocationCallbacks, pCallback: *mut VkDebugReportCallbackEXT, ) -> VkResult>,
+
+#[cfg(feature = "VK_EXT_debug_report")]
+		pub DestroyDebugReportCallbackEXT: Option<extern "C" fn(instance:  VkInstance, callback:  VkDebugReportCallbackEXT, pAllocator: *const VkAllocationCallbacks, )>,
+
+		// VK_KHR_get_physical_device_properties2
+
+#[cfg(feature = "VK_KHR_get_physical_device_properties2")]
+		pub GetPhysicalDeviceFeatures2KHR: Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures2, )>,
+
+		// Promoted to core, loaded by an extension's name when the implementation only has the extension
+
+		pub GetPhysicalDeviceFeatures2: Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures2, )>,
+	}
+
+	impl VulkanFunctionPointers {
+		pub fn new(instance: VkInstance) -> VulkanFunctionPointers {
+			assert!(instance != VK_NULL_HANDLE);
+			VulkanFunctionPointers {
+
+			// VK_KHR_surface
+
+#[cfg(feature = "VK_KHR_surface")]
+			DestroySurfaceKHR: unsafe { ::core::mem::transmute::<*const c_void,Option<extern "C" fn(instance:  VkInstance, surface:  VkSurfaceKHR, pAllocator: *const VkAllocationCallbacks, )>>(vkGetInstanceProcAddr(instance, "vkDestroySurfaceKHR\0".as_ptr())) },
+
+#[cfg(feature = "VK_KHR_surface")]
+			GetPhysicalDeviceSurfaceSupportKHR: unsafe { ::core::mem::transmute::<*const c_void,Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, queueFamilyIndex:  u32, surface:  VkSurfaceKHR, pSupported: *mut VkBool32, ) -> VkResult>>(vkGetInstanceProcAddr(instance, "vkGetPhysicalDeviceSurfaceSupportKHR\0".as_ptr())) },
+
+#[cfg(feature = "VK_KHR_surface")]
+			GetPhysicalDeviceSurfaceCapabilitiesKHR: unsafe { ::core::mem::transmute::<*const c_void,Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pSurfaceCapabilities: *mut VkSurfaceCapabilitiesKHR, ) -> VkResult>>(vkGetInstanceProcAddr(instance, "vkGetPhysicalDeviceSurfaceCapabilitiesKHR\0".as_ptr())) },
+
+#[cfg(feature = "VK_KHR_surface")]
+			GetPhysicalDeviceSurfaceFormatsKHR: unsafe { ::core::mem::transmute::<*const c_void,Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pSurfaceFormatCount: *mut u32, pSurfaceFormats: *mut VkSurfaceFormatKHR, ) -> VkResult>>(vkGetInstanceProcAddr(instance, "vkGetPhysicalDeviceSurfaceFormatsKHR\0".as_ptr())) },
+
+#[cfg(feature = "VK_KHR_surface")]
+			GetPhysicalDeviceSurfacePresentModesKHR: unsafe { ::core::mem::transmute::<*const c_void,Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pPresentModeCount: *mut u32, pPresentModes: *mut VkPresentModeKHR, ) -> VkResult>>(vkGetInstanceProcAddr(instance, "vkGetPhysicalDeviceSurfacePresentModesKHR\0".as_ptr())) },
+
+			// VK_KHR_swapchain
+
+#[cfg(feature = "VK_KHR_swapchain")]
+			CreateSwapchainKHR: unsafe { ::core::mem::transmute::<*const c_void,Option<extern "C" fn(device:  VkDevice, pCreateInfo: *const VkSwapchainCreateInfoKHR, pAllocator: *const VkAllocationCallbacks, pSwapchain: *mut VkSwapchainKHR, ) -> VkResult>>(vkGetInstanceProcAddr(instance, "vkCreateSwapchainKHR\0".as_ptr())) },
+
+#[cfg(feature = "VK_KHR_swapchain")]
+			DestroySwapchainKHR: unsafe { ::core::mem::transmute::<*const c_void,Option<extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, pAllocator: *const VkAllocationCallbacks, )>>(vkGetInstanceProcAddr(instance, "vkDestroySwapchainKHR\0".as_ptr())) },
+
+#[cfg(feature = "VK_KHR_swapchain")]
+			GetSwapchainImagesKHR: unsafe { ::core::mem::transmute::<*const c_void,Option<extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, pSwapchainImageCount: *mut u32, pSwapchainImages: *mut VkImage, ) -> VkResult>>(vkGetInstanceProcAddr(instance, "vkGetSwapchainImagesKHR\0".as_ptr())) },
+
+#[cfg(feature = "VK_KHR_swapchain")]
+			AcquireNextImageKHR: unsafe { ::core::mem::transmute::<*const c_void,Option<extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, timeout:  u64, semaphore:  VkSemaphore, fence:  VkFence, pImageIndex: *mut u32, ) -> VkResult>>(vkGetInstanceProcAddr(instance, "vkAcquireNextImageKHR\0".as_ptr())) },
+
+#[cfg(all(feature = "VK_KHR_swapchain", feature = "VK_KHR_get_physical_device_properties2", any(feature = "VK_EXT_debug_report", feature = "VK_KHR_surface")))]
+			GetSwapchainStatusKHR: unsafe { ::core::mem::transmute::<*const c_void,Option<extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, ) -> VkResult>>(vkGetInstanceProcAddr(instance, "vkGetSwapchainStatusKHR\0".as_ptr())) },
+
+			// VK_KHR_xcb_surface
+
+#[cfg(feature = "VK_KHR_xcb_surface")]
+			CreateXcbSurfaceKHR: unsafe { ::core::mem::transmute::<*const c_void,Option<extern "C" fn(instance:  VkInstance, pCreateInfo: *const VkXcbSurfaceCreateInfoKHR, pAllocator: *const VkAllocationCallbacks, pSurface: *mut VkSurfaceKHR, ) -> VkResult>>(vkGetInstanceProcAddr(instance, "vkCreateXcbSurfaceKHR\0".as_ptr())) },
+
+#[cfg(feature = "VK_KHR_xcb_surface")]
+			GetPhysicalDeviceXcbPresentationSupportKHR: unsafe { ::core::mem::transmute::<*const c_void,Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, queueFamilyIndex:  u32, connection: *mut xcb_connection_t, visual_id:  xcb_visualid_t, ) -> VkBool32>>(vkGetInstanceProcAddr(instance, "vkGetPhysicalDeviceXcbPresentationSupportKHR\0".as_ptr())) },
+
+			// VK_EXT_debug_report
+
+#[cfg(feature = "VK_EXT_debug_report")]
+			CreateDebugReportCallbackEXT: unsafe { ::core::mem::transmute::<*const c_void,Option<extern "C" fn(instance:  VkInstance, pCreateInfo: *const VkDebugReportCallbackCreateInfoEXT, pAllocator: *const VkAllocationCallbacks, pCallback: *mut VkDebugReportCallbackEXT, ) -> VkResult>>(vkGetInstanceProcAddr(instance, "vkCreateDebugReportCallbackEXT\0".as_ptr())) },
+
+#[cfg(feature = "VK_EXT_debug_report")]
+			DestroyDebugReportCallbackEXT: unsafe { ::core::mem::transmute::<*const c_void,Option<extern "C" fn(instance:  VkInstance, callback:  VkDebugReportCallbackEXT, pAllocator: *const VkAllocationCallbacks, )>>(vkGetInstanceProcAddr(instance, "vkDestroyDebugReportCallbackEXT\0".as_ptr())) },
+
+			// VK_KHR_get_physical_device_properties2
+
+#[cfg(feature = "VK_KHR_get_physical_device_properties2")]
+			GetPhysicalDeviceFeatures2KHR: unsafe { ::core::mem::transmute::<*const c_void,Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures2, )>>(first_proc_addr(&["vkGetPhysicalDeviceFeatures2KHR\0", "vkGetPhysicalDeviceFeatures2\0"], &|name| vkGetInstanceProcAddr(instance, name))) },
+
+			// Promoted to core
+
+			GetPhysicalDeviceFeatures2: unsafe { ::core::mem::transmute::<*const c_void,Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures2, )>>(first_proc_addr(&["vkGetPhysicalDeviceFeatures2\0", "vkGetPhysicalDeviceFeatures2KHR\0"], &|name| vkGetInstanceProcAddr(instance, name))) },
+}
+		}
+	}
+
+#[cfg(feature = "std")]
+impl VulkanFunctionPointers {
+
+#[cfg(feature = "VK_KHR_surface")]
+	pub unsafe fn vkGetPhysicalDeviceSurfaceFormatsKHR(&self, physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR) -> Result<Vec<VkSurfaceFormatKHR>, VkResult> {
+		loop {
+			let mut count = 0;
+			let res = self.GetPhysicalDeviceSurfaceFormatsKHR.expect("vkGetPhysicalDeviceSurfaceFormatsKHR is not loaded")(physicalDevice, surface, &mut count, ::core::ptr::null_mut());
+			if res != VkResult::VK_SUCCESS {
+				return Err(res);
+			}
+			let mut values = Vec::<VkSurfaceFormatKHR>::with_capacity(count as usize);
+			let res = self.GetPhysicalDeviceSurfaceFormatsKHR.expect("vkGetPhysicalDeviceSurfaceFormatsKHR is not loaded")(physicalDevice, surface, &mut count, values.as_mut_ptr());
+			if res == VkResult::VK_SUCCESS {
+				values.set_len(count as usize);
+				return Ok(values);
+			} else if res != VkResult::VK_INCOMPLETE {
+				return Err(res);
+			}
+		}
+	}
+
+#[cfg(feature = "VK_KHR_surface")]
+	pub unsafe fn vkGetPhysicalDeviceSurfacePresentModesKHR(&self, physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR) -> Result<Vec<VkPresentModeKHR>, VkResult> {
+		loop {
+			let mut count = 0;
+			let res = self.GetPhysicalDeviceSurfacePresentModesKHR.expect("vkGetPhysicalDeviceSurfacePresentModesKHR is not loaded")(physicalDevice, surface, &mut count, ::core::ptr::null_mut());
+			if res != VkResult::VK_SUCCESS {
+				return Err(res);
+			}
+			let mut values = Vec::<VkPresentModeKHR>::with_capacity(count as usize);
+			let res = self.GetPhysicalDeviceSurfacePresentModesKHR.expect("vkGetPhysicalDeviceSurfacePresentModesKHR is not loaded")(physicalDevice, surface, &mut count, values.as_mut_ptr());
+			if res == VkResult::VK_SUCCESS {
+				values.set_len(count as usize);
+				return Ok(values);
+			} else if res != VkResult::VK_INCOMPLETE {
+				return Err(res);
+			}
+		}
+	}
+
+#[cfg(feature = "VK_KHR_swapchain")]
+	pub unsafe fn vkGetSwapchainImagesKHR(&self, device:  VkDevice, swapchain:  VkSwapchainKHR) -> Result<Vec<VkImage>, VkResult> {
+		loop {
+			let mut count = 0;
+			let res = self.GetSwapchainImagesKHR.expect("vkGetSwapchainImagesKHR is not loaded")(device, swapchain, &mut count, ::core::ptr::null_mut());
+			if res != VkResult::VK_SUCCESS {
+				return Err(res);
+			}
+			let mut values = Vec::<VkImage>::with_capacity(count as usize);
+			let res = self.GetSwapchainImagesKHR.expect("vkGetSwapchainImagesKHR is not loaded")(device, swapchain, &mut count, values.as_mut_ptr());
+			if res == VkResult::VK_SUCCESS {
+				values.set_len(count as usize);
+				return Ok(values);
+			} else if res != VkResult::VK_INCOMPLETE {
+				return Err(res);
+			}
+		}
+	}
+}
+
+// VK_KHR_surface's commands, commands that depend on other extensions too are optional
+#[cfg(feature = "VK_KHR_surface")]
+#[derive(Copy, Clone)]
+pub struct KhrSurfaceFn {
+	pub DestroySurfaceKHR: extern "C" fn(instance:  VkInstance, surface:  VkSurfaceKHR, pAllocator: *const VkAllocationCallbacks, ),
+	pub GetPhysicalDeviceSurfaceSupportKHR: extern "C" fn(physicalDevice:  VkPhysicalDevice, queueFamilyIndex:  u32, surface:  VkSurfaceKHR, pSupported: *mut VkBool32, ) -> VkResult,
+	pub GetPhysicalDeviceSurfaceCapabilitiesKHR: extern "C" fn(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pSurfaceCapabilities: *mut VkSurfaceCapabilitiesKHR, ) -> VkResult,
+	pub GetPhysicalDeviceSurfaceFormatsKHR: extern "C" fn(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pSurfaceFormatCount: *mut u32, pSurfaceFormats: *mut VkSurfaceFormatKHR, ) -> VkResult,
+	pub GetPhysicalDeviceSurfacePresentModesKHR: extern "C" fn(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pPresentModeCount: *mut u32, pPresentModes: *mut VkPresentModeKHR, ) -> VkResult,
+}
+
+#[cfg(feature = "VK_KHR_surface")]
+impl KhrSurfaceFn {
+
+	// Err has the name of the first command the implementation does not have
+	pub unsafe fn load(instance: VkInstance) -> Result<KhrSurfaceFn, &'static str> {
+		Ok(KhrSurfaceFn {
+			DestroySurfaceKHR: ::core::mem::transmute::<*const c_void, Option<extern "C" fn(instance:  VkInstance, surface:  VkSurfaceKHR, pAllocator: *const VkAllocationCallbacks, )>>(first_proc_addr(&["vkDestroySurfaceKHR\0"], &|name| vkGetInstanceProcAddr(instance, name))).ok_or("vkDestroySurfaceKHR")?,
+			GetPhysicalDeviceSurfaceSupportKHR: ::core::mem::transmute::<*const c_void, Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, queueFamilyIndex:  u32, surface:  VkSurfaceKHR, pSupported: *mut VkBool32, ) -> VkResult>>(first_proc_addr(&["vkGetPhysicalDeviceSurfaceSupportKHR\0"], &|name| vkGetInstanceProcAddr(instance, name))).ok_or("vkGetPhysicalDeviceSurfaceSupportKHR")?,
+			GetPhysicalDeviceSurfaceCapabilitiesKHR: ::core::mem::transmute::<*const c_void, Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pSurfaceCapabilities: *mut VkSurfaceCapabilitiesKHR, ) -> VkResult>>(first_proc_addr(&["vkGetPhysicalDeviceSurfaceCapabilitiesKHR\0"], &|name| vkGetInstanceProcAddr(instance, name))).ok_or("vkGetPhysicalDeviceSurfaceCapabilitiesKHR")?,
+			GetPhysicalDeviceSurfaceFormatsKHR: ::core::mem::transmute::<*const c_void, Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pSurfaceFormatCount: *mut u32, pSurfaceFormats: *mut VkSurfaceFormatKHR, ) -> VkResult>>(first_proc_addr(&["vkGetPhysicalDeviceSurfaceFormatsKHR\0"], &|name| vkGetInstanceProcAddr(instance, name))).ok_or("vkGetPhysicalDeviceSurfaceFormatsKHR")?,
+			GetPhysicalDeviceSurfacePresentModesKHR: ::core::mem::transmute::<*const c_void, Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pPresentModeCount: *mut u32, pPresentModes: *mut VkPresentModeKHR, ) -> VkResult>>(first_proc_addr(&["vkGetPhysicalDeviceSurfacePresentModesKHR\0"], &|name| vkGetInstanceProcAddr(instance, name))).ok_or("vkGetPhysicalDeviceSurfacePresentModesKHR")?,
+		})
+	}
+}
+
+// VK_KHR_swapchain's commands, commands that depend on other extensions too are optional
+#[cfg(feature = "VK_KHR_swapchain")]
+#[derive(Copy, Clone)]
+pub struct KhrSwapchainFn {
+	pub CreateSwapchainKHR: extern "C" fn(device:  VkDevice, pCreateInfo: *const VkSwapchainCreateInfoKHR, pAllocator: *const VkAllocationCallbacks, pSwapchain: *mut VkSwapchainKHR, ) -> VkResult,
+	pub DestroySwapchainKHR: extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, pAllocator: *const VkAllocationCallbacks, ),
+	pub GetSwapchainImagesKHR: extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, pSwapchainImageCount: *mut u32, pSwapchainImages: *mut VkImage, ) -> VkResult,
+	pub AcquireNextImageKHR: extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, timeout:  u64, semaphore:  VkSemaphore, fence:  VkFence, pImageIndex: *mut u32, ) -> VkResult,
+#[cfg(all(feature = "VK_KHR_swapchain", feature = "VK_KHR_get_physical_device_properties2", any(feature = "VK_EXT_debug_report", feature = "VK_KHR_surface")))]
+	pub GetSwapchainStatusKHR: Option<extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, ) -> VkResult>,
+}
+
+#[cfg(feature = "VK_KHR_swapchain")]
+impl KhrSwapchainFn {
+
+	// Err has the name of the first command the implementation does not have
+	pub unsafe fn load(device: VkDevice) -> Result<KhrSwapchainFn, &'static str> {
+		Ok(KhrSwapchainFn {
+			CreateSwapchainKHR: ::core::mem::transmute::<*const c_void, Option<extern "C" fn(device:  VkDevice, pCreateInfo: *const VkSwapchainCreateInfoKHR, pAllocator: *const VkAllocationCallbacks, pSwapchain: *mut VkSwapchainKHR, ) -> VkResult>>(first_proc_addr(&["vkCreateSwapchainKHR\0"], &|name| vkGetDeviceProcAddr(device, name))).ok_or("vkCreateSwapchainKHR")?,
+			DestroySwapchainKHR: ::core::mem::transmute::<*const c_void, Option<extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, pAllocator: *const VkAllocationCallbacks, )>>(first_proc_addr(&["vkDestroySwapchainKHR\0"], &|name| vkGetDeviceProcAddr(device, name))).ok_or("vkDestroySwapchainKHR")?,
+			GetSwapchainImagesKHR: ::core::mem::transmute::<*const c_void, Option<extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, pSwapchainImageCount: *mut u32, pSwapchainImages: *mut VkImage, ) -> VkResult>>(first_proc_addr(&["vkGetSwapchainImagesKHR\0"], &|name| vkGetDeviceProcAddr(device, name))).ok_or("vkGetSwapchainImagesKHR")?,
+			AcquireNextImageKHR: ::core::mem::transmute::<*const c_void, Option<extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, timeout:  u64, semaphore:  VkSemaphore, fence:  VkFence, pImageIndex: *mut u32, ) -> VkResult>>(first_proc_addr(&["vkAcquireNextImageKHR\0"], &|name| vkGetDeviceProcAddr(device, name))).ok_or("vkAcquireNextImageKHR")?,
+#[cfg(all(feature = "VK_KHR_swapchain", feature = "VK_KHR_get_physical_device_properties2", any(feature = "VK_EXT_debug_report", feature = "VK_KHR_surface")))]
+			GetSwapchainStatusKHR: ::core::mem::transmute::<*const c_void, Option<extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, ) -> VkResult>>(first_proc_addr(&["vkGetSwapchainStatusKHR\0"], &|name| vkGetDeviceProcAddr(device, name))),
+		})
+	}
+}
+
+// VK_KHR_xcb_surface's commands, commands that depend on other extensions too are optional
+#[cfg(feature = "VK_KHR_xcb_surface")]
+#[derive(Copy, Clone)]
+pub struct KhrXcbSurfaceFn {
+	pub CreateXcbSurfaceKHR: extern "C" fn(instance:  VkInstance, pCreateInfo: *const VkXcbSurfaceCreateInfoKHR, pAllocator: *const VkAllocationCallbacks, pSurface: *mut VkSurfaceKHR, ) -> VkResult,
+	pub GetPhysicalDeviceXcbPresentationSupportKHR: extern "C" fn(physicalDevice:  VkPhysicalDevice, queueFamilyIndex:  u32, connection: *mut xcb_connection_t, visual_id:  xcb_visualid_t, ) -> VkBool32,
+}
+
+#[cfg(feature = "VK_KHR_xcb_surface")]
+impl KhrXcbSurfaceFn {
+
+	// Err has the name of the first command the implementation does not have
+	pub unsafe fn load(instance: VkInstance) -> Result<KhrXcbSurfaceFn, &'static str> {
+		Ok(KhrXcbSurfaceFn {
+			CreateXcbSurfaceKHR: ::core::mem::transmute::<*const c_void, Option<extern "C" fn(instance:  VkInstance, pCreateInfo: *const VkXcbSurfaceCreateInfoKHR, pAllocator: *const VkAllocationCallbacks, pSurface: *mut VkSurfaceKHR, ) -> VkResult>>(first_proc_addr(&["vkCreateXcbSurfaceKHR\0"], &|name| vkGetInstanceProcAddr(instance, name))).ok_or("vkCreateXcbSurfaceKHR")?,
+			GetPhysicalDeviceXcbPresentationSupportKHR: ::core::mem::transmute::<*const c_void, Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, queueFamilyIndex:  u32, connection: *mut xcb_connection_t, visual_id:  xcb_visualid_t, ) -> VkBool32>>(first_proc_addr(&["vkGetPhysicalDeviceXcbPresentationSupportKHR\0"], &|name| vkGetInstanceProcAddr(instance, name))).ok_or("vkGetPhysicalDeviceXcbPresentationSupportKHR")?,
+		})
+	}
+}
+
+// VK_EXT_debug_report's commands, commands that depend on other extensions too are optional
+#[cfg(feature = "VK_EXT_debug_report")]
+#[derive(Copy, Clone)]
+pub struct ExtDebugReportFn {
+	pub CreateDebugReportCallbackEXT: extern "C" fn(instance:  VkInstance, pCreateInfo: *const VkDebugReportCallbackCreateInfoEXT, pAllocator: *const VkAllocationCallbacks, pCallback: *mut VkDebugReportCallbackEXT, ) -> VkResult,
+	pub DestroyDebugReportCallbackEXT: extern "C" fn(instance:  VkInstance, callback:  VkDebugReportCallbackEXT, pAllocator: *const VkAllocationCallbacks, ),
+}
+
+#[cfg(feature = "VK_EXT_debug_report")]
+impl ExtDebugReportFn {
+
+	// Err has the name of the first command the implementation does not have
+	pub unsafe fn load(instance: VkInstance) -> Result<ExtDebugReportFn, &'static str> {
+		Ok(ExtDebugReportFn {
+			CreateDebugReportCallbackEXT: ::core::mem::transmute::<*const c_void, Option<extern "C" fn(instance:  VkInstance, pCreateInfo: *const VkDebugReportCallbackCreateInfoEXT, pAllocator: *const VkAllocationCallbacks, pCallback: *mut VkDebugReportCallbackEXT, ) -> VkResult>>(first_proc_addr(&["vkCreateDebugReportCallbackEXT\0"], &|name| vkGetInstanceProcAddr(instance, name))).ok_or("vkCreateDebugReportCallbackEXT")?,
+			DestroyDebugReportCallbackEXT: ::core::mem::transmute::<*const c_void, Option<extern "C" fn(instance:  VkInstance, callback:  VkDebugReportCallbackEXT, pAllocator: *const VkAllocationCallbacks, )>>(first_proc_addr(&["vkDestroyDebugReportCallbackEXT\0"], &|name| vkGetInstanceProcAddr(instance, name))).ok_or("vkDestroyDebugReportCallbackEXT")?,
+		})
+	}
+}
+
+// VK_KHR_get_physical_device_properties2's commands, commands that depend on other extensions too are optional
+#[cfg(feature = "VK_KHR_get_physical_device_properties2")]
+#[derive(Copy, Clone)]
+pub struct KhrGetPhysicalDeviceProperties2Fn {
+	pub GetPhysicalDeviceFeatures2KHR: extern "C" fn(physicalDevice:  VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures2, ),
+}
+
+#[cfg(feature = "VK_KHR_get_physical_device_properties2")]
+impl KhrGetPhysicalDeviceProperties2Fn {
+
+	// Err has the name of the first command the implementation does not have
+	pub unsafe fn load(instance: VkInstance) -> Result<KhrGetPhysicalDeviceProperties2Fn, &'static str> {
+		Ok(KhrGetPhysicalDeviceProperties2Fn {
+			GetPhysicalDeviceFeatures2KHR: ::core::mem::transmute::<*const c_void, Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures2, )>>(first_proc_addr(&["vkGetPhysicalDeviceFeatures2KHR\0", "vkGetPhysicalDeviceFeatures2\0"], &|name| vkGetInstanceProcAddr(instance, name))).ok_or("vkGetPhysicalDeviceFeatures2KHR")?,
+		})
+	}
+}
+
+// Generic code over handles (debug names, leak tracking, drop), implemented by the marker types in handle::
+// i.e. handle::VkBuffer for VkBuffer
+pub trait Handle {
+	const NAME: &'static str;
+	const OBJECT_TYPE: VkObjectType;
+
+	// From the registry's parent attribute, the handle itself for handles without one
+	type Parent: Handle;
+
+	// The vkDestroy* or vkFree* command, if it has one
+	const DESTROY_COMMAND: Option<&'static str>;
+
+	// Calls DESTROY_COMMAND without allocation callbacks, owner is the device or instance it was created from
+	// Returns false if there is nothing it can call, i.e. commands that free from a pool (vkFreeCommandBuffers)
+	unsafe fn destroy(functions: &VulkanFunctionPointers, owner: u64, handle: u64) -> bool;
+
+	// The same object type for VK_EXT_debug_report, unknown for types it does not have
+	const DEBUG_REPORT_OBJECT_TYPE: VkDebugReportObjectTypeEXT;
+}
+
+pub mod handle {
+	use super::*;
+
+	#[allow(non_camel_case_types)]
+	pub struct VkInstance;
+
+	impl Handle for VkInstance {
+		const NAME: &'static str = "VkInstance";
+		const OBJECT_TYPE: VkObjectType = VkObjectType::VK_OBJECT_TYPE_INSTANCE;
+		const DEBUG_REPORT_OBJECT_TYPE: VkDebugReportObjectTypeEXT = VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_INSTANCE_EXT;
+		type Parent = VkInstance;
+		const DESTROY_COMMAND: Option<&'static str> = Some("vkDestroyInstance");
+		unsafe fn destroy(_functions: &VulkanFunctionPointers, _owner: u64, handle: u64) -> bool {
+			vkDestroyInstance(handle, ::core::ptr::null());
+			true
+		}
+	}
+
+	#[allow(non_camel_case_types)]
+	pub struct VkPhysicalDevice;
+
+	impl Handle for VkPhysicalDevice {
+		const NAME: &'static str = "VkPhysicalDevice";
+		const OBJECT_TYPE: VkObjectType = VkObjectType::VK_OBJECT_TYPE_PHYSICAL_DEVICE;
+		const DEBUG_REPORT_OBJECT_TYPE: VkDebugReportObjectTypeEXT = VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_PHYSICAL_DEVICE_EXT;
+		type Parent = VkInstance;
+		const DESTROY_COMMAND: Option<&'static str> = None;
+		unsafe fn destroy(_functions: &VulkanFunctionPointers, _owner: u64, _handle: u64) -> bool {
+			false
+		}
+	}
+
+	#[allow(non_camel_case_types)]
+	pub struct VkDevice;
+
+	impl Handle for VkDevice {
+		const NAME: &'static str = "VkDevice";
+		const OBJECT_TYPE: VkObjectType = VkObjectType::VK_OBJECT_TYPE_DEVICE;
+		const DEBUG_REPORT_OBJECT_TYPE: VkDebugReportObjectTypeEXT = VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_EXT;
+		type Parent = VkPhysicalDevice;
+		const DESTROY_COMMAND: Option<&'static str> = Some("vkDestroyDevice");
+		unsafe fn destroy(_functions: &VulkanFunctionPointers, _owner: u64, handle: u64) -> bool {
+			vkDestroyDevice(handle, ::core::ptr::null());
+			true
+		}
+	}
+
+	#[allow(non_camel_case_types)]
+	pub struct VkQueue;
+
+	impl Handle for VkQueue {
+		const NAME: &'static str = "VkQueue";
+		const OBJECT_TYPE: VkObjectType = VkObjectType::VK_OBJECT_TYPE_QUEUE;
+		const DEBUG_REPORT_OBJECT_TYPE: VkDebugReportObjectTypeEXT = VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_QUEUE_EXT;
+		type Parent = VkDevice;
+		const DESTROY_COMMAND: Option<&'static str> = None;
+		unsafe fn destroy(_functions: &VulkanFunctionPointers, _owner: u64, _handle: u64) -> bool {
+			false
+		}
+	}
+
+	#[allow(non_camel_case_types)]
+	pub struct VkCommandBuffer;
+
+	impl Handle for VkCommandBuffer {
+		const NAME: &'static str = "VkCommandBuffer";
+		const OBJECT_TYPE: VkObjectType = VkObjectType::VK_OBJECT_TYPE_COMMAND_BUFFER;
+		const DEBUG_REPORT_OBJECT_TYPE: VkDebugReportObjectTypeEXT = VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_COMMAND_BUFFER_EXT;
+		type Parent = VkCommandPool;
+		const DESTROY_COMMAND: Option<&'static str> = Some("vkFreeCommandBuffers");
+		unsafe fn destroy(_functions: &VulkanFunctionPointers, _owner: u64, _handle: u64) -> bool {
+			false
+		}
+	}
+
+	#[allow(non_camel_case_types)]
+	pub struct VkDeviceMemory;
+
+	impl Handle for VkDeviceMemory {
+		const NAME: &'static str = "VkDeviceMemory";
+		const OBJECT_TYPE: VkObjectType = VkObjectType::VK_OBJECT_TYPE_DEVICE_MEMORY;
+		const DEBUG_REPORT_OBJECT_TYPE: VkDebugReportObjectTypeEXT = VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_DEVICE_MEMORY_EXT;
+		type Parent = VkDevice;
+		const DESTROY_COMMAND: Option<&'static str> = Some("vkFreeMemory");
+		unsafe fn destroy(_functions: &VulkanFunctionPointers, owner: u64, handle: u64) -> bool {
+			vkFreeMemory(owner, handle, ::core::ptr::null());
+			true
+		}
+	}
+
+	#[allow(non_camel_case_types)]
+	pub struct VkCommandPool;
+
+	impl Handle for VkCommandPool {
+		const NAME: &'static str = "VkCommandPool";
+		const OBJECT_TYPE: VkObjectType = VkObjectType::VK_OBJECT_TYPE_COMMAND_POOL;
+		const DEBUG_REPORT_OBJECT_TYPE: VkDebugReportObjectTypeEXT = VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_COMMAND_POOL_EXT;
+		type Parent = VkDevice;
+		const DESTROY_COMMAND: Option<&'static str> = Some("vkDestroyCommandPool");
+		unsafe fn destroy(_functions: &VulkanFunctionPointers, owner: u64, handle: u64) -> bool {
+			vkDestroyCommandPool(owner, handle, ::core::ptr::null());
+			true
+		}
+	}
+
+	#[allow(non_camel_case_types)]
+	pub struct VkBuffer;
+
+	impl Handle for VkBuffer {
+		const NAME: &'static str = "VkBuffer";
+		const OBJECT_TYPE: VkObjectType = VkObjectType::VK_OBJECT_TYPE_BUFFER;
+		const DEBUG_REPORT_OBJECT_TYPE: VkDebugReportObjectTypeEXT = VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_BUFFER_EXT;
+		type Parent = VkDevice;
+		const DESTROY_COMMAND: Option<&'static str> = Some("vkDestroyBuffer");
+		unsafe fn destroy(_functions: &VulkanFunctionPointers, owner: u64, handle: u64) -> bool {
+			vkDestroyBuffer(owner, handle, ::core::ptr::null());
+			true
+		}
+	}
+
+	#[allow(non_camel_case_types)]
+	pub struct VkImage;
+
+	impl Handle for VkImage {
+		const NAME: &'static str = "VkImage";
+		const OBJECT_TYPE: VkObjectType = VkObjectType::VK_OBJECT_TYPE_IMAGE;
+		const DEBUG_REPORT_OBJECT_TYPE: VkDebugReportObjectTypeEXT = VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_IMAGE_EXT;
+		type Parent = VkDevice;
+		const DESTROY_COMMAND: Option<&'static str> = Some("vkDestroyImage");
+		unsafe fn destroy(_functions: &VulkanFunctionPointers, owner: u64, handle: u64) -> bool {
+			vkDestroyImage(owner, handle, ::core::ptr::null());
+			true
+		}
+	}
+
+	#[allow(non_camel_case_types)]
+	pub struct VkFence;
+
+	impl Handle for VkFence {
+		const NAME: &'static str = "VkFence";
+		const OBJECT_TYPE: VkObjectType = VkObjectType::VK_OBJECT_TYPE_FENCE;
+		const DEBUG_REPORT_OBJECT_TYPE: VkDebugReportObjectTypeEXT = VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_FENCE_EXT;
+		type Parent = VkDevice;
+		const DESTROY_COMMAND: Option<&'static str> = Some("vkDestroyFence");
+		unsafe fn destroy(_functions: &VulkanFunctionPointers, owner: u64, handle: u64) -> bool {
+			vkDestroyFence(owner, handle, ::core::ptr::null());
+			true
+		}
+	}
+
+	#[allow(non_camel_case_types)]
+	pub struct VkSemaphore;
+
+	impl Handle for VkSemaphore {
+		const NAME: &'static str = "VkSemaphore";
+		const OBJECT_TYPE: VkObjectType = VkObjectType::VK_OBJECT_TYPE_SEMAPHORE;
+		const DEBUG_REPORT_OBJECT_TYPE: VkDebugReportObjectTypeEXT = VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_SEMAPHORE_EXT;
+		type Parent = VkDevice;
+		const DESTROY_COMMAND: Option<&'static str> = Some("vkDestroySemaphore");
+		unsafe fn destroy(_functions: &VulkanFunctionPointers, owner: u64, handle: u64) -> bool {
+			vkDestroySemaphore(owner, handle, ::core::ptr::null());
+			true
+		}
+	}
+
+#[cfg(feature = "VK_KHR_surface")]
+	#[allow(non_camel_case_types)]
+	pub struct VkSurfaceKHR;
+
+#[cfg(feature = "VK_KHR_surface")]
+	impl Handle for VkSurfaceKHR {
+		const NAME: &'static str = "VkSurfaceKHR";
+		const OBJECT_TYPE: VkObjectType = VkObjectType::VK_OBJECT_TYPE_SURFACE_KHR;
+		const DEBUG_REPORT_OBJECT_TYPE: VkDebugReportObjectTypeEXT = VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_SURFACE_KHR_EXT;
+		type Parent = VkInstance;
+		const DESTROY_COMMAND: Option<&'static str> = Some("vkDestroySurfaceKHR");
+		unsafe fn destroy(functions: &VulkanFunctionPointers, owner: u64, handle: u64) -> bool {
+			functions.DestroySurfaceKHR.expect("vkDestroySurfaceKHR is not loaded")(owner, handle, ::core::ptr::null());
+			true
+		}
+	}
+
+#[cfg(feature = "VK_KHR_swapchain")]
+	#[allow(non_camel_case_types)]
+	pub struct VkSwapchainKHR;
+
+#[cfg(feature = "VK_KHR_swapchain")]
+	impl Handle for VkSwapchainKHR {
+		const NAME: &'static str = "VkSwapchainKHR";
+		const OBJECT_TYPE: VkObjectType = VkObjectType::VK_OBJECT_TYPE_SWAPCHAIN_KHR;
+		const DEBUG_REPORT_OBJECT_TYPE: VkDebugReportObjectTypeEXT = VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_SWAPCHAIN_KHR_EXT;
+		type Parent = VkSurfaceKHR;
+		const DESTROY_COMMAND: Option<&'static str> = Some("vkDestroySwapchainKHR");
+		unsafe fn destroy(functions: &VulkanFunctionPointers, owner: u64, handle: u64) -> bool {
+			functions.DestroySwapchainKHR.expect("vkDestroySwapchainKHR is not loaded")(owner, handle, ::core::ptr::null());
+			true
+		}
+	}
+
+#[cfg(feature = "VK_EXT_debug_report")]
+	#[allow(non_camel_case_types)]
+	pub struct VkDebugReportCallbackEXT;
+
+#[cfg(feature = "VK_EXT_debug_report")]
+	impl Handle for VkDebugReportCallbackEXT {
+		const NAME: &'static str = "VkDebugReportCallbackEXT";
+		const OBJECT_TYPE: VkObjectType = VkObjectType::VK_OBJECT_TYPE_DEBUG_REPORT_CALLBACK_EXT;
+		const DEBUG_REPORT_OBJECT_TYPE: VkDebugReportObjectTypeEXT = VkDebugReportObjectTypeEXT::VK_DEBUG_REPORT_OBJECT_TYPE_DEBUG_REPORT_CALLBACK_EXT_EXT;
+		type Parent = VkInstance;
+		const DESTROY_COMMAND: Option<&'static str> = Some("vkDestroyDebugReportCallbackEXT");
+		unsafe fn destroy(functions: &VulkanFunctionPointers, owner: u64, handle: u64) -> bool {
+			functions.DestroyDebugReportCallbackEXT.expect("vkDestroyDebugReportCallbackEXT is not loaded")(owner, handle, ::core::ptr::null());
+			true
+		}
+	}
+}
+
+// A struct in a pNext chain, see chain
+#[derive(Copy, Clone)]
+pub enum ChainStruct<'a> {
+	VkApplicationInfo(&'a VkApplicationInfo),
+	VkDeviceQueueCreateInfo(&'a VkDeviceQueueCreateInfo),
+	VkDeviceCreateInfo(&'a VkDeviceCreateInfo),
+	VkInstanceCreateInfo(&'a VkInstanceCreateInfo),
+	VkMemoryAllocateInfo(&'a VkMemoryAllocateInfo),
+	VkBufferCreateInfo(&'a VkBufferCreateInfo),
+	VkCommandPoolCreateInfo(&'a VkCommandPoolCreateInfo),
+	VkFenceCreateInfo(&'a VkFenceCreateInfo),
+	VkPhysicalDeviceFeatures2(&'a VkPhysicalDeviceFeatures2),
+	VkPhysicalDeviceGroupProperties(&'a VkPhysicalDeviceGroupProperties),
+	VkQueueFamilyProperties2(&'a VkQueueFamilyProperties2),
+#[cfg(feature = "VK_KHR_swapchain")]
+	VkSwapchainCreateInfoKHR(&'a VkSwapchainCreateInfoKHR),
+#[cfg(feature = "VK_KHR_xcb_surface")]
+	VkXcbSurfaceCreateInfoKHR(&'a VkXcbSurfaceCreateInfoKHR),
+#[cfg(feature = "VK_EXT_debug_report")]
+	VkDebugReportCallbackCreateInfoEXT(&'a VkDebugReportCallbackCreateInfoEXT),
+#[cfg(feature = "VK_KHR_shader_float16_int8")]
+	VkPhysicalDeviceFloat16Int8FeaturesKHR(&'a VkPhysicalDeviceFloat16Int8FeaturesKHR),
+
+	// A struct vkraw does not know, with its sType
+	Unknown(i32)
+}
+
+impl<'a> core::fmt::Debug for ChainStruct<'a> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		match *self {
+			ChainStruct::VkApplicationInfo(value) => core::fmt::Debug::fmt(value, f),
+			ChainStruct::VkDeviceQueueCreateInfo(value) => core::fmt::Debug::fmt(value, f),
+			ChainStruct::VkDeviceCreateInfo(value) => core::fmt::Debug::fmt(value, f),
+			ChainStruct::VkInstanceCreateInfo(value) => core::fmt::Debug::fmt(value, f),
+			ChainStruct::VkMemoryAllocateInfo(value) => core::fmt::Debug::fmt(value, f),
+			ChainStruct::VkBufferCreateInfo(value) => core::fmt::Debug::fmt(value, f),
+			ChainStruct::VkCommandPoolCreateInfo(value) => core::fmt::Debug::fmt(value, f),
+			ChainStruct::VkFenceCreateInfo(value) => core::fmt::Debug::fmt(value, f),
+			ChainStruct::VkPhysicalDeviceFeatures2(value) => core::fmt::Debug::fmt(value, f),
+			ChainStruct::VkPhysicalDeviceGroupProperties(_) => f.write_str("VkPhysicalDeviceGroupProperties { .. }"),
+			ChainStruct::VkQueueFamilyProperties2(value) => core::fmt::Debug::fmt(value, f),
+#[cfg(feature = "VK_KHR_swapchain")]
+			ChainStruct::VkSwapchainCreateInfoKHR(value) => core::fmt::Debug::fmt(value, f),
+#[cfg(feature = "VK_KHR_xcb_surface")]
+			ChainStruct::VkXcbSurfaceCreateInfoKHR(value) => core::fmt::Debug::fmt(value, f),
+#[cfg(feature = "VK_EXT_debug_report")]
+			ChainStruct::VkDebugReportCallbackCreateInfoEXT(value) => core::fmt::Debug::fmt(value, f),
+#[cfg(feature = "VK_KHR_shader_float16_int8")]
+			ChainStruct::VkPhysicalDeviceFloat16Int8FeaturesKHR(value) => core::fmt::Debug::fmt(value, f),
+			ChainStruct::Unknown(sType) => write!(f, "{{ sType: {}, .. }}", sType)
+		}
+	}
+}
+
+// The structs of a pNext chain, see chain
+#[derive(Copy, Clone)]
+pub struct Chain<'a> {
+	next: *const c_void,
+	marker: core::marker::PhantomData<&'a c_void>
+}
+
+impl<'a> Iterator for Chain<'a> {
+	type Item = ChainStruct<'a>;
+
+	fn next(&mut self) -> Option<ChainStruct<'a>> {
+		if self.next.is_null() {
+			return None;
+		}
+		let current = self.next;
+		unsafe {
+			self.next = (*(current as *const VkBaseOutStructure)).pNext as *const c_void;
+
+			// sType is read as an i32, it can be a value vkraw does not know
+			Some(match *(current as *const i32) {
+				0 => ChainStruct::VkApplicationInfo(&*(current as *const VkApplicationInfo)),
+				2 => ChainStruct::VkDeviceQueueCreateInfo(&*(current as *const VkDeviceQueueCreateInfo)),
+				3 => ChainStruct::VkDeviceCreateInfo(&*(current as *const VkDeviceCreateInfo)),
+				1 => ChainStruct::VkInstanceCreateInfo(&*(current as *const VkInstanceCreateInfo)),
+				5 => ChainStruct::VkMemoryAllocateInfo(&*(current as *const VkMemoryAllocateInfo)),
+				12 => ChainStruct::VkBufferCreateInfo(&*(current as *const VkBufferCreateInfo)),
+				39 => ChainStruct::VkCommandPoolCreateInfo(&*(current as *const VkCommandPoolCreateInfo)),
+				8 => ChainStruct::VkFenceCreateInfo(&*(current as *const VkFenceCreateInfo)),
+				1000059000 => ChainStruct::VkPhysicalDeviceFeatures2(&*(current as *const VkPhysicalDeviceFeatures2)),
+				1000070000 => ChainStruct::VkPhysicalDeviceGroupProperties(&*(current as *const VkPhysicalDeviceGroupProperties)),
+				1000059005 => ChainStruct::VkQueueFamilyProperties2(&*(current as *const VkQueueFamilyProperties2)),
+#[cfg(feature = "VK_KHR_swapchain")]
+				1000001000 => ChainStruct::VkSwapchainCreateInfoKHR(&*(current as *const VkSwapchainCreateInfoKHR)),
+#[cfg(feature = "VK_KHR_xcb_surface")]
+				1000005000 => ChainStruct::VkXcbSurfaceCreateInfoKHR(&*(current as *const VkXcbSurfaceCreateInfoKHR)),
+#[cfg(feature = "VK_EXT_debug_report")]
+				1000011000 => ChainStruct::VkDebugReportCallbackCreateInfoEXT(&*(current as *const VkDebugReportCallbackCreateInfoEXT)),
+#[cfg(feature = "VK_KHR_shader_float16_int8")]
+				1000082000 => ChainStruct::VkPhysicalDeviceFloat16Int8FeaturesKHR(&*(current as *const VkPhysicalDeviceFloat16Int8FeaturesKHR)),
+				sType => ChainStruct::Unknown(sType)
+			})
+		}
+	}
+}
+
+impl<'a> core::fmt::Debug for Chain<'a> {
+	fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+		f.debug_list().entries(*self).finish()
+	}
+}
+
+// The structs in a pNext chain, found from their sType, i.e. to print it with {:?}
+// The chain has to stay valid while they are used
+pub unsafe fn chain<'a>(pNext: *const c_void) -> Chain<'a> {
+	Chain { next: pNext, marker: core::marker::PhantomData }
+}
+
+// Framework for writing layers, see vklayer for an example
+#[cfg(feature = "layer")]
+pub mod layer {
+	use super::*;
+
+	use std::collections::BTreeMap;
+	use std::ffi::CStr;
+	use std::sync::{Arc, RwLock};
+	use core::ptr;
+
+	// Loader and layer interface from vk_layer.h, which is not part of vk.xml
+	// sTypes are read as integers as other structures in a pNext chain may have values vkraw was built without
+	const LAYER_NEGOTIATE_INTERFACE_STRUCT: i32 = 1;
+	const VK_STRUCTURE_TYPE_LOADER_INSTANCE_CREATE_INFO: i32 = 47;
+	const VK_STRUCTURE_TYPE_LOADER_DEVICE_CREATE_INFO: i32 = 48;
+	const VK_LAYER_LINK_INFO: i32 = 0;
+
+	pub const LAYER_INTERFACE_VERSION: u32 = 2;
+
+	#[repr(C)]
+	pub struct VkNegotiateLayerInterface {
+		pub sType: i32,
+		pub pNext: *mut c_void,
+		pub loaderLayerInterfaceVersion: u32,
+		pub pfnGetInstanceProcAddr: Option<unsafe extern "C" fn(instance: VkInstance, pName: *const u8) -> PFN_vkVoidFunction>,
+		pub pfnGetDeviceProcAddr: Option<unsafe extern "C" fn(device: VkDevice, pName: *const u8) -> PFN_vkVoidFunction>,
+		pub pfnGetPhysicalDeviceProcAddr: PFN_vkVoidFunction
+	}
+
+	#[repr(C)]
+	pub struct VkLayerInstanceLink {
+		pub pNext: *mut VkLayerInstanceLink,
+		pub pfnNextGetInstanceProcAddr: extern "C" fn(instance: VkInstance, pName: *const u8, ) -> PFN_vkVoidFunction,
+		pub pfnNextGetPhysicalDeviceProcAddr: PFN_vkVoidFunction
+	}
+
+	#[repr(C)]
+	pub struct VkLayerDeviceLink {
+		pub pNext: *mut VkLayerDeviceLink,
+		pub pfnNextGetInstanceProcAddr: extern "C" fn(instance: VkInstance, pName: *const u8, ) -> PFN_vkVoidFunction,
+		pub pfnNextGetDeviceProcAddr: extern "C" fn(device: VkDevice, pName: *const u8, ) -> PFN_vkVoidFunction
+	}
+
+	// Only the pLayerInfo member of the C union is used, when function is VK_LAYER_LINK_INFO
+	#[repr(C)]
+	pub struct VkLayerInstanceCreateInfo {
+		pub sType: i32,
+		pub pNext: *const c_void,
+		pub function: i32,
+		pub pLayerInfo: *mut VkLayerInstanceLink
+	}
+
+	#[repr(C)]
+	pub struct VkLayerDeviceCreateInfo {
+		pub sType: i32,
+		pub pNext: *const c_void,
+		pub function: i32,
+		pub pLayerInfo: *mut VkLayerDeviceLink
+	}
+
+	// Finds the loader's link info for this layer in a create info's pNext chain
+	unsafe fn find_link_info<T>(pNext: *const c_void, sType: i32) -> *mut T {
+		let mut next = pNext as *mut VkLayerInstanceCreateInfo;
+		while !next.is_null() {
+			if (*next).sType == sType && (*next).function == VK_LAYER_LINK_INFO {
+				return next as *mut T;
+			}
+			next = (*next).pNext as *mut VkLayerInstanceCreateInfo;
+		}
+		ptr::null_mut()
+	}
+
+	// Dispatchable handles point at the loader's dispatch table, physical devices share their instance's
+	// and queues and command buffers share their device's
+	unsafe fn dispatch_key(handle: u64) -> usize {
+		*(handle as usize as *const usize)
+	}
+
+	static INSTANCES: RwLock<BTreeMap<usize, Arc<InstanceDispatch>>> = RwLock::new(BTreeMap::new());
+	static DEVICES: RwLock<BTreeMap<usize, Arc<DeviceDispatch>>> = RwLock::new(BTreeMap::new());
+
+	// The next layer's commands for an instance or one of its physical devices
+	pub unsafe fn instance_dispatch(handle: u64) -> Arc<InstanceDispatch> {
+		INSTANCES.read().unwrap().get(&dispatch_key(handle)).expect("Unknown instance or physical device").clone()
+	}
+
+	// The next layer's commands for a device or one of its queues or command buffers
+	pub unsafe fn device_dispatch(handle: u64) -> Arc<DeviceDispatch> {
+		DEVICES.read().unwrap().get(&dispatch_key(handle)).expect("Unknown device, queue or command buffer").clone()
+	}
+
+	// The layer's own command if the next layer has it, so that commands that are not enabled stay null
+	unsafe fn next_or_intercept<L: Layer>(name: &[u8], next: PFN_vkVoidFunction) -> PFN_vkVoidFunction {
+		let own = intercept_address::<L>(name);
+		if next.is_null() || own.is_null() { next } else { own }
+	}
+
+	unsafe extern "C" fn get_instance_proc_addr<L: Layer>(instance: VkInstance, pName: *const u8) -> PFN_vkVoidFunction {
+		let name = CStr::from_ptr(pName as *const _).to_bytes();
+		if name == b"vkGetInstanceProcAddr" || name == b"vkCreateInstance" {
+			return intercept_address::<L>(name);
+		}
+		if instance == VK_NULL_HANDLE {
+			return ptr::null();
+		}
+		let dispatch = match INSTANCES.read().unwrap().get(&dispatch_key(instance)) {
+			Some(dispatch) => dispatch.clone(),
+			None => return ptr::null()
+		};
+		next_or_intercept::<L>(name, dispatch.GetInstanceProcAddr.expect("vkGetInstanceProcAddr is not loaded")(instance, pName))
+	}
+
+	unsafe extern "C" fn get_device_proc_addr<L: Layer>(device: VkDevice, pName: *const u8) -> PFN_vkVoidFunction {
+		let name = CStr::from_ptr(pName as *const _).to_bytes();
+		if name == b"vkGetDeviceProcAddr" {
+			return intercept_address::<L>(name);
+		}
+		let dispatch = match DEVICES.read().unwrap().get(&dispatch_key(device)) {
+			Some(dispatch) => dispatch.clone(),
+			None => return ptr::null()
+		};
+		next_or_intercept::<L>(name, dispatch.GetDeviceProcAddr.expect("vkGetDeviceProcAddr is not loaded")(device, pName))
+	}
+
+	unsafe extern "C" fn create_instance<L: Layer>(pCreateInfo: *const VkInstanceCreateInfo, pAllocator: *const VkAllocationCallbacks, pInstance: *mut VkInstance) -> VkResult {
+		let layer_info = find_link_info::<VkLayerInstanceCreateInfo>((*pCreateInfo).pNext, VK_STRUCTURE_TYPE_LOADER_INSTANCE_CREATE_INFO);
+		if layer_info.is_null() {
+			return VkResult::VK_ERROR_INITIALIZATION_FAILED;
+		}
+
+		// Move the chain on to the next layer before calling down
+		let link = (*layer_info).pLayerInfo;
+		let next_get_instance_proc_addr = (*link).pfnNextGetInstanceProcAddr;
+		(*layer_info).pLayerInfo = (*link).pNext;
+
+		let result = L::get().vkCreateInstance(&InstanceDispatch::load(VK_NULL_HANDLE, next_get_instance_proc_addr), pCreateInfo, pAllocator, pInstance);
+		if result == VkResult::VK_SUCCESS {
+			INSTANCES.write().unwrap().insert(dispatch_key(*pInstance), Arc::new(InstanceDispatch::load(*pInstance, next_get_instance_proc_addr)));
+		}
+		result
+	}
+
+	unsafe extern "C" fn create_device<L: Layer>(physicalDevice: VkPhysicalDevice, pCreateInfo: *const VkDeviceCreateInfo, pAllocator: *const VkAllocationCallbacks, pDevice: *mut VkDevice) -> VkResult {
+		let layer_info = find_link_info::<VkLayerDeviceCreateInfo>((*pCreateInfo).pNext, VK_STRUCTURE_TYPE_LOADER_DEVICE_CREATE_INFO);
+		if layer_info.is_null() {
+			return VkResult::VK_ERROR_INITIALIZATION_FAILED;
+		}
+
+		// Move the chain on to the next layer before calling down
+		let link = (*layer_info).pLayerInfo;
+		let next_get_device_proc_addr = (*link).pfnNextGetDeviceProcAddr;
+		(*layer_info).pLayerInfo = (*link).pNext;
+
+		let result = L::get().vkCreateDevice(&instance_dispatch(physicalDevice), physicalDevice, pCreateInfo, pAllocator, pDevice);
+		if result == VkResult::VK_SUCCESS {
+			DEVICES.write().unwrap().insert(dispatch_key(*pDevice), Arc::new(DeviceDispatch::load(*pDevice, next_get_device_proc_addr)));
+		}
+		result
+	}
+
+	// Called from the vkNegotiateLoaderLayerInterfaceVersion that export_layer! exports
+	pub unsafe fn negotiate_loader_layer_interface_version<L: Layer>(pVersionStruct: *mut VkNegotiateLayerInterface) -> VkResult {
+		if pVersionStruct.is_null() || (*pVersionStruct).sType != LAYER_NEGOTIATE_INTERFACE_STRUCT || (*pVersionStruct).loaderLayerInterfaceVersion < LAYER_INTERFACE_VERSION {
+			return VkResult::VK_ERROR_INITIALIZATION_FAILED;
+		}
+		(*pVersionStruct).loaderLayerInterfaceVersion = LAYER_INTERFACE_VERSION;
+		(*pVersionStruct).pfnGetInstanceProcAddr = Some(get_instance_proc_addr::<L>);
+		(*pVersionStruct).pfnGetDeviceProcAddr = Some(get_device_proc_addr::<L>);
+		(*pVersionStruct).pfnGetPhysicalDeviceProcAddr = ptr::null();
+		VkResult::VK_SUCCESS
+	}
+
+	// Exports the entry point the loader looks for in a layer's library, i.e. vkraw::export_layer!(MyLayer);
+	#[macro_export]
+	macro_rules! export_layer {
+		($layer:ty) => {
+			#[no_mangle]
+			pub unsafe extern "C" fn vkNegotiateLoaderLayerInterfaceVersion(version_struct: *mut $crate::layer::VkNegotiateLayerInterface) -> $crate::VkResult {
+				$crate::layer::negotiate_loader_layer_interface_version::<$layer>(version_struct)
+			}
+		}
+	}
+
+	// The next layer's commands for an instance
+	pub struct InstanceDispatch {
+		pub instance: VkInstance,
+		pub CreateInstance: Option<extern "C" fn(pCreateInfo: *const VkInstanceCreateInfo, pAllocator: *const VkAllocationCallbacks, pInstance: *mut VkInstance, ) -> VkResult>,
+		pub DestroyInstance: Option<extern "C" fn(instance:  VkInstance, pAllocator: *const VkAllocationCallbacks, )>,
+		pub EnumeratePhysicalDevices: Option<extern "C" fn(instance:  VkInstance, pPhysicalDeviceCount: *mut u32, pPhysicalDevices: *mut VkPhysicalDevice, ) -> VkResult>,
+		pub GetPhysicalDeviceFeatures: Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures, )>,
+		pub GetPhysicalDeviceProperties: Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, pProperties: *mut VkPhysicalDeviceProperties, )>,
+		pub GetPhysicalDeviceQueueFamilyProperties: Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, pQueueFamilyPropertyCount: *mut u32, pQueueFamilyProperties: *mut VkQueueFamilyProperties, )>,
+		pub GetPhysicalDeviceMemoryProperties: Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, pMemoryProperties: *mut VkPhysicalDeviceMemoryProperties, )>,
+		pub GetInstanceProcAddr: Option<extern "C" fn(instance:  VkInstance, pName: *const u8, ) -> PFN_vkVoidFunction>,
+		pub CreateDevice: Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, pCreateInfo: *const VkDeviceCreateInfo, pAllocator: *const VkAllocationCallbacks, pDevice: *mut VkDevice, ) -> VkResult>,
+		pub EnumerateDeviceExtensionProperties: Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, pLayerName: *const u8, pPropertyCount: *mut u32, pProperties: *mut VkExtensionProperties, ) -> VkResult>,
+		pub GetPhysicalDeviceFeatures2: Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures2, )>,
+		pub GetPhysicalDeviceQueueFamilyProperties2: Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, pQueueFamilyPropertyCount: *mut u32, pQueueFamilyProperties: *mut VkQueueFamilyProperties2, )>,
+#[cfg(feature = "VK_KHR_surface")]
+		pub DestroySurfaceKHR: Option<extern "C" fn(instance:  VkInstance, surface:  VkSurfaceKHR, pAllocator: *const VkAllocationCallbacks, )>,
+#[cfg(feature = "VK_KHR_surface")]
+		pub GetPhysicalDeviceSurfaceSupportKHR: Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, queueFamilyIndex:  u32, surface:  VkSurfaceKHR, pSupported: *mut VkBool32, ) -> VkResult>,
+#[cfg(feature = "VK_KHR_surface")]
+		pub GetPhysicalDeviceSurfaceCapabilitiesKHR: Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pSurfaceCapabilities: *mut VkSurfaceCapabilitiesKHR, ) -> VkResult>,
+#[cfg(feature = "VK_KHR_surface")]
+		pub GetPhysicalDeviceSurfaceFormatsKHR: Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pSurfaceFormatCount: *mut u32, pSurfaceFormats: *mut VkSurfaceFormatKHR, ) -> VkResult>,
+#[cfg(feature = "VK_KHR_surface")]
+		pub GetPhysicalDeviceSurfacePresentModesKHR: Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pPresentModeCount: *mut u32, pPresentModes: *mut VkPresentModeKHR, ) -> VkResult>,
+#[cfg(feature = "VK_KHR_xcb_surface")]
+		pub CreateXcbSurfaceKHR: Option<extern "C" fn(instance:  VkInstance, pCreateInfo: *const VkXcbSurfaceCreateInfoKHR, pAllocator: *const VkAllocationCallbacks, pSurface: *mut VkSurfaceKHR, ) -> VkResult>,
+#[cfg(feature = "VK_KHR_xcb_surface")]
+		pub GetPhysicalDeviceXcbPresentationSupportKHR: Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, queueFamilyIndex:  u32, connection: *mut xcb_connection_t, visual_id:  xcb_visualid_t, ) -> VkBool32>,
+#[cfg(feature = "VK_EXT_debug_report")]
+		pub CreateDebugReportCallbackEXT: Option<extern "C" fn(instance:  VkInstance, pCreateInfo: *const VkDebugReportCallbackCreateInfoEXT, pAllocator: *const VkAllocationCallbacks, pCallback: *mut VkDebugReportCallbackEXT, ) -> VkResult>,
+#[cfg(feature = "VK_EXT_debug_report")]
+		pub DestroyDebugReportCallbackEXT: Option<extern "C" fn(instance:  VkInstance, callback:  VkDebugReportCallbackEXT, pAllocator: *const VkAllocationCallbacks, )>,
+	}
+
+	impl InstanceDispatch {
+		pub unsafe fn load(instance: VkInstance, get_proc_addr: extern "C" fn(instance:  VkInstance, pName: *const u8, ) -> PFN_vkVoidFunction) -> InstanceDispatch {
+			InstanceDispatch {
+				instance: instance,
+				CreateInstance: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(pCreateInfo: *const VkInstanceCreateInfo, pAllocator: *const VkAllocationCallbacks, pInstance: *mut VkInstance, ) -> VkResult>>(get_proc_addr(instance, "vkCreateInstance\0".as_ptr())),
+				DestroyInstance: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(instance:  VkInstance, pAllocator: *const VkAllocationCallbacks, )>>(get_proc_addr(instance, "vkDestroyInstance\0".as_ptr())),
+				EnumeratePhysicalDevices: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(instance:  VkInstance, pPhysicalDeviceCount: *mut u32, pPhysicalDevices: *mut VkPhysicalDevice, ) -> VkResult>>(get_proc_addr(instance, "vkEnumeratePhysicalDevices\0".as_ptr())),
+				GetPhysicalDeviceFeatures: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures, )>>(get_proc_addr(instance, "vkGetPhysicalDeviceFeatures\0".as_ptr())),
+				GetPhysicalDeviceProperties: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, pProperties: *mut VkPhysicalDeviceProperties, )>>(get_proc_addr(instance, "vkGetPhysicalDeviceProperties\0".as_ptr())),
+				GetPhysicalDeviceQueueFamilyProperties: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, pQueueFamilyPropertyCount: *mut u32, pQueueFamilyProperties: *mut VkQueueFamilyProperties, )>>(get_proc_addr(instance, "vkGetPhysicalDeviceQueueFamilyProperties\0".as_ptr())),
+				GetPhysicalDeviceMemoryProperties: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, pMemoryProperties: *mut VkPhysicalDeviceMemoryProperties, )>>(get_proc_addr(instance, "vkGetPhysicalDeviceMemoryProperties\0".as_ptr())),
+				GetInstanceProcAddr: Some(get_proc_addr),
+				CreateDevice: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, pCreateInfo: *const VkDeviceCreateInfo, pAllocator: *const VkAllocationCallbacks, pDevice: *mut VkDevice, ) -> VkResult>>(get_proc_addr(instance, "vkCreateDevice\0".as_ptr())),
+				EnumerateDeviceExtensionProperties: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, pLayerName: *const u8, pPropertyCount: *mut u32, pProperties: *mut VkExtensionProperties, ) -> VkResult>>(get_proc_addr(instance, "vkEnumerateDeviceExtensionProperties\0".as_ptr())),
+				GetPhysicalDeviceFeatures2: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures2, )>>(first_proc_addr(&["vkGetPhysicalDeviceFeatures2\0", "vkGetPhysicalDeviceFeatures2KHR\0"], &|name| get_proc_addr(instance, name))),
+				GetPhysicalDeviceQueueFamilyProperties2: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, pQueueFamilyPropertyCount: *mut u32, pQueueFamilyProperties: *mut VkQueueFamilyProperties2, )>>(get_proc_addr(instance, "vkGetPhysicalDeviceQueueFamilyProperties2\0".as_ptr())),
+#[cfg(feature = "VK_KHR_surface")]
+				DestroySurfaceKHR: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(instance:  VkInstance, surface:  VkSurfaceKHR, pAllocator: *const VkAllocationCallbacks, )>>(get_proc_addr(instance, "vkDestroySurfaceKHR\0".as_ptr())),
+#[cfg(feature = "VK_KHR_surface")]
+				GetPhysicalDeviceSurfaceSupportKHR: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, queueFamilyIndex:  u32, surface:  VkSurfaceKHR, pSupported: *mut VkBool32, ) -> VkResult>>(get_proc_addr(instance, "vkGetPhysicalDeviceSurfaceSupportKHR\0".as_ptr())),
+#[cfg(feature = "VK_KHR_surface")]
+				GetPhysicalDeviceSurfaceCapabilitiesKHR: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pSurfaceCapabilities: *mut VkSurfaceCapabilitiesKHR, ) -> VkResult>>(get_proc_addr(instance, "vkGetPhysicalDeviceSurfaceCapabilitiesKHR\0".as_ptr())),
+#[cfg(feature = "VK_KHR_surface")]
+				GetPhysicalDeviceSurfaceFormatsKHR: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pSurfaceFormatCount: *mut u32, pSurfaceFormats: *mut VkSurfaceFormatKHR, ) -> VkResult>>(get_proc_addr(instance, "vkGetPhysicalDeviceSurfaceFormatsKHR\0".as_ptr())),
+#[cfg(feature = "VK_KHR_surface")]
+				GetPhysicalDeviceSurfacePresentModesKHR: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pPresentModeCount: *mut u32, pPresentModes: *mut VkPresentModeKHR, ) -> VkResult>>(get_proc_addr(instance, "vkGetPhysicalDeviceSurfacePresentModesKHR\0".as_ptr())),
+#[cfg(feature = "VK_KHR_xcb_surface")]
+				CreateXcbSurfaceKHR: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(instance:  VkInstance, pCreateInfo: *const VkXcbSurfaceCreateInfoKHR, pAllocator: *const VkAllocationCallbacks, pSurface: *mut VkSurfaceKHR, ) -> VkResult>>(get_proc_addr(instance, "vkCreateXcbSurfaceKHR\0".as_ptr())),
+#[cfg(feature = "VK_KHR_xcb_surface")]
+				GetPhysicalDeviceXcbPresentationSupportKHR: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(physicalDevice:  VkPhysicalDevice, queueFamilyIndex:  u32, connection: *mut xcb_connection_t, visual_id:  xcb_visualid_t, ) -> VkBool32>>(get_proc_addr(instance, "vkGetPhysicalDeviceXcbPresentationSupportKHR\0".as_ptr())),
+#[cfg(feature = "VK_EXT_debug_report")]
+				CreateDebugReportCallbackEXT: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(instance:  VkInstance, pCreateInfo: *const VkDebugReportCallbackCreateInfoEXT, pAllocator: *const VkAllocationCallbacks, pCallback: *mut VkDebugReportCallbackEXT, ) -> VkResult>>(get_proc_addr(instance, "vkCreateDebugReportCallbackEXT\0".as_ptr())),
+#[cfg(feature = "VK_EXT_debug_report")]
+				DestroyDebugReportCallbackEXT: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(instance:  VkInstance, callback:  VkDebugReportCallbackEXT, pAllocator: *const VkAllocationCallbacks, )>>(get_proc_addr(instance, "vkDestroyDebugReportCallbackEXT\0".as_ptr())),
+			}
+		}
+
+		pub unsafe fn vkCreateInstance(&self, pCreateInfo: *const VkInstanceCreateInfo, pAllocator: *const VkAllocationCallbacks, pInstance: *mut VkInstance, ) -> VkResult {
+			self.CreateInstance.expect("vkCreateInstance is not loaded")(pCreateInfo, pAllocator, pInstance)
+		}
+
+		pub unsafe fn vkDestroyInstance(&self, instance:  VkInstance, pAllocator: *const VkAllocationCallbacks, ) {
+			self.DestroyInstance.expect("vkDestroyInstance is not loaded")(instance, pAllocator)
+		}
+
+		pub unsafe fn vkEnumeratePhysicalDevices(&self, instance:  VkInstance, pPhysicalDeviceCount: *mut u32, pPhysicalDevices: *mut VkPhysicalDevice, ) -> VkResult {
+			self.EnumeratePhysicalDevices.expect("vkEnumeratePhysicalDevices is not loaded")(instance, pPhysicalDeviceCount, pPhysicalDevices)
+		}
+
+		pub unsafe fn vkGetPhysicalDeviceFeatures(&self, physicalDevice:  VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures, ) {
+			self.GetPhysicalDeviceFeatures.expect("vkGetPhysicalDeviceFeatures is not loaded")(physicalDevice, pFeatures)
+		}
+
+		pub unsafe fn vkGetPhysicalDeviceProperties(&self, physicalDevice:  VkPhysicalDevice, pProperties: *mut VkPhysicalDeviceProperties, ) {
+			self.GetPhysicalDeviceProperties.expect("vkGetPhysicalDeviceProperties is not loaded")(physicalDevice, pProperties)
+		}
+
+		pub unsafe fn vkGetPhysicalDeviceQueueFamilyProperties(&self, physicalDevice:  VkPhysicalDevice, pQueueFamilyPropertyCount: *mut u32, pQueueFamilyProperties: *mut VkQueueFamilyProperties, ) {
+			self.GetPhysicalDeviceQueueFamilyProperties.expect("vkGetPhysicalDeviceQueueFamilyProperties is not loaded")(physicalDevice, pQueueFamilyPropertyCount, pQueueFamilyProperties)
+		}
+
+		pub unsafe fn vkGetPhysicalDeviceMemoryProperties(&self, physicalDevice:  VkPhysicalDevice, pMemoryProperties: *mut VkPhysicalDeviceMemoryProperties, ) {
+			self.GetPhysicalDeviceMemoryProperties.expect("vkGetPhysicalDeviceMemoryProperties is not loaded")(physicalDevice, pMemoryProperties)
+		}
+
+		pub unsafe fn vkGetInstanceProcAddr(&self, instance:  VkInstance, pName: *const u8, ) -> PFN_vkVoidFunction {
+			self.GetInstanceProcAddr.expect("vkGetInstanceProcAddr is not loaded")(instance, pName)
+		}
+
+		pub unsafe fn vkCreateDevice(&self, physicalDevice:  VkPhysicalDevice, pCreateInfo: *const VkDeviceCreateInfo, pAllocator: *const VkAllocationCallbacks, pDevice: *mut VkDevice, ) -> VkResult {
+			self.CreateDevice.expect("vkCreateDevice is not loaded")(physicalDevice, pCreateInfo, pAllocator, pDevice)
+		}
+
+		pub unsafe fn vkEnumerateDeviceExtensionProperties(&self, physicalDevice:  VkPhysicalDevice, pLayerName: *const u8, pPropertyCount: *mut u32, pProperties: *mut VkExtensionProperties, ) -> VkResult {
+			self.EnumerateDeviceExtensionProperties.expect("vkEnumerateDeviceExtensionProperties is not loaded")(physicalDevice, pLayerName, pPropertyCount, pProperties)
+		}
+
+		pub unsafe fn vkGetPhysicalDeviceFeatures2(&self, physicalDevice:  VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures2, ) {
+			self.GetPhysicalDeviceFeatures2.expect("vkGetPhysicalDeviceFeatures2 is not loaded")(physicalDevice, pFeatures)
+		}
+
+		pub unsafe fn vkGetPhysicalDeviceQueueFamilyProperties2(&self, physicalDevice:  VkPhysicalDevice, pQueueFamilyPropertyCount: *mut u32, pQueueFamilyProperties: *mut VkQueueFamilyProperties2, ) {
+			self.GetPhysicalDeviceQueueFamilyProperties2.expect("vkGetPhysicalDeviceQueueFamilyProperties2 is not loaded")(physicalDevice, pQueueFamilyPropertyCount, pQueueFamilyProperties)
+		}
+
+#[cfg(feature = "VK_KHR_surface")]
+		pub unsafe fn vkDestroySurfaceKHR(&self, instance:  VkInstance, surface:  VkSurfaceKHR, pAllocator: *const VkAllocationCallbacks, ) {
+			self.DestroySurfaceKHR.expect("vkDestroySurfaceKHR is not loaded")(instance, surface, pAllocator)
+		}
+
+#[cfg(feature = "VK_KHR_surface")]
+		pub unsafe fn vkGetPhysicalDeviceSurfaceSupportKHR(&self, physicalDevice:  VkPhysicalDevice, queueFamilyIndex:  u32, surface:  VkSurfaceKHR, pSupported: *mut VkBool32, ) -> VkResult {
+			self.GetPhysicalDeviceSurfaceSupportKHR.expect("vkGetPhysicalDeviceSurfaceSupportKHR is not loaded")(physicalDevice, queueFamilyIndex, surface, pSupported)
+		}
+
+#[cfg(feature = "VK_KHR_surface")]
+		pub unsafe fn vkGetPhysicalDeviceSurfaceCapabilitiesKHR(&self, physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pSurfaceCapabilities: *mut VkSurfaceCapabilitiesKHR, ) -> VkResult {
+			self.GetPhysicalDeviceSurfaceCapabilitiesKHR.expect("vkGetPhysicalDeviceSurfaceCapabilitiesKHR is not loaded")(physicalDevice, surface, pSurfaceCapabilities)
+		}
+
+#[cfg(feature = "VK_KHR_surface")]
+		pub unsafe fn vkGetPhysicalDeviceSurfaceFormatsKHR(&self, physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pSurfaceFormatCount: *mut u32, pSurfaceFormats: *mut VkSurfaceFormatKHR, ) -> VkResult {
+			self.GetPhysicalDeviceSurfaceFormatsKHR.expect("vkGetPhysicalDeviceSurfaceFormatsKHR is not loaded")(physicalDevice, surface, pSurfaceFormatCount, pSurfaceFormats)
+		}
+
+#[cfg(feature = "VK_KHR_surface")]
+		pub unsafe fn vkGetPhysicalDeviceSurfacePresentModesKHR(&self, physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pPresentModeCount: *mut u32, pPresentModes: *mut VkPresentModeKHR, ) -> VkResult {
+			self.GetPhysicalDeviceSurfacePresentModesKHR.expect("vkGetPhysicalDeviceSurfacePresentModesKHR is not loaded")(physicalDevice, surface, pPresentModeCount, pPresentModes)
+		}
+
+#[cfg(feature = "VK_KHR_xcb_surface")]
+		pub unsafe fn vkCreateXcbSurfaceKHR(&self, instance:  VkInstance, pCreateInfo: *const VkXcbSurfaceCreateInfoKHR, pAllocator: *const VkAllocationCallbacks, pSurface: *mut VkSurfaceKHR, ) -> VkResult {
+			self.CreateXcbSurfaceKHR.expect("vkCreateXcbSurfaceKHR is not loaded")(instance, pCreateInfo, pAllocator, pSurface)
+		}
+
+#[cfg(feature = "VK_KHR_xcb_surface")]
+		pub unsafe fn vkGetPhysicalDeviceXcbPresentationSupportKHR(&self, physicalDevice:  VkPhysicalDevice, queueFamilyIndex:  u32, connection: *mut xcb_connection_t, visual_id:  xcb_visualid_t, ) -> VkBool32 {
+			self.GetPhysicalDeviceXcbPresentationSupportKHR.expect("vkGetPhysicalDeviceXcbPresentationSupportKHR is not loaded")(physicalDevice, queueFamilyIndex, connection, visual_id)
+		}
+
+#[cfg(feature = "VK_EXT_debug_report")]
+		pub unsafe fn vkCreateDebugReportCallbackEXT(&self, instance:  VkInstance, pCreateInfo: *const VkDebugReportCallbackCreateInfoEXT, pAllocator: *const VkAllocationCallbacks, pCallback: *mut VkDebugReportCallbackEXT, ) -> VkResult {
+			self.CreateDebugReportCallbackEXT.expect("vkCreateDebugReportCallbackEXT is not loaded")(instance, pCreateInfo, pAllocator, pCallback)
+		}
+
+#[cfg(feature = "VK_EXT_debug_report")]
+		pub unsafe fn vkDestroyDebugReportCallbackEXT(&self, instance:  VkInstance, callback:  VkDebugReportCallbackEXT, pAllocator: *const VkAllocationCallbacks, ) {
+			self.DestroyDebugReportCallbackEXT.expect("vkDestroyDebugReportCallbackEXT is not loaded")(instance, callback, pAllocator)
+		}
+	}
+
+	// The next layer's commands for a device
+	pub struct DeviceDispatch {
+		pub device: VkDevice,
+		pub GetDeviceProcAddr: Option<extern "C" fn(device:  VkDevice, pName: *const u8, ) -> PFN_vkVoidFunction>,
+		pub DestroyDevice: Option<extern "C" fn(device:  VkDevice, pAllocator: *const VkAllocationCallbacks, )>,
+		pub GetDeviceQueue: Option<extern "C" fn(device:  VkDevice, queueFamilyIndex:  u32, queueIndex:  u32, pQueue: *mut VkQueue, )>,
+		pub QueueWaitIdle: Option<extern "C" fn(queue:  VkQueue, ) -> VkResult>,
+		pub AllocateMemory: Option<extern "C" fn(device:  VkDevice, pAllocateInfo: *const VkMemoryAllocateInfo, pAllocator: *const VkAllocationCallbacks, pMemory: *mut VkDeviceMemory, ) -> VkResult>,
+		pub FreeMemory: Option<extern "C" fn(device:  VkDevice, memory:  VkDeviceMemory, pAllocator: *const VkAllocationCallbacks, )>,
+		pub MapMemory: Option<extern "C" fn(device:  VkDevice, memory:  VkDeviceMemory, offset:  VkDeviceSize, size:  VkDeviceSize, flags:  VkMemoryMapFlags, ppData: *mut*mut c_void, ) -> VkResult>,
+		pub CreateBuffer: Option<extern "C" fn(device:  VkDevice, pCreateInfo: *const VkBufferCreateInfo, pAllocator: *const VkAllocationCallbacks, pBuffer: *mut VkBuffer, ) -> VkResult>,
+		pub DestroyBuffer: Option<extern "C" fn(device:  VkDevice, buffer:  VkBuffer, pAllocator: *const VkAllocationCallbacks, )>,
+		pub DestroyImage: Option<extern "C" fn(device:  VkDevice, image:  VkImage, pAllocator: *const VkAllocationCallbacks, )>,
+		pub CreateFence: Option<extern "C" fn(device:  VkDevice, pCreateInfo: *const VkFenceCreateInfo, pAllocator: *const VkAllocationCallbacks, pFence: *mut VkFence, ) -> VkResult>,
+		pub DestroyFence: Option<extern "C" fn(device:  VkDevice, fence:  VkFence, pAllocator: *const VkAllocationCallbacks, )>,
+		pub WaitForFences: Option<extern "C" fn(device:  VkDevice, fenceCount:  u32, pFences: *const VkFence, waitAll:  VkBool32, timeout:  u64, ) -> VkResult>,
+		pub DestroySemaphore: Option<extern "C" fn(device:  VkDevice, semaphore:  VkSemaphore, pAllocator: *const VkAllocationCallbacks, )>,
+		pub CreateCommandPool: Option<extern "C" fn(device:  VkDevice, pCreateInfo: *const VkCommandPoolCreateInfo, pAllocator: *const VkAllocationCallbacks, pCommandPool: *mut VkCommandPool, ) -> VkResult>,
+		pub DestroyCommandPool: Option<extern "C" fn(device:  VkDevice, commandPool:  VkCommandPool, pAllocator: *const VkAllocationCallbacks, )>,
+		pub FreeCommandBuffers: Option<extern "C" fn(device:  VkDevice, commandPool:  VkCommandPool, commandBufferCount:  u32, pCommandBuffers: *const VkCommandBuffer, )>,
+#[cfg(feature = "VK_KHR_swapchain")]
+		pub CreateSwapchainKHR: Option<extern "C" fn(device:  VkDevice, pCreateInfo: *const VkSwapchainCreateInfoKHR, pAllocator: *const VkAllocationCallbacks, pSwapchain: *mut VkSwapchainKHR, ) -> VkResult>,
+#[cfg(feature = "VK_KHR_swapchain")]
+		pub DestroySwapchainKHR: Option<extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, pAllocator: *const VkAllocationCallbacks, )>,
+#[cfg(feature = "VK_KHR_swapchain")]
+		pub GetSwapchainImagesKHR: Option<extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, pSwapchainImageCount: *mut u32, pSwapchainImages: *mut VkImage, ) -> VkResult>,
+#[cfg(feature = "VK_KHR_swapchain")]
+		pub AcquireNextImageKHR: Option<extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, timeout:  u64, semaphore:  VkSemaphore, fence:  VkFence, pImageIndex: *mut u32, ) -> VkResult>,
+#[cfg(all(feature = "VK_KHR_swapchain", feature = "VK_KHR_get_physical_device_properties2", any(feature = "VK_EXT_debug_report", feature = "VK_KHR_surface")))]
+		pub GetSwapchainStatusKHR: Option<extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, ) -> VkResult>,
+	}
+
+	impl DeviceDispatch {
+		pub unsafe fn load(device: VkDevice, get_proc_addr: extern "C" fn(device:  VkDevice, pName: *const u8, ) -> PFN_vkVoidFunction) -> DeviceDispatch {
+			DeviceDispatch {
+				device: device,
+				GetDeviceProcAddr: Some(get_proc_addr),
+				DestroyDevice: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(device:  VkDevice, pAllocator: *const VkAllocationCallbacks, )>>(get_proc_addr(device, "vkDestroyDevice\0".as_ptr())),
+				GetDeviceQueue: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(device:  VkDevice, queueFamilyIndex:  u32, queueIndex:  u32, pQueue: *mut VkQueue, )>>(get_proc_addr(device, "vkGetDeviceQueue\0".as_ptr())),
+				QueueWaitIdle: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(queue:  VkQueue, ) -> VkResult>>(get_proc_addr(device, "vkQueueWaitIdle\0".as_ptr())),
+				AllocateMemory: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(device:  VkDevice, pAllocateInfo: *const VkMemoryAllocateInfo, pAllocator: *const VkAllocationCallbacks, pMemory: *mut VkDeviceMemory, ) -> VkResult>>(get_proc_addr(device, "vkAllocateMemory\0".as_ptr())),
+				FreeMemory: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(device:  VkDevice, memory:  VkDeviceMemory, pAllocator: *const VkAllocationCallbacks, )>>(get_proc_addr(device, "vkFreeMemory\0".as_ptr())),
+				MapMemory: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(device:  VkDevice, memory:  VkDeviceMemory, offset:  VkDeviceSize, size:  VkDeviceSize, flags:  VkMemoryMapFlags, ppData: *mut*mut c_void, ) -> VkResult>>(get_proc_addr(device, "vkMapMemory\0".as_ptr())),
+				CreateBuffer: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(device:  VkDevice, pCreateInfo: *const VkBufferCreateInfo, pAllocator: *const VkAllocationCallbacks, pBuffer: *mut VkBuffer, ) -> VkResult>>(get_proc_addr(device, "vkCreateBuffer\0".as_ptr())),
+				DestroyBuffer: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(device:  VkDevice, buffer:  VkBuffer, pAllocator: *const VkAllocationCallbacks, )>>(get_proc_addr(device, "vkDestroyBuffer\0".as_ptr())),
+				DestroyImage: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(device:  VkDevice, image:  VkImage, pAllocator: *const VkAllocationCallbacks, )>>(get_proc_addr(device, "vkDestroyImage\0".as_ptr())),
+				CreateFence: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(device:  VkDevice, pCreateInfo: *const VkFenceCreateInfo, pAllocator: *const VkAllocationCallbacks, pFence: *mut VkFence, ) -> VkResult>>(get_proc_addr(device, "vkCreateFence\0".as_ptr())),
+				DestroyFence: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(device:  VkDevice, fence:  VkFence, pAllocator: *const VkAllocationCallbacks, )>>(get_proc_addr(device, "vkDestroyFence\0".as_ptr())),
+				WaitForFences: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(device:  VkDevice, fenceCount:  u32, pFences: *const VkFence, waitAll:  VkBool32, timeout:  u64, ) -> VkResult>>(get_proc_addr(device, "vkWaitForFences\0".as_ptr())),
+				DestroySemaphore: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(device:  VkDevice, semaphore:  VkSemaphore, pAllocator: *const VkAllocationCallbacks, )>>(get_proc_addr(device, "vkDestroySemaphore\0".as_ptr())),
+				CreateCommandPool: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(device:  VkDevice, pCreateInfo: *const VkCommandPoolCreateInfo, pAllocator: *const VkAllocationCallbacks, pCommandPool: *mut VkCommandPool, ) -> VkResult>>(get_proc_addr(device, "vkCreateCommandPool\0".as_ptr())),
+				DestroyCommandPool: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(device:  VkDevice, commandPool:  VkCommandPool, pAllocator: *const VkAllocationCallbacks, )>>(get_proc_addr(device, "vkDestroyCommandPool\0".as_ptr())),
+				FreeCommandBuffers: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(device:  VkDevice, commandPool:  VkCommandPool, commandBufferCount:  u32, pCommandBuffers: *const VkCommandBuffer, )>>(get_proc_addr(device, "vkFreeCommandBuffers\0".as_ptr())),
+#[cfg(feature = "VK_KHR_swapchain")]
+				CreateSwapchainKHR: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(device:  VkDevice, pCreateInfo: *const VkSwapchainCreateInfoKHR, pAllocator: *const VkAllocationCallbacks, pSwapchain: *mut VkSwapchainKHR, ) -> VkResult>>(get_proc_addr(device, "vkCreateSwapchainKHR\0".as_ptr())),
+#[cfg(feature = "VK_KHR_swapchain")]
+				DestroySwapchainKHR: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, pAllocator: *const VkAllocationCallbacks, )>>(get_proc_addr(device, "vkDestroySwapchainKHR\0".as_ptr())),
+#[cfg(feature = "VK_KHR_swapchain")]
+				GetSwapchainImagesKHR: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, pSwapchainImageCount: *mut u32, pSwapchainImages: *mut VkImage, ) -> VkResult>>(get_proc_addr(device, "vkGetSwapchainImagesKHR\0".as_ptr())),
+#[cfg(feature = "VK_KHR_swapchain")]
+				AcquireNextImageKHR: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, timeout:  u64, semaphore:  VkSemaphore, fence:  VkFence, pImageIndex: *mut u32, ) -> VkResult>>(get_proc_addr(device, "vkAcquireNextImageKHR\0".as_ptr())),
+#[cfg(all(feature = "VK_KHR_swapchain", feature = "VK_KHR_get_physical_device_properties2", any(feature = "VK_EXT_debug_report", feature = "VK_KHR_surface")))]
+				GetSwapchainStatusKHR: ::core::mem::transmute::<PFN_vkVoidFunction, Option<extern "C" fn(device:  VkDevice, swapchain:  VkSwapchainKHR, ) -> VkResult>>(get_proc_addr(device, "vkGetSwapchainStatusKHR\0".as_ptr())),
+			}
+		}
+
+		pub unsafe fn vkGetDeviceProcAddr(&self, device:  VkDevice, pName: *const u8, ) -> PFN_vkVoidFunction {
+			self.GetDeviceProcAddr.expect("vkGetDeviceProcAddr is not loaded")(device, pName)
+		}
+
+		pub unsafe fn vkDestroyDevice(&self, device:  VkDevice, pAllocator: *const VkAllocationCallbacks, ) {
+			self.DestroyDevice.expect("vkDestroyDevice is not loaded")(device, pAllocator)
+		}
+
+		pub unsafe fn vkGetDeviceQueue(&self, device:  VkDevice, queueFamilyIndex:  u32, queueIndex:  u32, pQueue: *mut VkQueue, ) {
+			self.GetDeviceQueue.expect("vkGetDeviceQueue is not loaded")(device, queueFamilyIndex, queueIndex, pQueue)
+		}
+
+		pub unsafe fn vkQueueWaitIdle(&self, queue:  VkQueue, ) -> VkResult {
+			self.QueueWaitIdle.expect("vkQueueWaitIdle is not loaded")(queue)
+		}
+
+		pub unsafe fn vkAllocateMemory(&self, device:  VkDevice, pAllocateInfo: *const VkMemoryAllocateInfo, pAllocator: *const VkAllocationCallbacks, pMemory: *mut VkDeviceMemory, ) -> VkResult {
+			self.AllocateMemory.expect("vkAllocateMemory is not loaded")(device, pAllocateInfo, pAllocator, pMemory)
+		}
+
+		pub unsafe fn vkFreeMemory(&self, device:  VkDevice, memory:  VkDeviceMemory, pAllocator: *const VkAllocationCallbacks, ) {
+			self.FreeMemory.expect("vkFreeMemory is not loaded")(device, memory, pAllocator)
+		}
+
+		pub unsafe fn vkMapMemory(&self, device:  VkDevice, memory:  VkDeviceMemory, offset:  VkDeviceSize, size:  VkDeviceSize, flags:  VkMemoryMapFlags, ppData: *mut*mut c_void, ) -> VkResult {
+			self.MapMemory.expect("vkMapMemory is not loaded")(device, memory, offset, size, flags, ppData)
+		}
+
+		pub unsafe fn vkCreateBuffer(&self, device:  VkDevice, pCreateInfo: *const VkBufferCreateInfo, pAllocator: *const VkAllocationCallbacks, pBuffer: *mut VkBuffer, ) -> VkResult {
+			self.CreateBuffer.expect("vkCreateBuffer is not loaded")(device, pCreateInfo, pAllocator, pBuffer)
+		}
+
+		pub unsafe fn vkDestroyBuffer(&self, device:  VkDevice, buffer:  VkBuffer, pAllocator: *const VkAllocationCallbacks, ) {
+			self.DestroyBuffer.expect("vkDestroyBuffer is not loaded")(device, buffer, pAllocator)
+		}
+
+		pub unsafe fn vkDestroyImage(&self, device:  VkDevice, image:  VkImage, pAllocator: *const VkAllocationCallbacks, ) {
+			self.DestroyImage.expect("vkDestroyImage is not loaded")(device, image, pAllocator)
+		}
+
+		pub unsafe fn vkCreateFence(&self, device:  VkDevice, pCreateInfo: *const VkFenceCreateInfo, pAllocator: *const VkAllocationCallbacks, pFence: *mut VkFence, ) -> VkResult {
+			self.CreateFence.expect("vkCreateFence is not loaded")(device, pCreateInfo, pAllocator, pFence)
+		}
+
+		pub unsafe fn vkDestroyFence(&self, device:  VkDevice, fence:  VkFence, pAllocator: *const VkAllocationCallbacks, ) {
+			self.DestroyFence.expect("vkDestroyFence is not loaded")(device, fence, pAllocator)
+		}
+
+		pub unsafe fn vkWaitForFences(&self, device:  VkDevice, fenceCount:  u32, pFences: *const VkFence, waitAll:  VkBool32, timeout:  u64, ) -> VkResult {
+			self.WaitForFences.expect("vkWaitForFences is not loaded")(device, fenceCount, pFences, waitAll, timeout)
+		}
+
+		pub unsafe fn vkDestroySemaphore(&self, device:  VkDevice, semaphore:  VkSemaphore, pAllocator: *const VkAllocationCallbacks, ) {
+			self.DestroySemaphore.expect("vkDestroySemaphore is not loaded")(device, semaphore, pAllocator)
+		}
+
+		pub unsafe fn vkCreateCommandPool(&self, device:  VkDevice, pCreateInfo: *const VkCommandPoolCreateInfo, pAllocator: *const VkAllocationCallbacks, pCommandPool: *mut VkCommandPool, ) -> VkResult {
+			self.CreateCommandPool.expect("vkCreateCommandPool is not loaded")(device, pCreateInfo, pAllocator, pCommandPool)
+		}
+
+		pub unsafe fn vkDestroyCommandPool(&self, device:  VkDevice, commandPool:  VkCommandPool, pAllocator: *const VkAllocationCallbacks, ) {
+			self.DestroyCommandPool.expect("vkDestroyCommandPool is not loaded")(device, commandPool, pAllocator)
+		}
+
+		pub unsafe fn vkFreeCommandBuffers(&self, device:  VkDevice, commandPool:  VkCommandPool, commandBufferCount:  u32, pCommandBuffers: *const VkCommandBuffer, ) {
+			self.FreeCommandBuffers.expect("vkFreeCommandBuffers is not loaded")(device, commandPool, commandBufferCount, pCommandBuffers)
+		}
+
+#[cfg(feature = "VK_KHR_swapchain")]
+		pub unsafe fn vkCreateSwapchainKHR(&self, device:  VkDevice, pCreateInfo: *const VkSwapchainCreateInfoKHR, pAllocator: *const VkAllocationCallbacks, pSwapchain: *mut VkSwapchainKHR, ) -> VkResult {
+			self.CreateSwapchainKHR.expect("vkCreateSwapchainKHR is not loaded")(device, pCreateInfo, pAllocator, pSwapchain)
+		}
+
+#[cfg(feature = "VK_KHR_swapchain")]
+		pub unsafe fn vkDestroySwapchainKHR(&self, device:  VkDevice, swapchain:  VkSwapchainKHR, pAllocator: *const VkAllocationCallbacks, ) {
+			self.DestroySwapchainKHR.expect("vkDestroySwapchainKHR is not loaded")(device, swapchain, pAllocator)
+		}
+
+#[cfg(feature = "VK_KHR_swapchain")]
+		pub unsafe fn vkGetSwapchainImagesKHR(&self, device:  VkDevice, swapchain:  VkSwapchainKHR, pSwapchainImageCount: *mut u32, pSwapchainImages: *mut VkImage, ) -> VkResult {
+			self.GetSwapchainImagesKHR.expect("vkGetSwapchainImagesKHR is not loaded")(device, swapchain, pSwapchainImageCount, pSwapchainImages)
+		}
+
+#[cfg(feature = "VK_KHR_swapchain")]
+		pub unsafe fn vkAcquireNextImageKHR(&self, device:  VkDevice, swapchain:  VkSwapchainKHR, timeout:  u64, semaphore:  VkSemaphore, fence:  VkFence, pImageIndex: *mut u32, ) -> VkResult {
+			self.AcquireNextImageKHR.expect("vkAcquireNextImageKHR is not loaded")(device, swapchain, timeout, semaphore, fence, pImageIndex)
+		}
+
+#[cfg(all(feature = "VK_KHR_swapchain", feature = "VK_KHR_get_physical_device_properties2", any(feature = "VK_EXT_debug_report", feature = "VK_KHR_surface")))]
+		pub unsafe fn vkGetSwapchainStatusKHR(&self, device:  VkDevice, swapchain:  VkSwapchainKHR, ) -> VkResult {
+			self.GetSwapchainStatusKHR.expect("vkGetSwapchainStatusKHR is not loaded")(device, swapchain)
+		}
+	}
+
+	// A layer, every command it does not override is passed on to the next layer
+	pub trait Layer: Sized + Sync + 'static {
+
+		// The layer the loader's calls go to
+		fn get() -> &'static Self;
+
+		unsafe fn vkCreateInstance(&self, dispatch: &InstanceDispatch, pCreateInfo: *const VkInstanceCreateInfo, pAllocator: *const VkAllocationCallbacks, pInstance: *mut VkInstance, ) -> VkResult {
+			dispatch.vkCreateInstance(pCreateInfo, pAllocator, pInstance)
+		}
+
+		unsafe fn vkDestroyInstance(&self, dispatch: &InstanceDispatch, instance:  VkInstance, pAllocator: *const VkAllocationCallbacks, ) {
+			dispatch.vkDestroyInstance(instance, pAllocator)
+		}
+
+		unsafe fn vkEnumeratePhysicalDevices(&self, dispatch: &InstanceDispatch, instance:  VkInstance, pPhysicalDeviceCount: *mut u32, pPhysicalDevices: *mut VkPhysicalDevice, ) -> VkResult {
+			dispatch.vkEnumeratePhysicalDevices(instance, pPhysicalDeviceCount, pPhysicalDevices)
+		}
+
+		unsafe fn vkGetPhysicalDeviceFeatures(&self, dispatch: &InstanceDispatch, physicalDevice:  VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures, ) {
+			dispatch.vkGetPhysicalDeviceFeatures(physicalDevice, pFeatures)
+		}
+
+		unsafe fn vkGetPhysicalDeviceProperties(&self, dispatch: &InstanceDispatch, physicalDevice:  VkPhysicalDevice, pProperties: *mut VkPhysicalDeviceProperties, ) {
+			dispatch.vkGetPhysicalDeviceProperties(physicalDevice, pProperties)
+		}
+
+		unsafe fn vkGetPhysicalDeviceQueueFamilyProperties(&self, dispatch: &InstanceDispatch, physicalDevice:  VkPhysicalDevice, pQueueFamilyPropertyCount: *mut u32, pQueueFamilyProperties: *mut VkQueueFamilyProperties, ) {
+			dispatch.vkGetPhysicalDeviceQueueFamilyProperties(physicalDevice, pQueueFamilyPropertyCount, pQueueFamilyProperties)
+		}
+
+		unsafe fn vkGetPhysicalDeviceMemoryProperties(&self, dispatch: &InstanceDispatch, physicalDevice:  VkPhysicalDevice, pMemoryProperties: *mut VkPhysicalDeviceMemoryProperties, ) {
+			dispatch.vkGetPhysicalDeviceMemoryProperties(physicalDevice, pMemoryProperties)
+		}
+
+		unsafe fn vkCreateDevice(&self, dispatch: &InstanceDispatch, physicalDevice:  VkPhysicalDevice, pCreateInfo: *const VkDeviceCreateInfo, pAllocator: *const VkAllocationCallbacks, pDevice: *mut VkDevice, ) -> VkResult {
+			dispatch.vkCreateDevice(physicalDevice, pCreateInfo, pAllocator, pDevice)
+		}
+
+		unsafe fn vkDestroyDevice(&self, dispatch: &DeviceDispatch, device:  VkDevice, pAllocator: *const VkAllocationCallbacks, ) {
+			dispatch.vkDestroyDevice(device, pAllocator)
+		}
+
+		unsafe fn vkEnumerateDeviceExtensionProperties(&self, dispatch: &InstanceDispatch, physicalDevice:  VkPhysicalDevice, pLayerName: *const u8, pPropertyCount: *mut u32, pProperties: *mut VkExtensionProperties, ) -> VkResult {
+			dispatch.vkEnumerateDeviceExtensionProperties(physicalDevice, pLayerName, pPropertyCount, pProperties)
+		}
+
+		unsafe fn vkGetDeviceQueue(&self, dispatch: &DeviceDispatch, device:  VkDevice, queueFamilyIndex:  u32, queueIndex:  u32, pQueue: *mut VkQueue, ) {
+			dispatch.vkGetDeviceQueue(device, queueFamilyIndex, queueIndex, pQueue)
+		}
+
+		unsafe fn vkQueueWaitIdle(&self, dispatch: &DeviceDispatch, queue:  VkQueue, ) -> VkResult {
+			dispatch.vkQueueWaitIdle(queue)
+		}
+
+		unsafe fn vkAllocateMemory(&self, dispatch: &DeviceDispatch, device:  VkDevice, pAllocateInfo: *const VkMemoryAllocateInfo, pAllocator: *const VkAllocationCallbacks, pMemory: *mut VkDeviceMemory, ) -> VkResult {
+			dispatch.vkAllocateMemory(device, pAllocateInfo, pAllocator, pMemory)
+		}
+
+		unsafe fn vkFreeMemory(&self, dispatch: &DeviceDispatch, device:  VkDevice, memory:  VkDeviceMemory, pAllocator: *const VkAllocationCallbacks, ) {
+			dispatch.vkFreeMemory(device, memory, pAllocator)
+		}
+
+		unsafe fn vkMapMemory(&self, dispatch: &DeviceDispatch, device:  VkDevice, memory:  VkDeviceMemory, offset:  VkDeviceSize, size:  VkDeviceSize, flags:  VkMemoryMapFlags, ppData: *mut*mut c_void, ) -> VkResult {
+			dispatch.vkMapMemory(device, memory, offset, size, flags, ppData)
+		}
+
+		unsafe fn vkCreateBuffer(&self, dispatch: &DeviceDispatch, device:  VkDevice, pCreateInfo: *const VkBufferCreateInfo, pAllocator: *const VkAllocationCallbacks, pBuffer: *mut VkBuffer, ) -> VkResult {
+			dispatch.vkCreateBuffer(device, pCreateInfo, pAllocator, pBuffer)
+		}
+
+		unsafe fn vkDestroyBuffer(&self, dispatch: &DeviceDispatch, device:  VkDevice, buffer:  VkBuffer, pAllocator: *const VkAllocationCallbacks, ) {
+			dispatch.vkDestroyBuffer(device, buffer, pAllocator)
+		}
+
+		unsafe fn vkDestroyImage(&self, dispatch: &DeviceDispatch, device:  VkDevice, image:  VkImage, pAllocator: *const VkAllocationCallbacks, ) {
+			dispatch.vkDestroyImage(device, image, pAllocator)
+		}
+
+		unsafe fn vkCreateFence(&self, dispatch: &DeviceDispatch, device:  VkDevice, pCreateInfo: *const VkFenceCreateInfo, pAllocator: *const VkAllocationCallbacks, pFence: *mut VkFence, ) -> VkResult {
+			dispatch.vkCreateFence(device, pCreateInfo, pAllocator, pFence)
+		}
+
+		unsafe fn vkDestroyFence(&self, dispatch: &DeviceDispatch, device:  VkDevice, fence:  VkFence, pAllocator: *const VkAllocationCallbacks, ) {
+			dispatch.vkDestroyFence(device, fence, pAllocator)
+		}
+
+		unsafe fn vkWaitForFences(&self, dispatch: &DeviceDispatch, device:  VkDevice, fenceCount:  u32, pFences: *const VkFence, waitAll:  VkBool32, timeout:  u64, ) -> VkResult {
+			dispatch.vkWaitForFences(device, fenceCount, pFences, waitAll, timeout)
+		}
+
+		unsafe fn vkDestroySemaphore(&self, dispatch: &DeviceDispatch, device:  VkDevice, semaphore:  VkSemaphore, pAllocator: *const VkAllocationCallbacks, ) {
+			dispatch.vkDestroySemaphore(device, semaphore, pAllocator)
+		}
+
+		unsafe fn vkCreateCommandPool(&self, dispatch: &DeviceDispatch, device:  VkDevice, pCreateInfo: *const VkCommandPoolCreateInfo, pAllocator: *const VkAllocationCallbacks, pCommandPool: *mut VkCommandPool, ) -> VkResult {
+			dispatch.vkCreateCommandPool(device, pCreateInfo, pAllocator, pCommandPool)
+		}
+
+		unsafe fn vkDestroyCommandPool(&self, dispatch: &DeviceDispatch, device:  VkDevice, commandPool:  VkCommandPool, pAllocator: *const VkAllocationCallbacks, ) {
+			dispatch.vkDestroyCommandPool(device, commandPool, pAllocator)
+		}
+
+		unsafe fn vkFreeCommandBuffers(&self, dispatch: &DeviceDispatch, device:  VkDevice, commandPool:  VkCommandPool, commandBufferCount:  u32, pCommandBuffers: *const VkCommandBuffer, ) {
+			dispatch.vkFreeCommandBuffers(device, commandPool, commandBufferCount, pCommandBuffers)
+		}
+
+		unsafe fn vkGetPhysicalDeviceFeatures2(&self, dispatch: &InstanceDispatch, physicalDevice:  VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures2, ) {
+			dispatch.vkGetPhysicalDeviceFeatures2(physicalDevice, pFeatures)
+		}
+
+		unsafe fn vkGetPhysicalDeviceQueueFamilyProperties2(&self, dispatch: &InstanceDispatch, physicalDevice:  VkPhysicalDevice, pQueueFamilyPropertyCount: *mut u32, pQueueFamilyProperties: *mut VkQueueFamilyProperties2, ) {
+			dispatch.vkGetPhysicalDeviceQueueFamilyProperties2(physicalDevice, pQueueFamilyPropertyCount, pQueueFamilyProperties)
+		}
+
+#[cfg(feature = "VK_KHR_surface")]
+		unsafe fn vkDestroySurfaceKHR(&self, dispatch: &InstanceDispatch, instance:  VkInstance, surface:  VkSurfaceKHR, pAllocator: *const VkAllocationCallbacks, ) {
+			dispatch.vkDestroySurfaceKHR(instance, surface, pAllocator)
+		}
+
+#[cfg(feature = "VK_KHR_surface")]
+		unsafe fn vkGetPhysicalDeviceSurfaceSupportKHR(&self, dispatch: &InstanceDispatch, physicalDevice:  VkPhysicalDevice, queueFamilyIndex:  u32, surface:  VkSurfaceKHR, pSupported: *mut VkBool32, ) -> VkResult {
+			dispatch.vkGetPhysicalDeviceSurfaceSupportKHR(physicalDevice, queueFamilyIndex, surface, pSupported)
+		}
+
+#[cfg(feature = "VK_KHR_surface")]
+		unsafe fn vkGetPhysicalDeviceSurfaceCapabilitiesKHR(&self, dispatch: &InstanceDispatch, physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pSurfaceCapabilities: *mut VkSurfaceCapabilitiesKHR, ) -> VkResult {
+			dispatch.vkGetPhysicalDeviceSurfaceCapabilitiesKHR(physicalDevice, surface, pSurfaceCapabilities)
+		}
+
+#[cfg(feature = "VK_KHR_surface")]
+		unsafe fn vkGetPhysicalDeviceSurfaceFormatsKHR(&self, dispatch: &InstanceDispatch, physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pSurfaceFormatCount: *mut u32, pSurfaceFormats: *mut VkSurfaceFormatKHR, ) -> VkResult {
+			dispatch.vkGetPhysicalDeviceSurfaceFormatsKHR(physicalDevice, surface, pSurfaceFormatCount, pSurfaceFormats)
+		}
+
+#[cfg(feature = "VK_KHR_surface")]
+		unsafe fn vkGetPhysicalDeviceSurfacePresentModesKHR(&self, dispatch: &InstanceDispatch, physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pPresentModeCount: *mut u32, pPresentModes: *mut VkPresentModeKHR, ) -> VkResult {
+			dispatch.vkGetPhysicalDeviceSurfacePresentModesKHR(physicalDevice, surface, pPresentModeCount, pPresentModes)
+		}
+
+#[cfg(feature = "VK_KHR_swapchain")]
+		unsafe fn vkCreateSwapchainKHR(&self, dispatch: &DeviceDispatch, device:  VkDevice, pCreateInfo: *const VkSwapchainCreateInfoKHR, pAllocator: *const VkAllocationCallbacks, pSwapchain: *mut VkSwapchainKHR, ) -> VkResult {
+			dispatch.vkCreateSwapchainKHR(device, pCreateInfo, pAllocator, pSwapchain)
+		}
+
+#[cfg(feature = "VK_KHR_swapchain")]
+		unsafe fn vkDestroySwapchainKHR(&self, dispatch: &DeviceDispatch, device:  VkDevice, swapchain:  VkSwapchainKHR, pAllocator: *const VkAllocationCallbacks, ) {
+			dispatch.vkDestroySwapchainKHR(device, swapchain, pAllocator)
+		}
+
+#[cfg(feature = "VK_KHR_swapchain")]
+		unsafe fn vkGetSwapchainImagesKHR(&self, dispatch: &DeviceDispatch, device:  VkDevice, swapchain:  VkSwapchainKHR, pSwapchainImageCount: *mut u32, pSwapchainImages: *mut VkImage, ) -> VkResult {
+			dispatch.vkGetSwapchainImagesKHR(device, swapchain, pSwapchainImageCount, pSwapchainImages)
+		}
+
+#[cfg(feature = "VK_KHR_swapchain")]
+		unsafe fn vkAcquireNextImageKHR(&self, dispatch: &DeviceDispatch, device:  VkDevice, swapchain:  VkSwapchainKHR, timeout:  u64, semaphore:  VkSemaphore, fence:  VkFence, pImageIndex: *mut u32, ) -> VkResult {
+			dispatch.vkAcquireNextImageKHR(device, swapchain, timeout, semaphore, fence, pImageIndex)
+		}
+
+#[cfg(all(feature = "VK_KHR_swapchain", feature = "VK_KHR_get_physical_device_properties2", any(feature = "VK_EXT_debug_report", feature = "VK_KHR_surface")))]
+		unsafe fn vkGetSwapchainStatusKHR(&self, dispatch: &DeviceDispatch, device:  VkDevice, swapchain:  VkSwapchainKHR, ) -> VkResult {
+			dispatch.vkGetSwapchainStatusKHR(device, swapchain)
+		}
+
+#[cfg(feature = "VK_KHR_xcb_surface")]
+		unsafe fn vkCreateXcbSurfaceKHR(&self, dispatch: &InstanceDispatch, instance:  VkInstance, pCreateInfo: *const VkXcbSurfaceCreateInfoKHR, pAllocator: *const VkAllocationCallbacks, pSurface: *mut VkSurfaceKHR, ) -> VkResult {
+			dispatch.vkCreateXcbSurfaceKHR(instance, pCreateInfo, pAllocator, pSurface)
+		}
+
+#[cfg(feature = "VK_KHR_xcb_surface")]
+		unsafe fn vkGetPhysicalDeviceXcbPresentationSupportKHR(&self, dispatch: &InstanceDispatch, physicalDevice:  VkPhysicalDevice, queueFamilyIndex:  u32, connection: *mut xcb_connection_t, visual_id:  xcb_visualid_t, ) -> VkBool32 {
+			dispatch.vkGetPhysicalDeviceXcbPresentationSupportKHR(physicalDevice, queueFamilyIndex, connection, visual_id)
+		}
+
+#[cfg(feature = "VK_EXT_debug_report")]
+		unsafe fn vkCreateDebugReportCallbackEXT(&self, dispatch: &InstanceDispatch, instance:  VkInstance, pCreateInfo: *const VkDebugReportCallbackCreateInfoEXT, pAllocator: *const VkAllocationCallbacks, pCallback: *mut VkDebugReportCallbackEXT, ) -> VkResult {
+			dispatch.vkCreateDebugReportCallbackEXT(instance, pCreateInfo, pAllocator, pCallback)
+		}
+
+#[cfg(feature = "VK_EXT_debug_report")]
+		unsafe fn vkDestroyDebugReportCallbackEXT(&self, dispatch: &InstanceDispatch, instance:  VkInstance, callback:  VkDebugReportCallbackEXT, pAllocator: *const VkAllocationCallbacks, ) {
+			dispatch.vkDestroyDebugReportCallbackEXT(instance, callback, pAllocator)
+		}
+	}
+
+	mod intercept {
+		use super::*;
+
+		pub unsafe extern "C" fn vkDestroyInstance<L: Layer>(instance:  VkInstance, pAllocator: *const VkAllocationCallbacks, ) {
+			let key = dispatch_key(instance);
+			L::get().vkDestroyInstance(&instance_dispatch(instance), instance, pAllocator);
+			INSTANCES.write().unwrap().remove(&key);
+		}
+
+		pub unsafe extern "C" fn vkEnumeratePhysicalDevices<L: Layer>(instance:  VkInstance, pPhysicalDeviceCount: *mut u32, pPhysicalDevices: *mut VkPhysicalDevice, ) -> VkResult {
+			L::get().vkEnumeratePhysicalDevices(&instance_dispatch(instance), instance, pPhysicalDeviceCount, pPhysicalDevices)
+		}
+
+		pub unsafe extern "C" fn vkGetPhysicalDeviceFeatures<L: Layer>(physicalDevice:  VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures, ) {
+			L::get().vkGetPhysicalDeviceFeatures(&instance_dispatch(physicalDevice), physicalDevice, pFeatures)
+		}
+
+		pub unsafe extern "C" fn vkGetPhysicalDeviceProperties<L: Layer>(physicalDevice:  VkPhysicalDevice, pProperties: *mut VkPhysicalDeviceProperties, ) {
+			L::get().vkGetPhysicalDeviceProperties(&instance_dispatch(physicalDevice), physicalDevice, pProperties)
+		}
+
+		pub unsafe extern "C" fn vkGetPhysicalDeviceQueueFamilyProperties<L: Layer>(physicalDevice:  VkPhysicalDevice, pQueueFamilyPropertyCount: *mut u32, pQueueFamilyProperties: *mut VkQueueFamilyProperties, ) {
+			L::get().vkGetPhysicalDeviceQueueFamilyProperties(&instance_dispatch(physicalDevice), physicalDevice, pQueueFamilyPropertyCount, pQueueFamilyProperties)
+		}
+
+		pub unsafe extern "C" fn vkGetPhysicalDeviceMemoryProperties<L: Layer>(physicalDevice:  VkPhysicalDevice, pMemoryProperties: *mut VkPhysicalDeviceMemoryProperties, ) {
+			L::get().vkGetPhysicalDeviceMemoryProperties(&instance_dispatch(physicalDevice), physicalDevice, pMemoryProperties)
+		}
+
+		pub unsafe extern "C" fn vkDestroyDevice<L: Layer>(device:  VkDevice, pAllocator: *const VkAllocationCallbacks, ) {
+			let key = dispatch_key(device);
+			L::get().vkDestroyDevice(&device_dispatch(device), device, pAllocator);
+			DEVICES.write().unwrap().remove(&key);
+		}
+
+		pub unsafe extern "C" fn vkEnumerateDeviceExtensionProperties<L: Layer>(physicalDevice:  VkPhysicalDevice, pLayerName: *const u8, pPropertyCount: *mut u32, pProperties: *mut VkExtensionProperties, ) -> VkResult {
+			L::get().vkEnumerateDeviceExtensionProperties(&instance_dispatch(physicalDevice), physicalDevice, pLayerName, pPropertyCount, pProperties)
+		}
+
+		pub unsafe extern "C" fn vkGetDeviceQueue<L: Layer>(device:  VkDevice, queueFamilyIndex:  u32, queueIndex:  u32, pQueue: *mut VkQueue, ) {
+			L::get().vkGetDeviceQueue(&device_dispatch(device), device, queueFamilyIndex, queueIndex, pQueue)
+		}
+
+		pub unsafe extern "C" fn vkQueueWaitIdle<L: Layer>(queue:  VkQueue, ) -> VkResult {
+			L::get().vkQueueWaitIdle(&device_dispatch(queue), queue)
+		}
+
+		pub unsafe extern "C" fn vkAllocateMemory<L: Layer>(device:  VkDevice, pAllocateInfo: *const VkMemoryAllocateInfo, pAllocator: *const VkAllocationCallbacks, pMemory: *mut VkDeviceMemory, ) -> VkResult {
+			L::get().vkAllocateMemory(&device_dispatch(device), device, pAllocateInfo, pAllocator, pMemory)
+		}
+
+		pub unsafe extern "C" fn vkFreeMemory<L: Layer>(device:  VkDevice, memory:  VkDeviceMemory, pAllocator: *const VkAllocationCallbacks, ) {
+			L::get().vkFreeMemory(&device_dispatch(device), device, memory, pAllocator)
+		}
+
+		pub unsafe extern "C" fn vkMapMemory<L: Layer>(device:  VkDevice, memory:  VkDeviceMemory, offset:  VkDeviceSize, size:  VkDeviceSize, flags:  VkMemoryMapFlags, ppData: *mut*mut c_void, ) -> VkResult {
+			L::get().vkMapMemory(&device_dispatch(device), device, memory, offset, size, flags, ppData)
+		}
+
+		pub unsafe extern "C" fn vkCreateBuffer<L: Layer>(device:  VkDevice, pCreateInfo: *const VkBufferCreateInfo, pAllocator: *const VkAllocationCallbacks, pBuffer: *mut VkBuffer, ) -> VkResult {
+			L::get().vkCreateBuffer(&device_dispatch(device), device, pCreateInfo, pAllocator, pBuffer)
+		}
+
+		pub unsafe extern "C" fn vkDestroyBuffer<L: Layer>(device:  VkDevice, buffer:  VkBuffer, pAllocator: *const VkAllocationCallbacks, ) {
+			L::get().vkDestroyBuffer(&device_dispatch(device), device, buffer, pAllocator)
+		}
+
+		pub unsafe extern "C" fn vkDestroyImage<L: Layer>(device:  VkDevice, image:  VkImage, pAllocator: *const VkAllocationCallbacks, ) {
+			L::get().vkDestroyImage(&device_dispatch(device), device, image, pAllocator)
+		}
+
+		pub unsafe extern "C" fn vkCreateFence<L: Layer>(device:  VkDevice, pCreateInfo: *const VkFenceCreateInfo, pAllocator: *const VkAllocationCallbacks, pFence: *mut VkFence, ) -> VkResult {
+			L::get().vkCreateFence(&device_dispatch(device), device, pCreateInfo, pAllocator, pFence)
+		}
+
+		pub unsafe extern "C" fn vkDestroyFence<L: Layer>(device:  VkDevice, fence:  VkFence, pAllocator: *const VkAllocationCallbacks, ) {
+			L::get().vkDestroyFence(&device_dispatch(device), device, fence, pAllocator)
+		}
+
+		pub unsafe extern "C" fn vkWaitForFences<L: Layer>(device:  VkDevice, fenceCount:  u32, pFences: *const VkFence, waitAll:  VkBool32, timeout:  u64, ) -> VkResult {
+			L::get().vkWaitForFences(&device_dispatch(device), device, fenceCount, pFences, waitAll, timeout)
+		}
+
+		pub unsafe extern "C" fn vkDestroySemaphore<L: Layer>(device:  VkDevice, semaphore:  VkSemaphore, pAllocator: *const VkAllocationCallbacks, ) {
+			L::get().vkDestroySemaphore(&device_dispatch(device), device, semaphore, pAllocator)
+		}
+
+		pub unsafe extern "C" fn vkCreateCommandPool<L: Layer>(device:  VkDevice, pCreateInfo: *const VkCommandPoolCreateInfo, pAllocator: *const VkAllocationCallbacks, pCommandPool: *mut VkCommandPool, ) -> VkResult {
+			L::get().vkCreateCommandPool(&device_dispatch(device), device, pCreateInfo, pAllocator, pCommandPool)
+		}
+
+		pub unsafe extern "C" fn vkDestroyCommandPool<L: Layer>(device:  VkDevice, commandPool:  VkCommandPool, pAllocator: *const VkAllocationCallbacks, ) {
+			L::get().vkDestroyCommandPool(&device_dispatch(device), device, commandPool, pAllocator)
+		}
+
+		pub unsafe extern "C" fn vkFreeCommandBuffers<L: Layer>(device:  VkDevice, commandPool:  VkCommandPool, commandBufferCount:  u32, pCommandBuffers: *const VkCommandBuffer, ) {
+			L::get().vkFreeCommandBuffers(&device_dispatch(device), device, commandPool, commandBufferCount, pCommandBuffers)
+		}
+
+		pub unsafe extern "C" fn vkGetPhysicalDeviceFeatures2<L: Layer>(physicalDevice:  VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures2, ) {
+			L::get().vkGetPhysicalDeviceFeatures2(&instance_dispatch(physicalDevice), physicalDevice, pFeatures)
+		}
+
+		pub unsafe extern "C" fn vkGetPhysicalDeviceQueueFamilyProperties2<L: Layer>(physicalDevice:  VkPhysicalDevice, pQueueFamilyPropertyCount: *mut u32, pQueueFamilyProperties: *mut VkQueueFamilyProperties2, ) {
+			L::get().vkGetPhysicalDeviceQueueFamilyProperties2(&instance_dispatch(physicalDevice), physicalDevice, pQueueFamilyPropertyCount, pQueueFamilyProperties)
+		}
+
+#[cfg(feature = "VK_KHR_surface")]
+		pub unsafe extern "C" fn vkDestroySurfaceKHR<L: Layer>(instance:  VkInstance, surface:  VkSurfaceKHR, pAllocator: *const VkAllocationCallbacks, ) {
+			L::get().vkDestroySurfaceKHR(&instance_dispatch(instance), instance, surface, pAllocator)
+		}
+
+#[cfg(feature = "VK_KHR_surface")]
+		pub unsafe extern "C" fn vkGetPhysicalDeviceSurfaceSupportKHR<L: Layer>(physicalDevice:  VkPhysicalDevice, queueFamilyIndex:  u32, surface:  VkSurfaceKHR, pSupported: *mut VkBool32, ) -> VkResult {
+			L::get().vkGetPhysicalDeviceSurfaceSupportKHR(&instance_dispatch(physicalDevice), physicalDevice, queueFamilyIndex, surface, pSupported)
+		}
+
+#[cfg(feature = "VK_KHR_surface")]
+		pub unsafe extern "C" fn vkGetPhysicalDeviceSurfaceCapabilitiesKHR<L: Layer>(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pSurfaceCapabilities: *mut VkSurfaceCapabilitiesKHR, ) -> VkResult {
+			L::get().vkGetPhysicalDeviceSurfaceCapabilitiesKHR(&instance_dispatch(physicalDevice), physicalDevice, surface, pSurfaceCapabilities)
+		}
+
+#[cfg(feature = "VK_KHR_surface")]
+		pub unsafe extern "C" fn vkGetPhysicalDeviceSurfaceFormatsKHR<L: Layer>(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pSurfaceFormatCount: *mut u32, pSurfaceFormats: *mut VkSurfaceFormatKHR, ) -> VkResult {
+			L::get().vkGetPhysicalDeviceSurfaceFormatsKHR(&instance_dispatch(physicalDevice), physicalDevice, surface, pSurfaceFormatCount, pSurfaceFormats)
+		}
+
+#[cfg(feature = "VK_KHR_surface")]
+		pub unsafe extern "C" fn vkGetPhysicalDeviceSurfacePresentModesKHR<L: Layer>(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pPresentModeCount: *mut u32, pPresentModes: *mut VkPresentModeKHR, ) -> VkResult {
+			L::get().vkGetPhysicalDeviceSurfacePresentModesKHR(&instance_dispatch(physicalDevice), physicalDevice, surface, pPresentModeCount, pPresentModes)
+		}
+
+#[cfg(feature = "VK_KHR_swapchain")]
+		pub unsafe extern "C" fn vkCreateSwapchainKHR<L: Layer>(device:  VkDevice, pCreateInfo: *const VkSwapchainCreateInfoKHR, pAllocator: *const VkAllocationCallbacks, pSwapchain: *mut VkSwapchainKHR, ) -> VkResult {
+			L::get().vkCreateSwapchainKHR(&device_dispatch(device), device, pCreateInfo, pAllocator, pSwapchain)
+		}
+
+#[cfg(feature = "VK_KHR_swapchain")]
+		pub unsafe extern "C" fn vkDestroySwapchainKHR<L: Layer>(device:  VkDevice, swapchain:  VkSwapchainKHR, pAllocator: *const VkAllocationCallbacks, ) {
+			L::get().vkDestroySwapchainKHR(&device_dispatch(device), device, swapchain, pAllocator)
+		}
+
+#[cfg(feature = "VK_KHR_swapchain")]
+		pub unsafe extern "C" fn vkGetSwapchainImagesKHR<L: Layer>(device:  VkDevice, swapchain:  VkSwapchainKHR, pSwapchainImageCount: *mut u32, pSwapchainImages: *mut VkImage, ) -> VkResult {
+			L::get().vkGetSwapchainImagesKHR(&device_dispatch(device), device, swapchain, pSwapchainImageCount, pSwapchainImages)
+		}
+
+#[cfg(feature = "VK_KHR_swapchain")]
+		pub unsafe extern "C" fn vkAcquireNextImageKHR<L: Layer>(device:  VkDevice, swapchain:  VkSwapchainKHR, timeout:  u64, semaphore:  VkSemaphore, fence:  VkFence, pImageIndex: *mut u32, ) -> VkResult {
+			L::get().vkAcquireNextImageKHR(&device_dispatch(device), device, swapchain, timeout, semaphore, fence, pImageIndex)
+		}
+
+#[cfg(all(feature = "VK_KHR_swapchain", feature = "VK_KHR_get_physical_device_properties2", any(feature = "VK_EXT_debug_report", feature = "VK_KHR_surface")))]
+		pub unsafe extern "C" fn vkGetSwapchainStatusKHR<L: Layer>(device:  VkDevice, swapchain:  VkSwapchainKHR, ) -> VkResult {
+			L::get().vkGetSwapchainStatusKHR(&device_dispatch(device), device, swapchain)
+		}
+
+#[cfg(feature = "VK_KHR_xcb_surface")]
+		pub unsafe extern "C" fn vkCreateXcbSurfaceKHR<L: Layer>(instance:  VkInstance, pCreateInfo: *const VkXcbSurfaceCreateInfoKHR, pAllocator: *const VkAllocationCallbacks, pSurface: *mut VkSurfaceKHR, ) -> VkResult {
+			L::get().vkCreateXcbSurfaceKHR(&instance_dispatch(instance), instance, pCreateInfo, pAllocator, pSurface)
+		}
+
+#[cfg(feature = "VK_KHR_xcb_surface")]
+		pub unsafe extern "C" fn vkGetPhysicalDeviceXcbPresentationSupportKHR<L: Layer>(physicalDevice:  VkPhysicalDevice, queueFamilyIndex:  u32, connection: *mut xcb_connection_t, visual_id:  xcb_visualid_t, ) -> VkBool32 {
+			L::get().vkGetPhysicalDeviceXcbPresentationSupportKHR(&instance_dispatch(physicalDevice), physicalDevice, queueFamilyIndex, connection, visual_id)
+		}
+
+#[cfg(feature = "VK_EXT_debug_report")]
+		pub unsafe extern "C" fn vkCreateDebugReportCallbackEXT<L: Layer>(instance:  VkInstance, pCreateInfo: *const VkDebugReportCallbackCreateInfoEXT, pAllocator: *const VkAllocationCallbacks, pCallback: *mut VkDebugReportCallbackEXT, ) -> VkResult {
+			L::get().vkCreateDebugReportCallbackEXT(&instance_dispatch(instance), instance, pCreateInfo, pAllocator, pCallback)
+		}
+
+#[cfg(feature = "VK_EXT_debug_report")]
+		pub unsafe extern "C" fn vkDestroyDebugReportCallbackEXT<L: Layer>(instance:  VkInstance, callback:  VkDebugReportCallbackEXT, pAllocator: *const VkAllocationCallbacks, ) {
+			L::get().vkDestroyDebugReportCallbackEXT(&instance_dispatch(instance), instance, callback, pAllocator)
+		}
+	}
+
+	// The layer's entry point for a command, or null for commands it does not intercept
+	fn intercept_address<L: Layer>(name: &[u8]) -> PFN_vkVoidFunction {
+		match name {
+			b"vkGetInstanceProcAddr" => get_instance_proc_addr::<L> as PFN_vkVoidFunction,
+			b"vkGetDeviceProcAddr" => get_device_proc_addr::<L> as PFN_vkVoidFunction,
+			b"vkCreateInstance" => create_instance::<L> as PFN_vkVoidFunction,
+			b"vkCreateDevice" => create_device::<L> as PFN_vkVoidFunction,
+			b"vkDestroyInstance" => intercept::vkDestroyInstance::<L> as PFN_vkVoidFunction,
+			b"vkEnumeratePhysicalDevices" => intercept::vkEnumeratePhysicalDevices::<L> as PFN_vkVoidFunction,
+			b"vkGetPhysicalDeviceFeatures" => intercept::vkGetPhysicalDeviceFeatures::<L> as PFN_vkVoidFunction,
+			b"vkGetPhysicalDeviceProperties" => intercept::vkGetPhysicalDeviceProperties::<L> as PFN_vkVoidFunction,
+			b"vkGetPhysicalDeviceQueueFamilyProperties" => intercept::vkGetPhysicalDeviceQueueFamilyProperties::<L> as PFN_vkVoidFunction,
+			b"vkGetPhysicalDeviceMemoryProperties" => intercept::vkGetPhysicalDeviceMemoryProperties::<L> as PFN_vkVoidFunction,
+			b"vkDestroyDevice" => intercept::vkDestroyDevice::<L> as PFN_vkVoidFunction,
+			b"vkEnumerateDeviceExtensionProperties" => intercept::vkEnumerateDeviceExtensionProperties::<L> as PFN_vkVoidFunction,
+			b"vkGetDeviceQueue" => intercept::vkGetDeviceQueue::<L> as PFN_vkVoidFunction,
+			b"vkQueueWaitIdle" => intercept::vkQueueWaitIdle::<L> as PFN_vkVoidFunction,
+			b"vkAllocateMemory" => intercept::vkAllocateMemory::<L> as PFN_vkVoidFunction,
+			b"vkFreeMemory" => intercept::vkFreeMemory::<L> as PFN_vkVoidFunction,
+			b"vkMapMemory" => intercept::vkMapMemory::<L> as PFN_vkVoidFunction,
+			b"vkCreateBuffer" => intercept::vkCreateBuffer::<L> as PFN_vkVoidFunction,
+			b"vkDestroyBuffer" => intercept::vkDestroyBuffer::<L> as PFN_vkVoidFunction,
+			b"vkDestroyImage" => intercept::vkDestroyImage::<L> as PFN_vkVoidFunction,
+			b"vkCreateFence" => intercept::vkCreateFence::<L> as PFN_vkVoidFunction,
+			b"vkDestroyFence" => intercept::vkDestroyFence::<L> as PFN_vkVoidFunction,
+			b"vkWaitForFences" => intercept::vkWaitForFences::<L> as PFN_vkVoidFunction,
+			b"vkDestroySemaphore" => intercept::vkDestroySemaphore::<L> as PFN_vkVoidFunction,
+			b"vkCreateCommandPool" => intercept::vkCreateCommandPool::<L> as PFN_vkVoidFunction,
+			b"vkDestroyCommandPool" => intercept::vkDestroyCommandPool::<L> as PFN_vkVoidFunction,
+			b"vkFreeCommandBuffers" => intercept::vkFreeCommandBuffers::<L> as PFN_vkVoidFunction,
+			b"vkGetPhysicalDeviceFeatures2" | b"vkGetPhysicalDeviceFeatures2KHR" => intercept::vkGetPhysicalDeviceFeatures2::<L> as PFN_vkVoidFunction,
+			b"vkGetPhysicalDeviceQueueFamilyProperties2" => intercept::vkGetPhysicalDeviceQueueFamilyProperties2::<L> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_KHR_surface")]
+			b"vkDestroySurfaceKHR" => intercept::vkDestroySurfaceKHR::<L> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_KHR_surface")]
+			b"vkGetPhysicalDeviceSurfaceSupportKHR" => intercept::vkGetPhysicalDeviceSurfaceSupportKHR::<L> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_KHR_surface")]
+			b"vkGetPhysicalDeviceSurfaceCapabilitiesKHR" => intercept::vkGetPhysicalDeviceSurfaceCapabilitiesKHR::<L> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_KHR_surface")]
+			b"vkGetPhysicalDeviceSurfaceFormatsKHR" => intercept::vkGetPhysicalDeviceSurfaceFormatsKHR::<L> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_KHR_surface")]
+			b"vkGetPhysicalDeviceSurfacePresentModesKHR" => intercept::vkGetPhysicalDeviceSurfacePresentModesKHR::<L> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_KHR_swapchain")]
+			b"vkCreateSwapchainKHR" => intercept::vkCreateSwapchainKHR::<L> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_KHR_swapchain")]
+			b"vkDestroySwapchainKHR" => intercept::vkDestroySwapchainKHR::<L> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_KHR_swapchain")]
+			b"vkGetSwapchainImagesKHR" => intercept::vkGetSwapchainImagesKHR::<L> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_KHR_swapchain")]
+			b"vkAcquireNextImageKHR" => intercept::vkAcquireNextImageKHR::<L> as PFN_vkVoidFunction,
+#[cfg(all(feature = "VK_KHR_swapchain", feature = "VK_KHR_get_physical_device_properties2", any(feature = "VK_EXT_debug_report", feature = "VK_KHR_surface")))]
+			b"vkGetSwapchainStatusKHR" => intercept::vkGetSwapchainStatusKHR::<L> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_KHR_xcb_surface")]
+			b"vkCreateXcbSurfaceKHR" => intercept::vkCreateXcbSurfaceKHR::<L> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_KHR_xcb_surface")]
+			b"vkGetPhysicalDeviceXcbPresentationSupportKHR" => intercept::vkGetPhysicalDeviceXcbPresentationSupportKHR::<L> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_EXT_debug_report")]
+			b"vkCreateDebugReportCallbackEXT" => intercept::vkCreateDebugReportCallbackEXT::<L> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_EXT_debug_report")]
+			b"vkDestroyDebugReportCallbackEXT" => intercept::vkDestroyDebugReportCallbackEXT::<L> as PFN_vkVoidFunction,
+			_ => ptr::null()
+		}
+	}
+}
+
+// Framework for writing drivers (ICDs), see vkmock for an example
+#[cfg(feature = "icd")]
+#[allow(unused_variables, dead_code)]
+pub mod icd {
+	use super::*;
+
+	use std::collections::BTreeMap;
+	use std::ffi::CStr;
+	use std::sync::Mutex;
+	use std::sync::atomic::{AtomicU64, Ordering};
+	use core::ptr;
+
+	// Loader and driver interface from vk_icd.h, which is not part of vk.xml
+	// Version 2 is the first with vk_icdGetInstanceProcAddr, from version 3 surfaces are created by the driver
+	const ICD_LOADER_MAGIC: usize = 0x01CDC0DE;
+	const MIN_ICD_INTERFACE_VERSION: u32 = 2;
+
+	pub const ICD_INTERFACE_VERSION: u32 = 5;
+
+	// How many handles commands that enumerate non-dispatchable handles (i.e. swapchain images) return
+	pub const ENUMERATED_HANDLE_COUNT: usize = 3;
+
+	// A physical device the driver reports
+	pub struct PhysicalDevice {
+		pub properties: VkPhysicalDeviceProperties,
+		pub features: VkPhysicalDeviceFeatures,
+		pub memory: VkPhysicalDeviceMemoryProperties,
+		pub queue_families: Vec<VkQueueFamilyProperties>,
+		pub extensions: Vec<VkExtensionProperties>
+	}
+
+	impl PhysicalDevice {
+
+		// A Vulkan 1.0 device with no features, memory, queues or extensions
+		pub fn new(name: &str) -> PhysicalDevice {
+			let mut properties: VkPhysicalDeviceProperties = unsafe { ::core::mem::zeroed() };
+			properties.apiVersion = VK_MAKE_VERSION(1, 0, 0);
+			copy_name(&mut properties.deviceName, name);
+			PhysicalDevice {
+				properties: properties,
+				features: unsafe { ::core::mem::zeroed() },
+				memory: unsafe { ::core::mem::zeroed() },
+				queue_families: Vec::new(),
+				extensions: Vec::new()
+			}
+		}
+	}
+
+	pub fn extension_properties(name: &str, spec_version: u32) -> VkExtensionProperties {
+		let mut properties: VkExtensionProperties = unsafe { ::core::mem::zeroed() };
+		copy_name(&mut properties.extensionName, name);
+		properties.specVersion = spec_version;
+		properties
+	}
+
+	// Fixed size, nul terminated strings, cut short if they don't fit
+	fn copy_name(to: &mut [u8], name: &str) {
+		let length = name.len().min(to.len() - 1);
+		to[..length].copy_from_slice(&name.as_bytes()[..length]);
+		to[length] = 0;
+	}
+
+	unsafe fn name_of<'a>(name: *const u8) -> &'a [u8] {
+		CStr::from_ptr(name as *const _).to_bytes()
+	}
+
+	// Dispatchable handles point at an object starting with a slot the loader writes its dispatch table into
+	// Devices and the objects they own know which of the driver's physical devices they are for
+	#[repr(C)]
+	struct Dispatchable {
+		loader_data: usize,
+		physical_device: usize,
+		queue: Option<(u32, u32)>,
+		children: Mutex<Vec<u64>>
+	}
+
+	unsafe fn dispatchable<'a>(handle: u64) -> &'a Dispatchable {
+		&*(handle as usize as *const Dispatchable)
+	}
+
+	// Creates a dispatchable object, owned by parent unless that is null
+	unsafe fn new_dispatchable(parent: u64, physical_device: usize, queue: Option<(u32, u32)>) -> u64 {
+		let handle = Box::into_raw(Box::new(Dispatchable {
+			loader_data: ICD_LOADER_MAGIC,
+			physical_device: physical_device,
+			queue: queue,
+			children: Mutex::new(Vec::new())
+		})) as usize as u64;
+		if parent != VK_NULL_HANDLE {
+			dispatchable(parent).children.lock().unwrap().push(handle);
+		}
+		handle
+	}
+
+	// A dispatchable object created by a command called on parent (i.e. a command buffer)
+	unsafe fn new_child(parent: u64) -> u64 {
+		let physical_device = if parent == VK_NULL_HANDLE { 0 } else { dispatchable(parent).physical_device };
+		new_dispatchable(parent, physical_device, None)
+	}
+
+	// Frees a dispatchable object and everything it owns
+	unsafe fn free_dispatchable(parent: u64, handle: u64) {
+		if handle == VK_NULL_HANDLE {
+			return;
+		}
+		if parent != VK_NULL_HANDLE {
+			dispatchable(parent).children.lock().unwrap().retain(|child| *child != handle);
+		}
+		let object = Box::from_raw(handle as usize as *mut Dispatchable);
+		for child in object.children.lock().unwrap().iter() {
+			free_dispatchable(VK_NULL_HANDLE, *child);
+		}
+	}
+
+	static NEXT_HANDLE: AtomicU64 = AtomicU64::new(1);
+
+	// Non-dispatchable handles are just numbers
+	fn new_handle() -> u64 {
+		NEXT_HANDLE.fetch_add(1, Ordering::Relaxed)
+	}
+
+	static ENUMERATED_HANDLES: Mutex<BTreeMap<(&'static str, u64), Vec<u64>>> = Mutex::new(BTreeMap::new());
+
+	// The handles a command enumerates for an object, the same ones every time
+	fn enumerated_handles(command: &'static str, handle: u64) -> Vec<u64> {
+		ENUMERATED_HANDLES.lock().unwrap().entry((command, handle)).or_insert_with(|| (0..ENUMERATED_HANDLE_COUNT).map(|_| new_handle()).collect()).clone()
+	}
+
+	// Second half of a two-call enumeration, the count when values is null and as many values as fit otherwise
+	unsafe fn enumerate<T: Copy>(values: &[T], pCount: *mut u32, pValues: *mut T) -> VkResult {
+		if pValues.is_null() {
+			*pCount = values.len() as u32;
+			return VkResult::VK_SUCCESS;
+		}
+		let count = values.len().min(*pCount as usize);
+		ptr::copy_nonoverlapping(values.as_ptr(), pValues, count);
+		*pCount = count as u32;
+		if count < values.len() { VkResult::VK_INCOMPLETE } else { VkResult::VK_SUCCESS }
+	}
+
+	static MEMORY: Mutex<BTreeMap<u64, (VkDeviceSize, Vec<u8>)>> = Mutex::new(BTreeMap::new());
+
+	unsafe fn create_instance<D: Driver>(pCreateInfo: *const VkInstanceCreateInfo, pAllocator: *const VkAllocationCallbacks, pInstance: *mut VkInstance) -> VkResult {
+		for i in 0..(*pCreateInfo).enabledExtensionCount as usize {
+			let name = name_of(*(*pCreateInfo).ppEnabledExtensionNames.add(i));
+			if !D::get().instance_extensions().iter().any(|e| name_of(e.extensionName.as_ptr()) == name) {
+				return VkResult::VK_ERROR_EXTENSION_NOT_PRESENT;
+			}
+		}
+		*pInstance = new_dispatchable(VK_NULL_HANDLE, 0, None);
+		VkResult::VK_SUCCESS
+	}
+
+	unsafe fn enumerate_instance_extension_properties<D: Driver>(pLayerName: *const u8, pPropertyCount: *mut u32, pProperties: *mut VkExtensionProperties) -> VkResult {
+		if !pLayerName.is_null() {
+			return VkResult::VK_ERROR_LAYER_NOT_PRESENT;
+		}
+		enumerate(D::get().instance_extensions(), pPropertyCount, pProperties)
+	}
+
+	unsafe fn enumerate_instance_version<D: Driver>(pApiVersion: *mut u32) -> VkResult {
+		*pApiVersion = D::get().api_version();
+		VkResult::VK_SUCCESS
+	}
+
+	// An instance's physical devices are created the first time they are enumerated
+	unsafe fn enumerate_physical_devices<D: Driver>(instance: VkInstance, pPhysicalDeviceCount: *mut u32, pPhysicalDevices: *mut VkPhysicalDevice) -> VkResult {
+		let mut children = dispatchable(instance).children.lock().unwrap();
+		if children.is_empty() {
+			for i in 0..D::get().physical_devices().len() {
+				children.push(new_dispatchable(VK_NULL_HANDLE, i, None));
+			}
+		}
+		enumerate(&children, pPhysicalDeviceCount, pPhysicalDevices)
+	}
+
+	unsafe fn physical_device<'a, D: Driver>(handle: u64) -> &'a PhysicalDevice {
+		&D::get().physical_devices()[dispatchable(handle).physical_device]
+	}
+
+	unsafe fn get_physical_device_properties<D: Driver>(physicalDevice: VkPhysicalDevice, pProperties: *mut VkPhysicalDeviceProperties) {
+		*pProperties = physical_device::<D>(physicalDevice).properties;
+	}
+
+	unsafe fn get_physical_device_features<D: Driver>(physicalDevice: VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures) {
+		*pFeatures = physical_device::<D>(physicalDevice).features;
+	}
+
+	unsafe fn get_physical_device_memory_properties<D: Driver>(physicalDevice: VkPhysicalDevice, pMemoryProperties: *mut VkPhysicalDeviceMemoryProperties) {
+		*pMemoryProperties = physical_device::<D>(physicalDevice).memory;
+	}
+
+	unsafe fn get_physical_device_queue_family_properties<D: Driver>(physicalDevice: VkPhysicalDevice, pQueueFamilyPropertyCount: *mut u32, pQueueFamilyProperties: *mut VkQueueFamilyProperties) {
+		enumerate(&physical_device::<D>(physicalDevice).queue_families, pQueueFamilyPropertyCount, pQueueFamilyProperties);
+	}
+
+	unsafe fn enumerate_device_extension_properties<D: Driver>(physicalDevice: VkPhysicalDevice, pLayerName: *const u8, pPropertyCount: *mut u32, pProperties: *mut VkExtensionProperties) -> VkResult {
+		if !pLayerName.is_null() {
+			return VkResult::VK_ERROR_LAYER_NOT_PRESENT;
+		}
+		enumerate(&physical_device::<D>(physicalDevice).extensions, pPropertyCount, pProperties)
+	}
+
+	unsafe fn create_device<D: Driver>(physicalDevice: VkPhysicalDevice, pCreateInfo: *const VkDeviceCreateInfo, pAllocator: *const VkAllocationCallbacks, pDevice: *mut VkDevice) -> VkResult {
+		let physical_device = physical_device::<D>(physicalDevice);
+		for i in 0..(*pCreateInfo).enabledExtensionCount as usize {
+			let name = name_of(*(*pCreateInfo).ppEnabledExtensionNames.add(i));
+			if !physical_device.extensions.iter().any(|e| name_of(e.extensionName.as_ptr()) == name) {
+				return VkResult::VK_ERROR_EXTENSION_NOT_PRESENT;
+			}
+		}
+		*pDevice = new_dispatchable(VK_NULL_HANDLE, dispatchable(physicalDevice).physical_device, None);
+		VkResult::VK_SUCCESS
+	}
+
+	// The same queue is returned every time it is asked for
+	unsafe fn get_device_queue<D: Driver>(device: VkDevice, queueFamilyIndex: u32, queueIndex: u32, pQueue: *mut VkQueue) {
+		let object = dispatchable(device);
+		let existing = object.children.lock().unwrap().iter().cloned().find(|child| dispatchable(*child).queue == Some((queueFamilyIndex, queueIndex)));
+		*pQueue = match existing {
+			Some(queue) => queue,
+			None => new_dispatchable(device, object.physical_device, Some((queueFamilyIndex, queueIndex)))
+		};
+	}
+
+	// Memory is only really allocated when it is mapped
+	unsafe fn allocate_memory<D: Driver>(device: VkDevice, pAllocateInfo: *const VkMemoryAllocateInfo, pAllocator: *const VkAllocationCallbacks, pMemory: *mut VkDeviceMemory) -> VkResult {
+		*pMemory = new_handle();
+		MEMORY.lock().unwrap().insert(*pMemory, ((*pAllocateInfo).allocationSize, Vec::new()));
+		VkResult::VK_SUCCESS
+	}
+
+	unsafe fn free_memory<D: Driver>(device: VkDevice, memory: VkDeviceMemory, pAllocator: *const VkAllocationCallbacks) {
+		MEMORY.lock().unwrap().remove(&memory);
+	}
+
+	unsafe fn map_memory<D: Driver>(device: VkDevice, memory: VkDeviceMemory, offset: VkDeviceSize, size: VkDeviceSize, flags: VkMemoryMapFlags, ppData: *mut *mut c_void) -> VkResult {
+		let mut allocations = MEMORY.lock().unwrap();
+		let allocation = match allocations.get_mut(&memory) {
+			Some(allocation) => allocation,
+			None => return VkResult::VK_ERROR_MEMORY_MAP_FAILED
+		};
+		if allocation.1.is_empty() {
+			allocation.1 = vec![0; allocation.0 as usize];
+		}
+		*ppData = allocation.1.as_mut_ptr().add(offset as usize) as *mut c_void;
+		VkResult::VK_SUCCESS
+	}
+
+	// Also the vk_icdGetInstanceProcAddr and vk_icdGetPhysicalDeviceProcAddr that export_icd! exports
+	pub unsafe fn get_instance_proc_addr<D: Driver>(instance: VkInstance, pName: *const u8) -> PFN_vkVoidFunction {
+		command_address::<D>(name_of(pName))
+	}
+
+	unsafe fn get_device_proc_addr<D: Driver>(device: VkDevice, pName: *const u8) -> PFN_vkVoidFunction {
+		command_address::<D>(name_of(pName))
+	}
+
+	// Called from the vk_icdNegotiateLoaderICDInterfaceVersion that export_icd! exports
+	pub unsafe fn negotiate_loader_icd_interface_version(pSupportedVersion: *mut u32) -> VkResult {
+		if pSupportedVersion.is_null() || *pSupportedVersion < MIN_ICD_INTERFACE_VERSION {
+			return VkResult::VK_ERROR_INCOMPATIBLE_DRIVER;
+		}
+		*pSupportedVersion = (*pSupportedVersion).min(ICD_INTERFACE_VERSION);
+		VkResult::VK_SUCCESS
+	}
+
+	// Exports the entry points the loader looks for in a driver's library, i.e. vkraw::export_icd!(MyDriver);
+	#[macro_export]
+	macro_rules! export_icd {
+		($driver:ty) => {
+			#[no_mangle]
+			pub unsafe extern "C" fn vk_icdNegotiateLoaderICDInterfaceVersion(pSupportedVersion: *mut u32) -> $crate::VkResult {
+				$crate::icd::negotiate_loader_icd_interface_version(pSupportedVersion)
+			}
+
+			#[no_mangle]
+			pub unsafe extern "C" fn vk_icdGetInstanceProcAddr(instance: $crate::VkInstance, pName: *const u8) -> $crate::PFN_vkVoidFunction {
+				$crate::icd::get_instance_proc_addr::<$driver>(instance, pName)
+			}
+
+			#[no_mangle]
+			pub unsafe extern "C" fn vk_icdGetPhysicalDeviceProcAddr(instance: $crate::VkInstance, pName: *const u8) -> $crate::PFN_vkVoidFunction {
+				$crate::icd::get_instance_proc_addr::<$driver>(instance, pName)
+			}
+		}
+	}
+
+	// A driver, what it reports is set up through this and every command succeeds unless it says otherwise
+	pub trait Driver: Sized + Sync + 'static {
+
+		// The driver the loader's calls go to
+		fn get() -> &'static Self;
+
+		fn physical_devices(&self) -> &[PhysicalDevice];
+
+		fn instance_extensions(&self) -> &[VkExtensionProperties] {
+			&[]
+		}
+
+		// What vkEnumerateInstanceVersion reports, the newest of the physical devices
+		fn api_version(&self) -> u32 {
+			self.physical_devices().iter().map(|p| p.properties.apiVersion).max().unwrap_or(VK_MAKE_VERSION(1, 0, 0))
+		}
+
+		// The result of a call to a command, or None for the usual one. Errors are returned straight away and
+		// other results (i.e. VK_SUBOPTIMAL_KHR) after the command has done what it usually does
+		fn result(&self, command: &str) -> Option<VkResult> {
+			None
+		}
+	}
+
+	// VkResult from its name, i.e. for scripted results in a driver's configuration
+	pub fn result_from_name(name: &str) -> Option<VkResult> {
+		match name {
+			"VK_SUCCESS" => Some(VkResult::VK_SUCCESS),
+			"VK_NOT_READY" => Some(VkResult::VK_NOT_READY),
+			"VK_TIMEOUT" => Some(VkResult::VK_TIMEOUT),
+			"VK_INCOMPLETE" => Some(VkResult::VK_INCOMPLETE),
+			"VK_ERROR_OUT_OF_HOST_MEMORY" => Some(VkResult::VK_ERROR_OUT_OF_HOST_MEMORY),
+			"VK_ERROR_OUT_OF_DEVICE_MEMORY" => Some(VkResult::VK_ERROR_OUT_OF_DEVICE_MEMORY),
+			"VK_ERROR_INITIALIZATION_FAILED" => Some(VkResult::VK_ERROR_INITIALIZATION_FAILED),
+			"VK_ERROR_DEVICE_LOST" => Some(VkResult::VK_ERROR_DEVICE_LOST),
+			"VK_ERROR_MEMORY_MAP_FAILED" => Some(VkResult::VK_ERROR_MEMORY_MAP_FAILED),
+			"VK_ERROR_LAYER_NOT_PRESENT" => Some(VkResult::VK_ERROR_LAYER_NOT_PRESENT),
+			"VK_ERROR_EXTENSION_NOT_PRESENT" => Some(VkResult::VK_ERROR_EXTENSION_NOT_PRESENT),
+			"VK_ERROR_INCOMPATIBLE_DRIVER" => Some(VkResult::VK_ERROR_INCOMPATIBLE_DRIVER),
+#[cfg(feature = "VK_KHR_surface")]
+			"VK_ERROR_SURFACE_LOST_KHR" => Some(VkResult::VK_ERROR_SURFACE_LOST_KHR),
+#[cfg(feature = "VK_KHR_surface")]
+			"VK_ERROR_NATIVE_WINDOW_IN_USE_KHR" => Some(VkResult::VK_ERROR_NATIVE_WINDOW_IN_USE_KHR),
+#[cfg(feature = "VK_KHR_swapchain")]
+			"VK_SUBOPTIMAL_KHR" => Some(VkResult::VK_SUBOPTIMAL_KHR),
+#[cfg(feature = "VK_KHR_swapchain")]
+			"VK_ERROR_OUT_OF_DATE_KHR" => Some(VkResult::VK_ERROR_OUT_OF_DATE_KHR),
+#[cfg(feature = "VK_EXT_debug_report")]
+			"VK_ERROR_VALIDATION_FAILED_EXT" => Some(VkResult::VK_ERROR_VALIDATION_FAILED_EXT),
+			_ => None
+		}
+	}
+
+	mod commands {
+		use super::*;
+
+		pub unsafe extern "C" fn vkCreateInstance<D: Driver>(pCreateInfo: *const VkInstanceCreateInfo, pAllocator: *const VkAllocationCallbacks, pInstance: *mut VkInstance, ) -> VkResult {
+			let scripted = D::get().result("vkCreateInstance");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			let result = create_instance::<D>(pCreateInfo, pAllocator, pInstance);
+			scripted.unwrap_or(result)
+		}
+
+		pub unsafe extern "C" fn vkDestroyInstance<D: Driver>(instance:  VkInstance, pAllocator: *const VkAllocationCallbacks, ) {
+			free_dispatchable(VK_NULL_HANDLE, instance);
+		}
+
+		pub unsafe extern "C" fn vkEnumeratePhysicalDevices<D: Driver>(instance:  VkInstance, pPhysicalDeviceCount: *mut u32, pPhysicalDevices: *mut VkPhysicalDevice, ) -> VkResult {
+			let scripted = D::get().result("vkEnumeratePhysicalDevices");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			let result = enumerate_physical_devices::<D>(instance, pPhysicalDeviceCount, pPhysicalDevices);
+			scripted.unwrap_or(result)
+		}
+
+		pub unsafe extern "C" fn vkGetPhysicalDeviceFeatures<D: Driver>(physicalDevice:  VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures, ) {
+			get_physical_device_features::<D>(physicalDevice, pFeatures);
+		}
+
+		pub unsafe extern "C" fn vkGetPhysicalDeviceProperties<D: Driver>(physicalDevice:  VkPhysicalDevice, pProperties: *mut VkPhysicalDeviceProperties, ) {
+			get_physical_device_properties::<D>(physicalDevice, pProperties);
+		}
+
+		pub unsafe extern "C" fn vkGetPhysicalDeviceQueueFamilyProperties<D: Driver>(physicalDevice:  VkPhysicalDevice, pQueueFamilyPropertyCount: *mut u32, pQueueFamilyProperties: *mut VkQueueFamilyProperties, ) {
+			get_physical_device_queue_family_properties::<D>(physicalDevice, pQueueFamilyPropertyCount, pQueueFamilyProperties);
+		}
+
+		pub unsafe extern "C" fn vkGetPhysicalDeviceMemoryProperties<D: Driver>(physicalDevice:  VkPhysicalDevice, pMemoryProperties: *mut VkPhysicalDeviceMemoryProperties, ) {
+			get_physical_device_memory_properties::<D>(physicalDevice, pMemoryProperties);
+		}
+
+		pub unsafe extern "C" fn vkGetInstanceProcAddr<D: Driver>(instance:  VkInstance, pName: *const u8, ) -> PFN_vkVoidFunction {
+			get_instance_proc_addr::<D>(instance, pName)
+		}
+
+		pub unsafe extern "C" fn vkGetDeviceProcAddr<D: Driver>(device:  VkDevice, pName: *const u8, ) -> PFN_vkVoidFunction {
+			get_device_proc_addr::<D>(device, pName)
+		}
+
+		pub unsafe extern "C" fn vkCreateDevice<D: Driver>(physicalDevice:  VkPhysicalDevice, pCreateInfo: *const VkDeviceCreateInfo, pAllocator: *const VkAllocationCallbacks, pDevice: *mut VkDevice, ) -> VkResult {
+			let scripted = D::get().result("vkCreateDevice");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			let result = create_device::<D>(physicalDevice, pCreateInfo, pAllocator, pDevice);
+			scripted.unwrap_or(result)
+		}
+
+		pub unsafe extern "C" fn vkDestroyDevice<D: Driver>(device:  VkDevice, pAllocator: *const VkAllocationCallbacks, ) {
+			free_dispatchable(VK_NULL_HANDLE, device);
+		}
+
+		pub unsafe extern "C" fn vkEnumerateInstanceExtensionProperties<D: Driver>(pLayerName: *const u8, pPropertyCount: *mut u32, pProperties: *mut VkExtensionProperties, ) -> VkResult {
+			let scripted = D::get().result("vkEnumerateInstanceExtensionProperties");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			let result = enumerate_instance_extension_properties::<D>(pLayerName, pPropertyCount, pProperties);
+			scripted.unwrap_or(result)
+		}
+
+		pub unsafe extern "C" fn vkEnumerateDeviceExtensionProperties<D: Driver>(physicalDevice:  VkPhysicalDevice, pLayerName: *const u8, pPropertyCount: *mut u32, pProperties: *mut VkExtensionProperties, ) -> VkResult {
+			let scripted = D::get().result("vkEnumerateDeviceExtensionProperties");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			let result = enumerate_device_extension_properties::<D>(physicalDevice, pLayerName, pPropertyCount, pProperties);
+			scripted.unwrap_or(result)
+		}
+
+		pub unsafe extern "C" fn vkEnumerateInstanceLayerProperties<D: Driver>(pPropertyCount: *mut u32, pProperties: *mut VkLayerProperties, ) -> VkResult {
+			let scripted = D::get().result("vkEnumerateInstanceLayerProperties");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			let result = enumerate::<VkLayerProperties>(&[], pPropertyCount, pProperties);
+			scripted.unwrap_or(result)
+		}
+
+		pub unsafe extern "C" fn vkGetDeviceQueue<D: Driver>(device:  VkDevice, queueFamilyIndex:  u32, queueIndex:  u32, pQueue: *mut VkQueue, ) {
+			get_device_queue::<D>(device, queueFamilyIndex, queueIndex, pQueue);
+		}
+
+		pub unsafe extern "C" fn vkQueueWaitIdle<D: Driver>(queue:  VkQueue, ) -> VkResult {
+			let scripted = D::get().result("vkQueueWaitIdle");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			let result = VkResult::VK_SUCCESS;
+			scripted.unwrap_or(result)
+		}
+
+		pub unsafe extern "C" fn vkAllocateMemory<D: Driver>(device:  VkDevice, pAllocateInfo: *const VkMemoryAllocateInfo, pAllocator: *const VkAllocationCallbacks, pMemory: *mut VkDeviceMemory, ) -> VkResult {
+			let scripted = D::get().result("vkAllocateMemory");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			let result = allocate_memory::<D>(device, pAllocateInfo, pAllocator, pMemory);
+			scripted.unwrap_or(result)
+		}
+
+		pub unsafe extern "C" fn vkFreeMemory<D: Driver>(device:  VkDevice, memory:  VkDeviceMemory, pAllocator: *const VkAllocationCallbacks, ) {
+			free_memory::<D>(device, memory, pAllocator);
+		}
+
+		pub unsafe extern "C" fn vkMapMemory<D: Driver>(device:  VkDevice, memory:  VkDeviceMemory, offset:  VkDeviceSize, size:  VkDeviceSize, flags:  VkMemoryMapFlags, ppData: *mut*mut c_void, ) -> VkResult {
+			let scripted = D::get().result("vkMapMemory");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			let result = map_memory::<D>(device, memory, offset, size, flags, ppData);
+			scripted.unwrap_or(result)
+		}
+
+		pub unsafe extern "C" fn vkCreateBuffer<D: Driver>(device:  VkDevice, pCreateInfo: *const VkBufferCreateInfo, pAllocator: *const VkAllocationCallbacks, pBuffer: *mut VkBuffer, ) -> VkResult {
+			let scripted = D::get().result("vkCreateBuffer");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			if !pBuffer.is_null() {
+				*pBuffer = new_handle();
+			}
+			let result = VkResult::VK_SUCCESS;
+			scripted.unwrap_or(result)
+		}
+
+		pub unsafe extern "C" fn vkDestroyBuffer<D: Driver>(device:  VkDevice, buffer:  VkBuffer, pAllocator: *const VkAllocationCallbacks, ) {
+		}
+
+		pub unsafe extern "C" fn vkDestroyImage<D: Driver>(device:  VkDevice, image:  VkImage, pAllocator: *const VkAllocationCallbacks, ) {
+		}
+
+		pub unsafe extern "C" fn vkCreateFence<D: Driver>(device:  VkDevice, pCreateInfo: *const VkFenceCreateInfo, pAllocator: *const VkAllocationCallbacks, pFence: *mut VkFence, ) -> VkResult {
+			let scripted = D::get().result("vkCreateFence");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			if !pFence.is_null() {
+				*pFence = new_handle();
+			}
+			let result = VkResult::VK_SUCCESS;
+			scripted.unwrap_or(result)
+		}
+
+		pub unsafe extern "C" fn vkDestroyFence<D: Driver>(device:  VkDevice, fence:  VkFence, pAllocator: *const VkAllocationCallbacks, ) {
+		}
+
+		pub unsafe extern "C" fn vkWaitForFences<D: Driver>(device:  VkDevice, fenceCount:  u32, pFences: *const VkFence, waitAll:  VkBool32, timeout:  u64, ) -> VkResult {
+			let scripted = D::get().result("vkWaitForFences");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			let result = VkResult::VK_SUCCESS;
+			scripted.unwrap_or(result)
+		}
+
+		pub unsafe extern "C" fn vkDestroySemaphore<D: Driver>(device:  VkDevice, semaphore:  VkSemaphore, pAllocator: *const VkAllocationCallbacks, ) {
+		}
+
+		pub unsafe extern "C" fn vkCreateCommandPool<D: Driver>(device:  VkDevice, pCreateInfo: *const VkCommandPoolCreateInfo, pAllocator: *const VkAllocationCallbacks, pCommandPool: *mut VkCommandPool, ) -> VkResult {
+			let scripted = D::get().result("vkCreateCommandPool");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			if !pCommandPool.is_null() {
+				*pCommandPool = new_handle();
+			}
+			let result = VkResult::VK_SUCCESS;
+			scripted.unwrap_or(result)
+		}
+
+		pub unsafe extern "C" fn vkDestroyCommandPool<D: Driver>(device:  VkDevice, commandPool:  VkCommandPool, pAllocator: *const VkAllocationCallbacks, ) {
+		}
+
+		pub unsafe extern "C" fn vkFreeCommandBuffers<D: Driver>(device:  VkDevice, commandPool:  VkCommandPool, commandBufferCount:  u32, pCommandBuffers: *const VkCommandBuffer, ) {
+			for i in 0..commandBufferCount as usize {
+				free_dispatchable(device, *pCommandBuffers.add(i));
+			}
+		}
+
+		pub unsafe extern "C" fn vkEnumerateInstanceVersion<D: Driver>(pApiVersion: *mut u32, ) -> VkResult {
+			let scripted = D::get().result("vkEnumerateInstanceVersion");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			let result = enumerate_instance_version::<D>(pApiVersion);
+			scripted.unwrap_or(result)
+		}
+
+		pub unsafe extern "C" fn vkGetPhysicalDeviceFeatures2<D: Driver>(physicalDevice:  VkPhysicalDevice, pFeatures: *mut VkPhysicalDeviceFeatures2, ) {
+		}
+
+		pub unsafe extern "C" fn vkGetPhysicalDeviceQueueFamilyProperties2<D: Driver>(physicalDevice:  VkPhysicalDevice, pQueueFamilyPropertyCount: *mut u32, pQueueFamilyProperties: *mut VkQueueFamilyProperties2, ) {
+			enumerate::<VkQueueFamilyProperties2>(&[], pQueueFamilyPropertyCount, pQueueFamilyProperties);
+		}
+
+#[cfg(feature = "VK_KHR_surface")]
+		pub unsafe extern "C" fn vkDestroySurfaceKHR<D: Driver>(instance:  VkInstance, surface:  VkSurfaceKHR, pAllocator: *const VkAllocationCallbacks, ) {
+		}
+
+#[cfg(feature = "VK_KHR_surface")]
+		pub unsafe extern "C" fn vkGetPhysicalDeviceSurfaceSupportKHR<D: Driver>(physicalDevice:  VkPhysicalDevice, queueFamilyIndex:  u32, surface:  VkSurfaceKHR, pSupported: *mut VkBool32, ) -> VkResult {
+			let scripted = D::get().result("vkGetPhysicalDeviceSurfaceSupportKHR");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			if !pSupported.is_null() {
+				*pSupported = VK_TRUE;
+			}
+			let result = VkResult::VK_SUCCESS;
+			scripted.unwrap_or(result)
+		}
+
+#[cfg(feature = "VK_KHR_surface")]
+		pub unsafe extern "C" fn vkGetPhysicalDeviceSurfaceCapabilitiesKHR<D: Driver>(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pSurfaceCapabilities: *mut VkSurfaceCapabilitiesKHR, ) -> VkResult {
+			let scripted = D::get().result("vkGetPhysicalDeviceSurfaceCapabilitiesKHR");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			let result = VkResult::VK_SUCCESS;
+			scripted.unwrap_or(result)
+		}
+
+#[cfg(feature = "VK_KHR_surface")]
+		pub unsafe extern "C" fn vkGetPhysicalDeviceSurfaceFormatsKHR<D: Driver>(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pSurfaceFormatCount: *mut u32, pSurfaceFormats: *mut VkSurfaceFormatKHR, ) -> VkResult {
+			let scripted = D::get().result("vkGetPhysicalDeviceSurfaceFormatsKHR");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			let result = enumerate::<VkSurfaceFormatKHR>(&[], pSurfaceFormatCount, pSurfaceFormats);
+			scripted.unwrap_or(result)
+		}
+
+#[cfg(feature = "VK_KHR_surface")]
+		pub unsafe extern "C" fn vkGetPhysicalDeviceSurfacePresentModesKHR<D: Driver>(physicalDevice:  VkPhysicalDevice, surface:  VkSurfaceKHR, pPresentModeCount: *mut u32, pPresentModes: *mut VkPresentModeKHR, ) -> VkResult {
+			let scripted = D::get().result("vkGetPhysicalDeviceSurfacePresentModesKHR");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			let result = enumerate::<VkPresentModeKHR>(&[], pPresentModeCount, pPresentModes);
+			scripted.unwrap_or(result)
+		}
+
+#[cfg(feature = "VK_KHR_swapchain")]
+		pub unsafe extern "C" fn vkCreateSwapchainKHR<D: Driver>(device:  VkDevice, pCreateInfo: *const VkSwapchainCreateInfoKHR, pAllocator: *const VkAllocationCallbacks, pSwapchain: *mut VkSwapchainKHR, ) -> VkResult {
+			let scripted = D::get().result("vkCreateSwapchainKHR");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			if !pSwapchain.is_null() {
+				*pSwapchain = new_handle();
+			}
+			let result = VkResult::VK_SUCCESS;
+			scripted.unwrap_or(result)
+		}
+
+#[cfg(feature = "VK_KHR_swapchain")]
+		pub unsafe extern "C" fn vkDestroySwapchainKHR<D: Driver>(device:  VkDevice, swapchain:  VkSwapchainKHR, pAllocator: *const VkAllocationCallbacks, ) {
+		}
+
+#[cfg(feature = "VK_KHR_swapchain")]
+		pub unsafe extern "C" fn vkGetSwapchainImagesKHR<D: Driver>(device:  VkDevice, swapchain:  VkSwapchainKHR, pSwapchainImageCount: *mut u32, pSwapchainImages: *mut VkImage, ) -> VkResult {
+			let scripted = D::get().result("vkGetSwapchainImagesKHR");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			let result = enumerate(&enumerated_handles("vkGetSwapchainImagesKHR", swapchain), pSwapchainImageCount, pSwapchainImages);
+			scripted.unwrap_or(result)
+		}
+
+#[cfg(feature = "VK_KHR_swapchain")]
+		pub unsafe extern "C" fn vkAcquireNextImageKHR<D: Driver>(device:  VkDevice, swapchain:  VkSwapchainKHR, timeout:  u64, semaphore:  VkSemaphore, fence:  VkFence, pImageIndex: *mut u32, ) -> VkResult {
+			let scripted = D::get().result("vkAcquireNextImageKHR");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			let result = VkResult::VK_SUCCESS;
+			scripted.unwrap_or(result)
+		}
+
+#[cfg(all(feature = "VK_KHR_swapchain", feature = "VK_KHR_get_physical_device_properties2", any(feature = "VK_EXT_debug_report", feature = "VK_KHR_surface")))]
+		pub unsafe extern "C" fn vkGetSwapchainStatusKHR<D: Driver>(device:  VkDevice, swapchain:  VkSwapchainKHR, ) -> VkResult {
+			let scripted = D::get().result("vkGetSwapchainStatusKHR");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			let result = VkResult::VK_SUCCESS;
+			scripted.unwrap_or(result)
+		}
+
+#[cfg(feature = "VK_KHR_xcb_surface")]
+		pub unsafe extern "C" fn vkCreateXcbSurfaceKHR<D: Driver>(instance:  VkInstance, pCreateInfo: *const VkXcbSurfaceCreateInfoKHR, pAllocator: *const VkAllocationCallbacks, pSurface: *mut VkSurfaceKHR, ) -> VkResult {
+			let scripted = D::get().result("vkCreateXcbSurfaceKHR");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			if !pSurface.is_null() {
+				*pSurface = new_handle();
+			}
+			let result = VkResult::VK_SUCCESS;
+			scripted.unwrap_or(result)
+		}
+
+#[cfg(feature = "VK_KHR_xcb_surface")]
+		pub unsafe extern "C" fn vkGetPhysicalDeviceXcbPresentationSupportKHR<D: Driver>(physicalDevice:  VkPhysicalDevice, queueFamilyIndex:  u32, connection: *mut xcb_connection_t, visual_id:  xcb_visualid_t, ) -> VkBool32 {
+			VK_TRUE
+		}
+
+#[cfg(feature = "VK_EXT_debug_report")]
+		pub unsafe extern "C" fn vkCreateDebugReportCallbackEXT<D: Driver>(instance:  VkInstance, pCreateInfo: *const VkDebugReportCallbackCreateInfoEXT, pAllocator: *const VkAllocationCallbacks, pCallback: *mut VkDebugReportCallbackEXT, ) -> VkResult {
+			let scripted = D::get().result("vkCreateDebugReportCallbackEXT");
+			if let Some(result) = scripted.filter(|result| (*result as i32) < 0) {
+				return result;
+			}
+			if !pCallback.is_null() {
+				*pCallback = new_handle();
+			}
+			let result = VkResult::VK_SUCCESS;
+			scripted.unwrap_or(result)
+		}
+
+#[cfg(feature = "VK_EXT_debug_report")]
+		pub unsafe extern "C" fn vkDestroyDebugReportCallbackEXT<D: Driver>(instance:  VkInstance, callback:  VkDebugReportCallbackEXT, pAllocator: *const VkAllocationCallbacks, ) {
+		}
+	}
+
+	// The driver's entry point for a command, or null for commands it does not have
+	fn command_address<D: Driver>(name: &[u8]) -> PFN_vkVoidFunction {
+		match name {
+			b"vkCreateInstance" => commands::vkCreateInstance::<D> as PFN_vkVoidFunction,
+			b"vkDestroyInstance" => commands::vkDestroyInstance::<D> as PFN_vkVoidFunction,
+			b"vkEnumeratePhysicalDevices" => commands::vkEnumeratePhysicalDevices::<D> as PFN_vkVoidFunction,
+			b"vkGetPhysicalDeviceFeatures" => commands::vkGetPhysicalDeviceFeatures::<D> as PFN_vkVoidFunction,
+			b"vkGetPhysicalDeviceProperties" => commands::vkGetPhysicalDeviceProperties::<D> as PFN_vkVoidFunction,
+			b"vkGetPhysicalDeviceQueueFamilyProperties" => commands::vkGetPhysicalDeviceQueueFamilyProperties::<D> as PFN_vkVoidFunction,
+			b"vkGetPhysicalDeviceMemoryProperties" => commands::vkGetPhysicalDeviceMemoryProperties::<D> as PFN_vkVoidFunction,
+			b"vkGetInstanceProcAddr" => commands::vkGetInstanceProcAddr::<D> as PFN_vkVoidFunction,
+			b"vkGetDeviceProcAddr" => commands::vkGetDeviceProcAddr::<D> as PFN_vkVoidFunction,
+			b"vkCreateDevice" => commands::vkCreateDevice::<D> as PFN_vkVoidFunction,
+			b"vkDestroyDevice" => commands::vkDestroyDevice::<D> as PFN_vkVoidFunction,
+			b"vkEnumerateInstanceExtensionProperties" => commands::vkEnumerateInstanceExtensionProperties::<D> as PFN_vkVoidFunction,
+			b"vkEnumerateDeviceExtensionProperties" => commands::vkEnumerateDeviceExtensionProperties::<D> as PFN_vkVoidFunction,
+			b"vkEnumerateInstanceLayerProperties" => commands::vkEnumerateInstanceLayerProperties::<D> as PFN_vkVoidFunction,
+			b"vkGetDeviceQueue" => commands::vkGetDeviceQueue::<D> as PFN_vkVoidFunction,
+			b"vkQueueWaitIdle" => commands::vkQueueWaitIdle::<D> as PFN_vkVoidFunction,
+			b"vkAllocateMemory" => commands::vkAllocateMemory::<D> as PFN_vkVoidFunction,
+			b"vkFreeMemory" => commands::vkFreeMemory::<D> as PFN_vkVoidFunction,
+			b"vkMapMemory" => commands::vkMapMemory::<D> as PFN_vkVoidFunction,
+			b"vkCreateBuffer" => commands::vkCreateBuffer::<D> as PFN_vkVoidFunction,
+			b"vkDestroyBuffer" => commands::vkDestroyBuffer::<D> as PFN_vkVoidFunction,
+			b"vkDestroyImage" => commands::vkDestroyImage::<D> as PFN_vkVoidFunction,
+			b"vkCreateFence" => commands::vkCreateFence::<D> as PFN_vkVoidFunction,
+			b"vkDestroyFence" => commands::vkDestroyFence::<D> as PFN_vkVoidFunction,
+			b"vkWaitForFences" => commands::vkWaitForFences::<D> as PFN_vkVoidFunction,
+			b"vkDestroySemaphore" => commands::vkDestroySemaphore::<D> as PFN_vkVoidFunction,
+			b"vkCreateCommandPool" => commands::vkCreateCommandPool::<D> as PFN_vkVoidFunction,
+			b"vkDestroyCommandPool" => commands::vkDestroyCommandPool::<D> as PFN_vkVoidFunction,
+			b"vkFreeCommandBuffers" => commands::vkFreeCommandBuffers::<D> as PFN_vkVoidFunction,
+			b"vkEnumerateInstanceVersion" => commands::vkEnumerateInstanceVersion::<D> as PFN_vkVoidFunction,
+			b"vkGetPhysicalDeviceFeatures2" | b"vkGetPhysicalDeviceFeatures2KHR" => commands::vkGetPhysicalDeviceFeatures2::<D> as PFN_vkVoidFunction,
+			b"vkGetPhysicalDeviceQueueFamilyProperties2" => commands::vkGetPhysicalDeviceQueueFamilyProperties2::<D> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_KHR_surface")]
+			b"vkDestroySurfaceKHR" => commands::vkDestroySurfaceKHR::<D> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_KHR_surface")]
+			b"vkGetPhysicalDeviceSurfaceSupportKHR" => commands::vkGetPhysicalDeviceSurfaceSupportKHR::<D> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_KHR_surface")]
+			b"vkGetPhysicalDeviceSurfaceCapabilitiesKHR" => commands::vkGetPhysicalDeviceSurfaceCapabilitiesKHR::<D> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_KHR_surface")]
+			b"vkGetPhysicalDeviceSurfaceFormatsKHR" => commands::vkGetPhysicalDeviceSurfaceFormatsKHR::<D> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_KHR_surface")]
+			b"vkGetPhysicalDeviceSurfacePresentModesKHR" => commands::vkGetPhysicalDeviceSurfacePresentModesKHR::<D> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_KHR_swapchain")]
+			b"vkCreateSwapchainKHR" => commands::vkCreateSwapchainKHR::<D> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_KHR_swapchain")]
+			b"vkDestroySwapchainKHR" => commands::vkDestroySwapchainKHR::<D> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_KHR_swapchain")]
+			b"vkGetSwapchainImagesKHR" => commands::vkGetSwapchainImagesKHR::<D> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_KHR_swapchain")]
+			b"vkAcquireNextImageKHR" => commands::vkAcquireNextImageKHR::<D> as PFN_vkVoidFunction,
+#[cfg(all(feature = "VK_KHR_swapchain", feature = "VK_KHR_get_physical_device_properties2", any(feature = "VK_EXT_debug_report", feature = "VK_KHR_surface")))]
+			b"vkGetSwapchainStatusKHR" => commands::vkGetSwapchainStatusKHR::<D> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_KHR_xcb_surface")]
+			b"vkCreateXcbSurfaceKHR" => commands::vkCreateXcbSurfaceKHR::<D> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_KHR_xcb_surface")]
+			b"vkGetPhysicalDeviceXcbPresentationSupportKHR" => commands::vkGetPhysicalDeviceXcbPresentationSupportKHR::<D> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_EXT_debug_report")]
+			b"vkCreateDebugReportCallbackEXT" => commands::vkCreateDebugReportCallbackEXT::<D> as PFN_vkVoidFunction,
+#[cfg(feature = "VK_EXT_debug_report")]
+			b"vkDestroyDebugReportCallbackEXT" => commands::vkDestroyDebugReportCallbackEXT::<D> as PFN_vkVoidFunction,
+			_ => ptr::null()
+		}
+	}
+}
@@ -0,0 +1,83 @@
+
+#[cfg(test)]
+mod tests {
+
+	use std::process::Command;
+
+	// Cut-down vk.xml covering enums, bitflags, structs, unions, handles, commands, extensions and aliases
+	const FIXTURE_XML: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/vk.xml");
+
+	// Expected output, regenerate with VKGEN_BLESS=1 cargo test after an intended change
+	const FIXTURE_RS: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/vk.rs");
+
+	fn generate(rs_filename: &str) -> String {
+		generate_with(rs_filename, &[])
+	}
+
+	fn generate_with(rs_filename: &str, args: &[&str]) -> String {
+
+		let rs_path = format!("{}/{}", env!("CARGO_TARGET_TMPDIR"), rs_filename);
+
+		let status = Command::new(env!("CARGO_BIN_EXE_vkgen"))
+			.arg(FIXTURE_XML)
+			.arg("-o")
+			.arg(&rs_path)
+			.args(args)
+			.status()
+			.expect("Failed to run vkgen");
+		assert!(status.success(), "vkgen failed on the fixture");
+
+		std::fs::read_to_string(&rs_path).expect("Could not read generated file")
+	}
+
+	#[test]
+	fn golden_test() {
+
+		let generated = generate("golden.rs");
+
+		if std::env::var("VKGEN_BLESS").is_ok() {
+			std::fs::write(FIXTURE_RS, &generated).expect("Could not write golden file");
+			return;
+		}
+
+		let expected = std::fs::read_to_string(FIXTURE_RS).expect("Could not read golden file");
+		if generated != expected {
+			for (line, (g, e)) in generated.lines().zip(expected.lines()).enumerate() {
+				if g != e {
+					panic!("Generated output differs from {} at line {}:\n  expected: {}\n  generated: {}", FIXTURE_RS, line + 1, e, g);
+				}
+			}
+			panic!("Generated output differs from {} in length ({} vs {} lines)", FIXTURE_RS, generated.lines().count(), expected.lines().count());
+		}
+	}
+
+	#[test]
+	fn deterministic_test() {
+
+		let first = generate("deterministic_1.rs");
+		let second = generate("deterministic_2.rs");
+		assert!(first == second, "vkgen output differs between two runs on the same input");
+	}
+
+	#[test]
+	fn include_test() {
+
+		// The same bindings without the crate root, for vkraw's lib.rs to include!
+		let crate_root = "\n#![allow(non_snake_case)]\n#![cfg_attr(not(feature = \"std\"), no_std)]\n\n#[macro_use]\nextern crate bitflags;\n";
+		let generated = generate_with("include.rs", &["--include"]);
+		assert!(!generated.contains("#!["));
+		assert!(!generated.contains("extern crate"));
+		assert_eq!(generated, generate("include_golden.rs").replacen(crate_root, "", 1));
+	}
+
+	#[test]
+	fn vulkansc_test() {
+
+		// The fixture's api="vulkansc" definitions, and an extension only Vulkan SC supports, are left out
+		let generated = generate("vulkansc.rs");
+		for name in &["vkGetFaultData", "maxFences", "VK_FORMAT_B4G4R4A4_UNORM_PACK16_EXT", "VK_NV_external_sci_sync", "VK_STRUCTURE_TYPE_EXPORT_FENCE_SCI_SYNC_INFO_NV"] {
+			assert!(!generated.contains(name), "{} is only in Vulkan SC", name);
+		}
+		assert_eq!(generated.matches("pub struct VkFenceCreateInfo ").count(), 1);
+	}
+}
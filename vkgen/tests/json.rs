@@ -0,0 +1,78 @@
+
+#[cfg(test)]
+mod tests {
+
+	use std::process::Command;
+
+	const FIXTURE_XML: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/vk.xml");
+
+	// Expected output, regenerate with VKGEN_BLESS=1 cargo test after an intended change
+	const FIXTURE_JSON: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/vk.json");
+
+	fn generate(json_filename: &str) -> String {
+
+		let json_path = format!("{}/{}", env!("CARGO_TARGET_TMPDIR"), json_filename);
+
+		let status = Command::new(env!("CARGO_BIN_EXE_vkgen"))
+			.arg(FIXTURE_XML)
+			.arg("--emit")
+			.arg("json")
+			.arg("-o")
+			.arg(&json_path)
+			.status()
+			.expect("Failed to run vkgen");
+		assert!(status.success(), "vkgen failed on the fixture");
+
+		std::fs::read_to_string(&json_path).expect("Could not read generated file")
+	}
+
+	#[test]
+	fn json_golden_test() {
+
+		let generated = generate("golden.json");
+
+		if std::env::var("VKGEN_BLESS").is_ok() {
+			std::fs::write(FIXTURE_JSON, &generated).expect("Could not write golden file");
+			return;
+		}
+
+		let expected = std::fs::read_to_string(FIXTURE_JSON).expect("Could not read golden file");
+		for (line, (g, e)) in generated.lines().zip(expected.lines()).enumerate() {
+			assert!(g == e, "Generated json differs from {} at line {}:\n  expected: {}\n  generated: {}", FIXTURE_JSON, line + 1, e, g);
+		}
+		assert_eq!(generated.lines().count(), expected.lines().count(), "Generated json differs from {} in length", FIXTURE_JSON);
+	}
+
+	#[test]
+	fn json_aliases_test() {
+
+		let generated = generate("aliases.json");
+
+		// Aliases are resolved to the value or definition of what they are an alias of
+		let expected = [
+			"\t\t{\"name\": \"VK_LUID_SIZE_KHR\", \"value\": 8, \"alias_of\": \"VK_LUID_SIZE\"},",
+			"\t\t\t\t{\"name\": \"VK_COLORSPACE_SRGB_NONLINEAR_KHR\", \"value\": 0, \"extension\": null, \"alias_of\": \"VK_COLOR_SPACE_SRGB_NONLINEAR_KHR\"}",
+			"\t\t\t\t{\"name\": \"VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FEATURES_2_KHR\", \"value\": 1000059000, \"extension\": \"VK_KHR_get_physical_device_properties2\", \"alias_of\": \"VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FEATURES_2\"}",
+			"\t\t\t\"alias_of\": \"VkPhysicalDeviceFeatures2\",",
+			"\t\t\t\"alias_of\": \"vkGetPhysicalDeviceFeatures2\",",
+		];
+		for line in expected.iter() {
+			assert!(generated.lines().any(|l| l == *line), "Missing from the json: {}", line);
+		}
+	}
+	#[test]
+	fn json_constants_test() {
+
+		let generated = generate("constants.json");
+
+		// The C expressions of constants are worked out to numbers, at the width of their suffix
+		let expected = [
+			"\t\t{\"name\": \"VK_LOD_CLAMP_NONE\", \"value\": 1000.0, \"alias_of\": null},",
+			"\t\t{\"name\": \"VK_REMAINING_MIP_LEVELS\", \"value\": 4294967295, \"alias_of\": null},",
+			"\t\t{\"name\": \"VK_WHOLE_SIZE\", \"value\": 18446744073709551615, \"alias_of\": null},",
+		];
+		for line in expected.iter() {
+			assert!(generated.lines().any(|l| l == *line), "Missing from the json: {}", line);
+		}
+	}
+}
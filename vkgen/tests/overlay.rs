@@ -0,0 +1,71 @@
+
+#[cfg(test)]
+mod tests {
+
+	use std::process::Command;
+
+	fn generate(overlay_filename: &str, rs_filename: &str) -> (bool, String, String) {
+
+		let rs_path = format!("{}/{}", env!("CARGO_TARGET_TMPDIR"), rs_filename);
+		let _ = std::fs::remove_file(&rs_path);
+
+		let output = Command::new(env!("CARGO_BIN_EXE_vkgen"))
+			.current_dir(env!("CARGO_MANIFEST_DIR"))
+			.arg("tests/fixtures/vk.xml")
+			.arg("--overlay")
+			.arg(overlay_filename)
+			.arg("-o")
+			.arg(&rs_path)
+			.output()
+			.expect("Failed to run vkgen");
+
+		let generated = std::fs::read_to_string(&rs_path).unwrap_or(String::new());
+		(output.status.success(), String::from_utf8(output.stdout).expect("vkgen output is not utf8"), generated)
+	}
+
+	#[test]
+	fn overlay_test() {
+
+		let (success, output, generated) = generate("tests/fixtures/overlay.xml", "overlay.rs");
+		assert!(success, "vkgen failed with the overlay:\n{}", output);
+
+		let expected = [
+			"pub const VK_VENDOR_PRIVATE_THING_SPEC_VERSION: u32 = 1;",
+			"\tVK_STRUCTURE_TYPE_PRIVATE_THING_INFO_VENDOR = 1001000000,",
+			"pub enum VkPrivateModeVENDOR {",
+			"\tVK_PRIVATE_MODE_SAFE_VENDOR = 1,",
+			"pub struct VkPrivateThingInfoVENDOR {",
+			"\tpub mode:  VkPrivateModeVENDOR,",
+			"\tExtensionInfo { name: \"VK_VENDOR_private_thing\", number: 1001, extension_type: ExtensionType::Device, spec_version: 1, requires: &[\"VK_KHR_get_physical_device_properties2\"], depends: None, promoted_to: None, deprecated_by: None, obsoleted_by: None },",
+			"\t\tpub SetPrivateModeVENDOR: Option<extern \"C\" fn(device:  VkDevice, mode:  VkPrivateModeVENDOR, ) -> VkResult>,",
+		];
+		for line in expected.iter() {
+			assert!(generated.lines().any(|l| l == *line), "Missing from the output with the overlay: {}", line);
+		}
+
+		// Everything upstream is still generated
+		let expected = std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/vk.rs")).expect("Could not read golden file");
+		for line in expected.lines() {
+			assert!(generated.lines().any(|l| l == line), "Missing from the output with the overlay: {}", line);
+		}
+	}
+
+	#[test]
+	fn overlay_conflict_test() {
+
+		let (success, output, generated) = generate("tests/fixtures/overlay_conflict.xml", "overlay_conflict.rs");
+		assert!(!success);
+		assert!(generated.is_empty());
+
+		let expected = [
+			"tests/fixtures/overlay_conflict.xml:7:9: type VkExtent2D is already defined",
+			"tests/fixtures/overlay_conflict.xml:12:9: command vkDestroyInstance is already defined",
+			"tests/fixtures/overlay_conflict.xml:18:9: extension number 2 is already defined",
+			"tests/fixtures/overlay_conflict.xml:20:17: enum value VK_STRUCTURE_TYPE_SWAPCHAIN_CREATE_INFO_KHR is already defined",
+			"tests/fixtures/overlay_conflict.xml:24:5: <feature> can not be merged, only types, enums, commands, extensions are",
+			"Could not merge overlay \"tests/fixtures/overlay_conflict.xml\""
+		];
+		let lines: Vec<&str> = output.lines().filter(|l| !l.starts_with("Using ")).collect();
+		assert_eq!(lines, expected);
+	}
+}
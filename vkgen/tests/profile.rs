@@ -0,0 +1,65 @@
+#[cfg(test)]
+mod tests {
+
+	use std::process::Command;
+
+	#[test]
+	fn openxr_profile_test() {
+
+		let rs_path = format!("{}/xr.rs", env!("CARGO_TARGET_TMPDIR"));
+		let _ = std::fs::remove_file(&rs_path);
+
+		let output = Command::new(env!("CARGO_BIN_EXE_vkgen"))
+			.current_dir(env!("CARGO_MANIFEST_DIR"))
+			.arg("tests/fixtures/xr.xml")
+			.arg("--profile")
+			.arg("openxr")
+			.arg("-o")
+			.arg(&rs_path)
+			.output()
+			.expect("Failed to run vkgen");
+		assert!(output.status.success(), "vkgen failed with --profile openxr:\n{}", String::from_utf8_lossy(&output.stdout));
+
+		let generated = std::fs::read_to_string(&rs_path).expect("Could not read generated file");
+
+		let expected = [
+			"pub const fn XR_MAKE_VERSION(major: u64, minor: u64, patch: u64) -> u64 {",
+			"pub const XR_CURRENT_API_VERSION: u64 = XR_MAKE_VERSION(1, 0, 5);",
+			"pub const XR_NULL_HANDLE: u64 = 0;",
+			"pub const XR_INFINITE_DURATION: i64 = 0x7fffffffffffffff;",
+			"pub const XR_FREQUENCY_UNSPECIFIED: f32 = 0.0;",
+			"pub type XrSwapchainUsageFlags = XrSwapchainUsageFlagBits;",
+			"\tpub struct XrSwapchainUsageFlagBits: u64 {",
+			"\t\tconst XR_SWAPCHAIN_USAGE_INPUT_ATTACHMENT_BIT_EXAMPLE = 0b0000000000000000000000010000000000000000000000000000000000000000;",
+			"\tXR_TYPE_GRAPHICS_BINDING_VULKAN_KHR = 1000025000,",
+			"\tassert!(::core::mem::offset_of!(XrFrameState, shouldRender) == 32);",
+			"#[link(name = \"openxr_loader\")]",
+			"\tpub fn xrCreateInstance(createInfo: *const XrInstanceCreateInfo, instance: *mut XrInstance, ) -> XrResult;",
+			"\tpub struct OpenXrFunctionPointers {",
+			"\t\tpub fn new(instance: XrInstance) -> OpenXrFunctionPointers {",
+			"\t\t\tassert!(instance != XR_NULL_HANDLE);",
+		];
+		for line in expected.iter() {
+			assert!(generated.lines().any(|l| l == *line), "Missing from the OpenXR output: {}", line);
+		}
+
+		// Nothing Vulkan specific, and none of the Vulkan loader frameworks
+		assert!(!generated.contains("VK_"), "Vulkan constants in the OpenXR output");
+		assert!(!generated.contains("pub mod layer"), "Layer framework in the OpenXR output");
+		assert!(!generated.contains("pub mod icd"), "Driver framework in the OpenXR output");
+	}
+
+	#[test]
+	fn openxr_traced_test() {
+
+		let output = Command::new(env!("CARGO_BIN_EXE_vkgen"))
+			.current_dir(env!("CARGO_MANIFEST_DIR"))
+			.arg("tests/fixtures/xr.xml")
+			.arg("--profile")
+			.arg("openxr")
+			.arg("--traced")
+			.output()
+			.expect("Failed to run vkgen");
+		assert!(!output.status.success(), "vkgen accepted --traced with the openxr profile");
+	}
+}
@@ -0,0 +1,72 @@
+#[cfg(test)]
+mod tests {
+
+	use std::process::Command;
+
+	fn query(name: &str) -> (bool, Vec<String>) {
+
+		let output = Command::new(env!("CARGO_BIN_EXE_vkgen"))
+			.current_dir(env!("CARGO_MANIFEST_DIR"))
+			.arg("query")
+			.arg("tests/fixtures/vk.xml")
+			.arg(name)
+			.output()
+			.expect("Failed to run vkgen");
+
+		let lines = String::from_utf8(output.stdout).expect("vkgen output is not utf8").lines().map(|l| l.to_string()).collect();
+		(output.status.success(), lines)
+	}
+
+	#[test]
+	fn query_struct_test() {
+
+		let (found, lines) = query("VkPhysicalDeviceFeatures2");
+		assert!(found);
+
+		let expected = [
+			"struct VkPhysicalDeviceFeatures2",
+			"\tsType: VkStructureType",
+			"\tpNext: *mut c_void",
+			"\tfeatures: VkPhysicalDeviceFeatures",
+			"sType: VK_STRUCTURE_TYPE_PHYSICAL_DEVICE_FEATURES_2",
+			"provided by: VK_VERSION_1_1 (Promoted from VK_KHR_get_physical_device_properties2)",
+			"aliases: VkPhysicalDeviceFeatures2KHR",
+			"extends: VkDeviceCreateInfo",
+			"extended by: VkPhysicalDeviceFloat16Int8FeaturesKHR",
+			"used by commands: vkGetPhysicalDeviceFeatures2, vkGetPhysicalDeviceFeatures2KHR"
+		];
+		assert_eq!(lines, expected);
+
+		// An alias answers for the struct it stands for
+		let (found, lines) = query("VkPhysicalDeviceFeatures2KHR");
+		assert!(found);
+		assert_eq!(lines[1], "alias of VkPhysicalDeviceFeatures2");
+		assert!(lines.contains(&"extended by: VkPhysicalDeviceFloat16Int8FeaturesKHR".to_string()));
+	}
+
+	#[test]
+	fn query_names_test() {
+
+		let (found, lines) = query("VK_FORMAT_A4R4G4B4_UNORM_PACK16_EXT");
+		assert!(found);
+		assert_eq!(lines, ["enum value VK_FORMAT_A4R4G4B4_UNORM_PACK16_EXT = 1000340000 of VkFormat", "provided by: VK_EXT_4444_formats"]);
+
+		let (found, lines) = query("VkImage");
+		assert!(found);
+		assert_eq!(lines, ["handle VkImage", "parent: VkDevice", "used by commands: vkDestroyImage, vkGetSwapchainImagesKHR"]);
+
+		let (found, lines) = query("vkCreateInstance");
+		assert!(found);
+		assert_eq!(lines[0], "command vkCreateInstance");
+		assert!(lines.contains(&"\tpAllocator: *const VkAllocationCallbacks (optional=true)".to_string()));
+		assert!(lines.contains(&"provided by: VK_VERSION_1_0 (Device initialization)".to_string()));
+
+		let (found, lines) = query("VK_KHR_SWAPCHAIN_EXTENSION_NAME");
+		assert!(found);
+		assert_eq!(lines, ["constant VK_KHR_SWAPCHAIN_EXTENSION_NAME", "provided by: VK_KHR_swapchain"]);
+
+		let (found, lines) = query("VkNothing");
+		assert!(!found);
+		assert_eq!(lines, ["Nothing in the registry is called \"VkNothing\""]);
+	}
+}
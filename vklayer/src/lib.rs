@@ -0,0 +1,84 @@
+
+// Skeleton of a layer on top of vkraw::layer
+// Every command it does not override goes straight to the next layer, the ones here trace instance and device
+// lifetimes and fail the commands named in VK_LAYER_RUST_SKELETON_FAIL (comma separated) to test error handling
+
+#![allow(non_snake_case)]
+
+extern crate vkraw;
+
+use vkraw::*;
+use vkraw::layer::*;
+
+use std::sync::OnceLock;
+
+struct Skeleton {
+	trace: bool,
+	fail: Vec<String>
+}
+
+static SKELETON: OnceLock<Skeleton> = OnceLock::new();
+
+impl Skeleton {
+
+	fn trace(&self, message: String) {
+		if self.trace {
+			eprintln!("VK_LAYER_RUST_skeleton: {}", message);
+		}
+	}
+
+	fn fail(&self, command: &str) -> bool {
+		if self.fail.iter().any(|f| f == command) {
+			self.trace(format!("{} failed on request", command));
+			return true;
+		}
+		false
+	}
+}
+
+impl Layer for Skeleton {
+
+	fn get() -> &'static Skeleton {
+		SKELETON.get_or_init(|| Skeleton {
+			trace: std::env::var("VK_LAYER_RUST_SKELETON_TRACE").is_ok(),
+			fail: std::env::var("VK_LAYER_RUST_SKELETON_FAIL").unwrap_or_default().split(',').map(|f| f.trim().to_string()).collect()
+		})
+	}
+
+	unsafe fn vkCreateInstance(&self, dispatch: &InstanceDispatch, pCreateInfo: *const VkInstanceCreateInfo, pAllocator: *const VkAllocationCallbacks, pInstance: *mut VkInstance) -> VkResult {
+		if self.fail("vkCreateInstance") {
+			return VkResult::VK_ERROR_INITIALIZATION_FAILED;
+		}
+		let result = dispatch.vkCreateInstance(pCreateInfo, pAllocator, pInstance);
+		self.trace(format!("vkCreateInstance() = {:?}", result));
+		result
+	}
+
+	unsafe fn vkDestroyInstance(&self, dispatch: &InstanceDispatch, instance: VkInstance, pAllocator: *const VkAllocationCallbacks) {
+		self.trace(format!("vkDestroyInstance({:#x})", instance));
+		dispatch.vkDestroyInstance(instance, pAllocator);
+	}
+
+	unsafe fn vkEnumeratePhysicalDevices(&self, dispatch: &InstanceDispatch, instance: VkInstance, pPhysicalDeviceCount: *mut u32, pPhysicalDevices: *mut VkPhysicalDevice) -> VkResult {
+		if self.fail("vkEnumeratePhysicalDevices") {
+			return VkResult::VK_ERROR_INITIALIZATION_FAILED;
+		}
+		dispatch.vkEnumeratePhysicalDevices(instance, pPhysicalDeviceCount, pPhysicalDevices)
+	}
+
+	unsafe fn vkCreateDevice(&self, dispatch: &InstanceDispatch, physicalDevice: VkPhysicalDevice, pCreateInfo: *const VkDeviceCreateInfo, pAllocator: *const VkAllocationCallbacks, pDevice: *mut VkDevice) -> VkResult {
+		if self.fail("vkCreateDevice") {
+			return VkResult::VK_ERROR_INITIALIZATION_FAILED;
+		}
+		let result = dispatch.vkCreateDevice(physicalDevice, pCreateInfo, pAllocator, pDevice);
+		self.trace(format!("vkCreateDevice({:#x}) = {:?}", physicalDevice, result));
+		result
+	}
+
+	unsafe fn vkDestroyDevice(&self, dispatch: &DeviceDispatch, device: VkDevice, pAllocator: *const VkAllocationCallbacks) {
+		self.trace(format!("vkDestroyDevice({:#x})", device));
+		dispatch.vkDestroyDevice(device, pAllocator);
+	}
+}
+
+export_layer!(Skeleton);
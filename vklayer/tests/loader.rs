@@ -0,0 +1,87 @@
+
+// Loads the layer through the loader, on the mock driver (vkmock) the test builds
+
+#[cfg(test)]
+mod tests {
+
+	extern crate vkraw;
+
+	use std::path::Path;
+	use std::process::Command;
+	use std::ptr;
+
+	const LAYER_NAME: &str = "VK_LAYER_RUST_skeleton\0";
+
+	// Points the loader at a copy of the manifest with the path of the library cargo built for this test
+	fn use_built_layer() {
+
+		let target_dir = std::env::current_exe().expect("No test executable").parent().unwrap().parent().unwrap().to_path_buf();
+		let library = target_dir.join(format!("{}vklayer{}", std::env::consts::DLL_PREFIX, std::env::consts::DLL_SUFFIX));
+		assert!(library.is_file(), "Layer library {:?} has not been built", library);
+
+		let manifest = include_str!("../VkLayer_rust_skeleton.json").replace("target/debug/libvklayer.so", &library.to_str().unwrap().replace("\\", "\\\\"));
+		std::fs::write(format!("{}/VkLayer_rust_skeleton.json", env!("CARGO_TARGET_TMPDIR")), manifest).expect("Could not write layer manifest");
+		std::env::set_var("VK_LAYER_PATH", env!("CARGO_TARGET_TMPDIR"));
+	}
+
+	// Builds the mock driver in a target directory of its own, it is only a cdylib so it can not be a dependency.
+	// Then points the loader at a copy of its manifest with the path of that library
+	fn use_built_driver() {
+
+		let target_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("vkmock");
+		let status = Command::new(env!("CARGO"))
+			.args(["build", "--manifest-path", concat!(env!("CARGO_MANIFEST_DIR"), "/../vkmock/Cargo.toml"), "--target-dir"])
+			.arg(&target_dir)
+			.status()
+			.expect("Failed to run cargo");
+		assert!(status.success(), "Could not build the mock driver");
+		let library = target_dir.join("debug").join(format!("{}vkmock{}", std::env::consts::DLL_PREFIX, std::env::consts::DLL_SUFFIX));
+		assert!(library.is_file(), "Driver library {} has not been built", library.display());
+
+		let manifest = include_str!("../../vkmock/VkICD_rust_mock.json").replace("target/debug/libvkmock.so", &library.to_str().unwrap().replace('\\', "\\\\"));
+		// Not next to the layer manifest, the loader reads every manifest in VK_LAYER_PATH as a layer's
+		let manifest_path = target_dir.join("VkICD_rust_mock.json");
+		std::fs::write(&manifest_path, manifest).expect("Could not write driver manifest");
+		std::env::set_var("VK_ICD_FILENAMES", manifest_path);
+	}
+
+	fn create_instance() -> Result<vkraw::VkInstance, vkraw::VkResult> {
+
+		let layers = [LAYER_NAME.as_ptr()];
+		let create_info = vkraw::VkInstanceCreateInfo {
+			sType: vkraw::VkStructureType::VK_STRUCTURE_TYPE_INSTANCE_CREATE_INFO,
+			pNext: ptr::null(),
+			flags: 0,
+			pApplicationInfo: ptr::null(),
+			enabledLayerCount: layers.len() as u32,
+			ppEnabledLayerNames: layers.as_ptr(),
+			enabledExtensionCount: 0,
+			ppEnabledExtensionNames: ptr::null(),
+		};
+		let mut instance: vkraw::VkInstance = 0;
+		let res = unsafe { vkraw::vkCreateInstance(&create_info, ptr::null(), &mut instance) };
+		match res {
+			vkraw::VkResult::VK_SUCCESS => Ok(instance),
+			_ => Err(res)
+		}
+	}
+
+	#[test]
+	fn layer_test() {
+
+		use_built_driver();
+		use_built_layer();
+		std::env::set_var("VK_LAYER_RUST_SKELETON_FAIL", "vkEnumeratePhysicalDevices");
+
+		let instance = create_instance().expect("Failed to create instance with the layer enabled");
+
+		// Only the layer fails this
+		let mut count = 0;
+		let res = unsafe { vkraw::vkEnumeratePhysicalDevices(instance, &mut count, ptr::null_mut()) };
+		assert_eq!(res, vkraw::VkResult::VK_ERROR_INITIALIZATION_FAILED);
+
+		unsafe {
+			vkraw::vkDestroyInstance(instance, ptr::null());
+		}
+	}
+}
@@ -0,0 +1,187 @@
+
+// Mock Vulkan driver on top of vkraw::icd, so that code using Vulkan can be tested without a GPU
+// Every command succeeds with fake handles. The physical devices it reports and scripted results of commands come
+// from the file named in VK_MOCK_ICD_CONFIG, one setting per line (numbers can be decimal or 0x hex):
+//
+//   device <name>                          a physical device, the settings up to the next one are for it
+//   api_version <major>.<minor>.<patch>
+//   vendor_id <id>
+//   device_id <id>
+//   type <other|integrated|discrete|virtual|cpu>
+//   queue_family <VkQueueFlags> <count>
+//   memory_heap <size> <VkMemoryHeapFlags>
+//   memory_type <VkMemoryPropertyFlags> <heap index>
+//   extension <name> <spec version>         a device extension
+//   instance_extension <name> <spec version>
+//   result <command> <VkResult>...          results of the next calls to a command, after that it succeeds again
+//
+// Without a configuration there is one discrete 1.0 device with a queue family that does everything and host
+// visible memory
+
+#![allow(non_snake_case)]
+
+extern crate vkraw;
+
+use vkraw::*;
+use vkraw::icd::*;
+
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{Mutex, OnceLock};
+
+struct Mock {
+	physical_devices: Vec<PhysicalDevice>,
+	instance_extensions: Vec<VkExtensionProperties>,
+	results: Mutex<BTreeMap<String, VecDeque<VkResult>>>
+}
+
+static MOCK: OnceLock<Mock> = OnceLock::new();
+
+fn parse_number(value: &str) -> Option<u64> {
+	if let Some(hex) = value.strip_prefix("0x") {
+		u64::from_str_radix(hex, 16).ok()
+	} else {
+		value.parse::<u64>().ok()
+	}
+}
+
+fn parse_version(value: &str) -> Option<u32> {
+	let parts: Vec<Option<u32>> = value.split('.').map(|part| part.parse::<u32>().ok()).collect();
+	match parts.as_slice() {
+		[Some(major), Some(minor), Some(patch)] => Some(VK_MAKE_VERSION(*major, *minor, *patch)),
+		_ => None
+	}
+}
+
+fn parse_device_type(value: &str) -> Option<VkPhysicalDeviceType> {
+	match value {
+		"other" => Some(VkPhysicalDeviceType::VK_PHYSICAL_DEVICE_TYPE_OTHER),
+		"integrated" => Some(VkPhysicalDeviceType::VK_PHYSICAL_DEVICE_TYPE_INTEGRATED_GPU),
+		"discrete" => Some(VkPhysicalDeviceType::VK_PHYSICAL_DEVICE_TYPE_DISCRETE_GPU),
+		"virtual" => Some(VkPhysicalDeviceType::VK_PHYSICAL_DEVICE_TYPE_VIRTUAL_GPU),
+		"cpu" => Some(VkPhysicalDeviceType::VK_PHYSICAL_DEVICE_TYPE_CPU),
+		_ => None
+	}
+}
+
+fn default_physical_device() -> PhysicalDevice {
+	let mut physical_device = PhysicalDevice::new("vkmock");
+	physical_device.properties.deviceType = VkPhysicalDeviceType::VK_PHYSICAL_DEVICE_TYPE_DISCRETE_GPU;
+	physical_device.queue_families.push(VkQueueFamilyProperties {
+		queueFlags: VkQueueFlags::VK_QUEUE_GRAPHICS_BIT | VkQueueFlags::VK_QUEUE_COMPUTE_BIT | VkQueueFlags::VK_QUEUE_TRANSFER_BIT,
+		queueCount: 1,
+		timestampValidBits: 64,
+		minImageTransferGranularity: VkExtent3D { width: 1, height: 1, depth: 1 }
+	});
+	physical_device.memory.memoryHeapCount = 1;
+	physical_device.memory.memoryHeaps[0] = VkMemoryHeap { size: 256 << 20, flags: VkMemoryHeapFlags::VK_MEMORY_HEAP_DEVICE_LOCAL_BIT };
+	physical_device.memory.memoryTypeCount = 1;
+	physical_device.memory.memoryTypes[0] = VkMemoryType {
+		propertyFlags: VkMemoryPropertyFlags::VK_MEMORY_PROPERTY_DEVICE_LOCAL_BIT | VkMemoryPropertyFlags::VK_MEMORY_PROPERTY_HOST_VISIBLE_BIT | VkMemoryPropertyFlags::VK_MEMORY_PROPERTY_HOST_COHERENT_BIT,
+		heapIndex: 0
+	};
+	physical_device
+}
+
+impl Mock {
+
+	fn load(config: &str) -> Result<Mock, String> {
+
+		let mut mock = Mock { physical_devices: Vec::new(), instance_extensions: Vec::new(), results: Mutex::new(BTreeMap::new()) };
+
+		for (i, line) in config.lines().enumerate() {
+
+			let words: Vec<&str> = line.split_whitespace().collect();
+			let error = |message: &str| format!("line {}: {}", i + 1, message);
+			let number = |index: usize| words.get(index).and_then(|w| parse_number(w)).ok_or_else(|| error(&format!("{} needs a number", words[0])));
+
+			if words.is_empty() || words[0].starts_with('#') {
+				continue;
+			}
+			if words[0] == "device" {
+				mock.physical_devices.push(PhysicalDevice::new(&words[1..].join(" ")));
+				continue;
+			}
+			if words[0] == "instance_extension" {
+				let name = words.get(1).ok_or_else(|| error("instance_extension needs a name"))?;
+				mock.instance_extensions.push(extension_properties(name, number(2)? as u32));
+				continue;
+			}
+			if words[0] == "result" {
+				let command = words.get(1).ok_or_else(|| error("result needs a command"))?;
+				let mut results = VecDeque::new();
+				for name in &words[2..] {
+					results.push_back(result_from_name(name).ok_or_else(|| error(&format!("unknown result {}", name)))?);
+				}
+				mock.results.lock().unwrap().insert(command.to_string(), results);
+				continue;
+			}
+
+			let physical_device = mock.physical_devices.last_mut().ok_or_else(|| error(&format!("{} before the first device", words[0])))?;
+			match words[0] {
+				"api_version" => physical_device.properties.apiVersion = words.get(1).and_then(|w| parse_version(w)).ok_or_else(|| error("api_version needs a major.minor.patch version"))?,
+				"vendor_id" => physical_device.properties.vendorID = number(1)? as u32,
+				"device_id" => physical_device.properties.deviceID = number(1)? as u32,
+				"type" => physical_device.properties.deviceType = words.get(1).and_then(|w| parse_device_type(w)).ok_or_else(|| error("type needs other, integrated, discrete, virtual or cpu"))?,
+				"queue_family" => physical_device.queue_families.push(VkQueueFamilyProperties {
+					queueFlags: VkQueueFlags::from_bits_truncate(number(1)? as u32),
+					queueCount: number(2)? as u32,
+					timestampValidBits: 64,
+					minImageTransferGranularity: VkExtent3D { width: 1, height: 1, depth: 1 }
+				}),
+				"memory_heap" => {
+					let memory = &mut physical_device.memory;
+					if memory.memoryHeapCount as usize == memory.memoryHeaps.len() {
+						return Err(error("too many memory heaps"));
+					}
+					memory.memoryHeaps[memory.memoryHeapCount as usize] = VkMemoryHeap { size: number(1)?, flags: VkMemoryHeapFlags::from_bits_truncate(number(2)? as u32) };
+					memory.memoryHeapCount += 1;
+				},
+				"memory_type" => {
+					let memory = &mut physical_device.memory;
+					if memory.memoryTypeCount as usize == memory.memoryTypes.len() {
+						return Err(error("too many memory types"));
+					}
+					memory.memoryTypes[memory.memoryTypeCount as usize] = VkMemoryType { propertyFlags: VkMemoryPropertyFlags::from_bits_truncate(number(1)? as u32), heapIndex: number(2)? as u32 };
+					memory.memoryTypeCount += 1;
+				},
+				"extension" => {
+					let name = words.get(1).ok_or_else(|| error("extension needs a name"))?;
+					physical_device.extensions.push(extension_properties(name, number(2)? as u32));
+				},
+				_ => return Err(error(&format!("unknown setting {}", words[0])))
+			}
+		}
+
+		if mock.physical_devices.is_empty() {
+			mock.physical_devices.push(default_physical_device());
+		}
+		Ok(mock)
+	}
+}
+
+impl Driver for Mock {
+
+	fn get() -> &'static Mock {
+		MOCK.get_or_init(|| {
+			let config = match std::env::var("VK_MOCK_ICD_CONFIG") {
+				Ok(filename) => std::fs::read_to_string(&filename).unwrap_or_else(|e| panic!("vkmock: could not read {}: {}", filename, e)),
+				Err(_) => String::new()
+			};
+			Mock::load(&config).unwrap_or_else(|e| panic!("vkmock: VK_MOCK_ICD_CONFIG {}", e))
+		})
+	}
+
+	fn physical_devices(&self) -> &[PhysicalDevice] {
+		&self.physical_devices
+	}
+
+	fn instance_extensions(&self) -> &[VkExtensionProperties] {
+		&self.instance_extensions
+	}
+
+	fn result(&self, command: &str) -> Option<VkResult> {
+		self.results.lock().unwrap().get_mut(command).and_then(|results| results.pop_front())
+	}
+}
+
+export_icd!(Mock);
@@ -0,0 +1,150 @@
+
+// Loads the mock driver through the loader and checks that it reports what its configuration says
+
+#[cfg(test)]
+mod tests {
+
+	extern crate vkraw;
+
+	use std::ptr;
+
+	const CONFIG: &str = "
+device first
+api_version 1.1.0
+vendor_id 0x1234
+type integrated
+queue_family 0x1 1
+queue_family 0x6 2
+memory_heap 1048576 0x1
+memory_type 0x7 0
+extension VK_KHR_swapchain 70
+
+device second
+type cpu
+
+result vkQueueWaitIdle VK_SUCCESS VK_ERROR_DEVICE_LOST
+";
+
+	// Points the loader at a copy of the manifest with the path of the library cargo built for this test
+	fn use_built_driver() {
+
+		let target_dir = std::env::current_exe().expect("No test executable").parent().unwrap().parent().unwrap().to_path_buf();
+		let library = target_dir.join(format!("{}vkmock{}", std::env::consts::DLL_PREFIX, std::env::consts::DLL_SUFFIX));
+		assert!(library.is_file(), "Driver library {:?} has not been built", library);
+
+		let manifest = include_str!("../VkICD_rust_mock.json").replace("target/debug/libvkmock.so", &library.to_str().unwrap().replace("\\", "\\\\"));
+		let manifest_path = format!("{}/VkICD_rust_mock.json", env!("CARGO_TARGET_TMPDIR"));
+		std::fs::write(&manifest_path, manifest).expect("Could not write driver manifest");
+		let config_path = format!("{}/vkmock.cfg", env!("CARGO_TARGET_TMPDIR"));
+		std::fs::write(&config_path, CONFIG).expect("Could not write driver configuration");
+		std::env::set_var("VK_ICD_FILENAMES", manifest_path);
+		std::env::set_var("VK_MOCK_ICD_CONFIG", config_path);
+	}
+
+	fn create_instance() -> Result<vkraw::VkInstance, vkraw::VkResult> {
+
+		let create_info = vkraw::VkInstanceCreateInfo {
+			sType: vkraw::VkStructureType::VK_STRUCTURE_TYPE_INSTANCE_CREATE_INFO,
+			pNext: ptr::null(),
+			flags: 0,
+			pApplicationInfo: ptr::null(),
+			enabledLayerCount: 0,
+			ppEnabledLayerNames: ptr::null(),
+			enabledExtensionCount: 0,
+			ppEnabledExtensionNames: ptr::null(),
+		};
+		let mut instance: vkraw::VkInstance = 0;
+		let res = unsafe { vkraw::vkCreateInstance(&create_info, ptr::null(), &mut instance) };
+		match res {
+			vkraw::VkResult::VK_SUCCESS => Ok(instance),
+			_ => Err(res)
+		}
+	}
+
+	#[test]
+	fn driver_test() {
+
+		use_built_driver();
+
+		let instance = create_instance().expect("Failed to create instance with the mock driver");
+
+		unsafe {
+			let mut count = 0;
+			assert_eq!(vkraw::vkEnumeratePhysicalDevices(instance, &mut count, ptr::null_mut()), vkraw::VkResult::VK_SUCCESS);
+			assert_eq!(count, 2);
+			let mut physical_devices = vec![0; count as usize];
+			assert_eq!(vkraw::vkEnumeratePhysicalDevices(instance, &mut count, physical_devices.as_mut_ptr()), vkraw::VkResult::VK_SUCCESS);
+
+			let mut properties: vkraw::VkPhysicalDeviceProperties = std::mem::zeroed();
+			vkraw::vkGetPhysicalDeviceProperties(physical_devices[0], &mut properties);
+			assert_eq!(std::ffi::CStr::from_ptr(properties.deviceName.as_ptr() as *const _).to_str().unwrap(), "first");
+			assert_eq!(properties.apiVersion, vkraw::VK_MAKE_VERSION(1, 1, 0));
+			assert_eq!(properties.vendorID, 0x1234);
+			assert_eq!(properties.deviceType, vkraw::VkPhysicalDeviceType::VK_PHYSICAL_DEVICE_TYPE_INTEGRATED_GPU);
+
+			vkraw::vkGetPhysicalDeviceQueueFamilyProperties(physical_devices[0], &mut count, ptr::null_mut());
+			assert_eq!(count, 2);
+			let mut queue_families = vec![std::mem::zeroed::<vkraw::VkQueueFamilyProperties>(); count as usize];
+			vkraw::vkGetPhysicalDeviceQueueFamilyProperties(physical_devices[0], &mut count, queue_families.as_mut_ptr());
+			assert_eq!(queue_families[1].queueFlags, vkraw::VkQueueFlags::VK_QUEUE_COMPUTE_BIT | vkraw::VkQueueFlags::VK_QUEUE_TRANSFER_BIT);
+			assert_eq!(queue_families[1].queueCount, 2);
+
+			let mut extension_count = 0;
+			assert_eq!(vkraw::vkEnumerateDeviceExtensionProperties(physical_devices[0], ptr::null(), &mut extension_count, ptr::null_mut()), vkraw::VkResult::VK_SUCCESS);
+			assert_eq!(extension_count, 1);
+
+			let priority = 1.0f32;
+			let queue_create_info = vkraw::VkDeviceQueueCreateInfo {
+				sType: vkraw::VkStructureType::VK_STRUCTURE_TYPE_DEVICE_QUEUE_CREATE_INFO,
+				pNext: ptr::null(),
+				flags: vkraw::VkDeviceQueueCreateFlags::_EMPTY,
+				queueFamilyIndex: 0,
+				queueCount: 1,
+				pQueuePriorities: &priority,
+			};
+			let device_create_info = vkraw::VkDeviceCreateInfo {
+				sType: vkraw::VkStructureType::VK_STRUCTURE_TYPE_DEVICE_CREATE_INFO,
+				pNext: ptr::null(),
+				flags: 0,
+				queueCreateInfoCount: 1,
+				pQueueCreateInfos: &queue_create_info,
+				enabledLayerCount: 0,
+				ppEnabledLayerNames: ptr::null(),
+				enabledExtensionCount: 0,
+				ppEnabledExtensionNames: ptr::null(),
+				pEnabledFeatures: ptr::null(),
+			};
+			let mut device: vkraw::VkDevice = 0;
+			assert_eq!(vkraw::vkCreateDevice(physical_devices[0], &device_create_info, ptr::null(), &mut device), vkraw::VkResult::VK_SUCCESS);
+
+			let mut queue: vkraw::VkQueue = 0;
+			vkraw::vkGetDeviceQueue(device, 0, 0, &mut queue);
+			let mut same_queue: vkraw::VkQueue = 0;
+			vkraw::vkGetDeviceQueue(device, 0, 0, &mut same_queue);
+			assert!(queue != 0);
+			assert_eq!(queue, same_queue);
+
+			let allocate_info = vkraw::VkMemoryAllocateInfo {
+				sType: vkraw::VkStructureType::VK_STRUCTURE_TYPE_MEMORY_ALLOCATE_INFO,
+				pNext: ptr::null(),
+				allocationSize: 4096,
+				memoryTypeIndex: 0,
+			};
+			let mut memory: vkraw::VkDeviceMemory = 0;
+			assert_eq!(vkraw::vkAllocateMemory(device, &allocate_info, ptr::null(), &mut memory), vkraw::VkResult::VK_SUCCESS);
+			let mut data = ptr::null_mut();
+			assert_eq!(vkraw::vkMapMemory(device, memory, 0, 4096, 0, &mut data), vkraw::VkResult::VK_SUCCESS);
+			std::slice::from_raw_parts_mut(data as *mut u8, 4096)[4095] = 1;
+			vkraw::vkUnmapMemory(device, memory);
+			vkraw::vkFreeMemory(device, memory, ptr::null());
+
+			// Scripted results, then back to succeeding
+			assert_eq!(vkraw::vkQueueWaitIdle(queue), vkraw::VkResult::VK_SUCCESS);
+			assert_eq!(vkraw::vkQueueWaitIdle(queue), vkraw::VkResult::VK_ERROR_DEVICE_LOST);
+			assert_eq!(vkraw::vkQueueWaitIdle(queue), vkraw::VkResult::VK_SUCCESS);
+
+			vkraw::vkDestroyDevice(device, ptr::null());
+			vkraw::vkDestroyInstance(instance, ptr::null());
+		}
+	}
+}
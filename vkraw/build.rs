@@ -1,40 +1,120 @@
 
 use std::process::Command;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::env;
+
+// The registry versions there are bindings for in src/bindings, each with a header-x-y-z feature. The newest is last
+const HEADER_VERSIONS: &[&str] = &["1.2.203", "1.3.250"];
 
 fn main() {
 
 	// Only run if files have changed
 	println!("cargo:rerun-if-changed=build.rs");
+	println!("cargo:rerun-if-env-changed=VKRAW_REGENERATE");
 
-	// Build the generator
-	Command::new("cargo").current_dir("../vkgen").args(&["build"]).status().unwrap();
+	// The bindings are pregenerated, so building vkraw needs neither vk.xml nor vkgen.
+	// Regenerating them is a maintainer step: VKRAW_REGENERATE=1 cargo build
+	// A checkout without them generates the missing ones once, which needs their registries
+	if env::var_os("VKRAW_REGENERATE").is_some() {
+		regenerate(true);
+	} else if HEADER_VERSIONS.iter().any(|version| !Path::new(&bindings_path(version)).is_file()) {
+		regenerate(false);
+	}
 
-	// Run the generator
-	let exe_path;
-	if cfg!(unix) {
-		exe_path = Path::new("../vkgen/target/debug/vkgen");
+	// Link against the libvulkan.so from the loader
+	// TODO: should we take over the loader functionality?
+	// TODO: should we link dynamically?
+	let loader_dir = if cfg!(unix) {
+		"/home/ash/github/Vulkan-Loader/build/loader"
 	} else if cfg!(windows) {
-		exe_path = Path::new("../vkgen/target/debug/vkgen.exe");
+		"C:\\Users\\ash\\Documents\\GitHub\\Vulkan-Loader\\build\\loader\\Debug"
 	} else {
 		panic!("Unknown system");
+	};
+	println!("cargo:rustc-link-search={}", loader_dir);
+
+	// Without a loader to link against, an empty library stands in for it so crates that only need the
+	// types still link. Dependents see whether there is one in DEP_VULKAN_LOADER, i.e. to skip their tests
+	println!("cargo:rerun-if-env-changed=VULKAN_SDK");
+	println!("cargo:rerun-if-env-changed=LIBRARY_PATH");
+	if find_loader(Path::new(loader_dir)) {
+		println!("cargo:loader=1");
+	} else {
+		println!("cargo:warning=No Vulkan loader (libvulkan) was found to link against, calling Vulkan will not link");
+		println!("cargo:loader=0");
+		let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap());
+		let stub_name = if cfg!(windows) { "vulkan.lib" } else { "libvulkan.a" };
+		std::fs::write(out_dir.join(stub_name), "!<arch>\n").unwrap();
+		println!("cargo:rustc-link-search={}", out_dir.display());
 	}
-	let xml_path = Path::new("../vkgen/vk.xml");
-	let out_path = Path::new("src/lib.rs");
+}
 
-	assert!(exe_path.is_file());
-	assert!(xml_path.is_file());
+// Whether the loader library is in loader_dir, the SDK or a directory the linker searches by default
+fn find_loader(loader_dir: &Path) -> bool {
+
+	let mut dirs = vec![loader_dir.to_path_buf()];
+	if let Some(sdk) = env::var_os("VULKAN_SDK") {
+		dirs.push(Path::new(&sdk).join("lib"));
+		dirs.push(Path::new(&sdk).join("Lib"));
+	}
+	if let Some(paths) = env::var_os("LIBRARY_PATH") {
+		dirs.extend(env::split_paths(&paths));
+	}
+	if cfg!(windows) {
+		return dirs.iter().any(|dir| dir.join("vulkan.lib").is_file());
+	}
+	let multiarch = env::var("TARGET").unwrap().replace("-unknown-", "-");
+	for dir in &["/lib", "/lib64", "/usr/lib", "/usr/lib64", "/usr/local/lib"] {
+		dirs.push(PathBuf::from(dir));
+		dirs.push(Path::new(dir).join(&multiarch));
+	}
+	let names = ["libvulkan.so", "libvulkan.a", "libvulkan.dylib"];
+	dirs.iter().any(|dir| names.iter().any(|name| dir.join(name).is_file()))
+}
 
-	Command::new(exe_path).args(&[xml_path.to_str().unwrap(), "-o", out_path.to_str().unwrap()]).status().unwrap();
+// Where the bindings for a header version are, i.e. src/bindings/header_1_3_250.rs
+fn bindings_path(version: &str) -> String {
+	format!("src/bindings/header_{}.rs", version.replace('.', "_"))
+}
 
-	// Link against the libvulkan.so from the loader
-	// TODO: should we take over the loader functionality?
-	// TODO: should we link dynamically?
+// Run the generator over registry/vk-x.y.z.xml for every header version, or only the ones without bindings
+// The extension features are only rewritten when every version is
+fn regenerate(all: bool) {
+
+	// Build the generator, in its own target directory so it does not wait for the lock on this build's
+	Command::new("cargo").current_dir("../vkgen").args(["build"]).env_remove("CARGO_TARGET_DIR").status().unwrap();
+
+	let exe_path;
 	if cfg!(unix) {
-		println!("cargo:rustc-link-search=/home/ash/github/Vulkan-Loader/build/loader");
+		exe_path = Path::new("../vkgen/target/debug/vkgen");
 	} else if cfg!(windows) {
-		println!("cargo:rustc-link-search=C:\\Users\\ash\\Documents\\GitHub\\Vulkan-Loader\\build\\loader\\Debug");
+		exe_path = Path::new("../vkgen/target/debug/vkgen.exe");
 	} else {
 		panic!("Unknown system");
 	}
+	assert!(exe_path.is_file());
+	std::fs::create_dir_all("src/bindings").unwrap();
+
+	for (i, version) in HEADER_VERSIONS.iter().enumerate() {
+
+		let xml_path = format!("registry/vk-{}.xml", version);
+		let out_path = bindings_path(version);
+		println!("cargo:rerun-if-changed={}", xml_path);
+		println!("cargo:rerun-if-changed={}", out_path);
+		if !all && Path::new(&out_path).is_file() {
+			continue;
+		}
+		assert!(Path::new(&xml_path).is_file(), "{} is missing, so {} can not be generated. Download https://raw.githubusercontent.com/KhronosGroup/Vulkan-Headers/v{}/registry/vk.xml to it", xml_path, out_path, version);
+
+		// The tracing wrappers, valid usage checks and serde impls are behind their features in the output,
+		// so the one file serves every feature combination. lib.rs has the crate attributes
+		let mut args = vec![xml_path.as_str(), "-o", out_path.as_str(), "--include", "--traced", "--validate", "--serde"];
+
+		// Extensions are never removed from the registry, so the newest one has every extension feature
+		if all && i + 1 == HEADER_VERSIONS.len() {
+			args.extend(&["--cargo-toml", "Cargo.toml"]);
+		}
+		let status = Command::new(exe_path).args(&args).status().unwrap();
+		assert!(status.success(), "vkgen failed to generate {}", out_path);
+	}
 }
@@ -0,0 +1,16 @@
+// The bindings are generated by vkgen ahead of time, one file per registry version, see build.rs to regenerate them
+// (or to generate the missing ones in a checkout without them)
+
+#![allow(non_snake_case)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[macro_use]
+extern crate bitflags;
+
+// The newest registry version is used unless an older one is asked for, the oldest one asked for wins,
+// so features from different dependents (or --all-features) never conflict
+#[cfg(feature = "header-1-2-203")]
+include!("bindings/header_1_2_203.rs");
+
+#[cfg(not(feature = "header-1-2-203"))]
+include!("bindings/header_1_3_250.rs");
@@ -0,0 +1,45 @@
+
+use std::process::Command;
+use std::path::Path;
+use std::env;
+
+fn main() {
+
+	// Only run if files have changed
+	println!("cargo:rerun-if-changed=build.rs");
+	println!("cargo:rerun-if-env-changed=XRRAW_REGENERATE");
+
+	// The bindings are pregenerated, so building xrraw needs neither xr.xml nor vkgen.
+	// Regenerating them is a maintainer step: XRRAW_REGENERATE=1 cargo build
+	// A checkout without them generates them and the extension features once, which needs the registry
+	if env::var_os("XRRAW_REGENERATE").is_some() || !Path::new("src/bindings.rs").is_file() {
+		regenerate();
+	}
+}
+
+// Run the generator with the OpenXR conventions over registry/xr.xml
+fn regenerate() {
+
+	// Build the generator, in its own target directory so it does not wait for the lock on this build's
+	Command::new("cargo").current_dir("../vkgen").args(["build"]).env_remove("CARGO_TARGET_DIR").status().unwrap();
+
+	let exe_path;
+	if cfg!(unix) {
+		exe_path = Path::new("../vkgen/target/debug/vkgen");
+	} else if cfg!(windows) {
+		exe_path = Path::new("../vkgen/target/debug/vkgen.exe");
+	} else {
+		panic!("Unknown system");
+	}
+	let xml_path = "registry/xr.xml";
+	let out_path = "src/bindings.rs";
+
+	assert!(exe_path.is_file());
+	println!("cargo:rerun-if-changed={}", xml_path);
+	println!("cargo:rerun-if-changed={}", out_path);
+	assert!(Path::new(xml_path).is_file(), "{} is missing, so {} can not be generated. Download https://raw.githubusercontent.com/KhronosGroup/OpenXR-SDK/main/specification/registry/xr.xml to it", xml_path, out_path);
+
+	// lib.rs has the crate attributes
+	let status = Command::new(exe_path).args([xml_path, "--profile", "openxr", "-o", out_path, "--include", "--cargo-toml", "Cargo.toml"]).status().unwrap();
+	assert!(status.success(), "vkgen failed to generate {}", out_path);
+}
@@ -0,0 +1,9 @@
+// The bindings are generated by vkgen ahead of time from the OpenXR registry, see build.rs to regenerate them
+
+#![allow(non_snake_case)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[macro_use]
+extern crate bitflags;
+
+include!("bindings.rs");